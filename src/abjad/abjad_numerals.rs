@@ -0,0 +1,130 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::AbjadSystem;
+use crate::ArabicCharacter;
+
+/// Returns the sum of the values of the letters of a word or a text.
+///
+/// ```
+/// use arabic_script::{abjad_word_value, AbjadSystem};
+///
+/// assert_eq!(abjad_word_value("بسم", AbjadSystem::Mashriqi), 102);
+/// assert_eq!(abjad_word_value("بسم", AbjadSystem::Maghrebi), 342);
+/// ```
+///
+/// Diacritical marks, and characters that are not letters, are not counted.
+pub fn abjad_word_value(text: &str, system: AbjadSystem) -> u32 {
+    text.chars()
+        .filter_map(|c| ArabicCharacter::try_from(c).ok())
+        .filter_map(|character| system.value(character))
+        .sum()
+}
+
+/// Writes a number in abjad numerals, from its greatest to its smallest letter.
+///
+/// The thousands are written as the number of thousands followed by the letter worth a thousand:
+///
+/// ```
+/// use arabic_script::{to_abjad, AbjadSystem, ArabicCharacter};
+///
+/// let abjad = |n| -> String {
+///     to_abjad(n, AbjadSystem::Mashriqi).iter().map(ArabicCharacter::to_string).collect()
+/// };
+///
+/// assert_eq!(abjad(1446), "غتمو");
+/// assert_eq!(abjad(2024), "بغكد");
+/// ```
+///
+/// Zero has no abjad numeral, and is written with no letters at all.
+pub fn to_abjad(number: u32, system: AbjadSystem) -> Vec<ArabicCharacter> {
+    let mut letters = Vec::new();
+
+    let thousands = number / 1000;
+    if thousands > 1 {
+        letters.extend(to_abjad(thousands, system));
+    }
+    if thousands > 0 {
+        letters.extend(system.letter(1000));
+    }
+
+    for unit in [100, 10, 1] {
+        let digit = number % (unit * 10) / unit;
+        if digit > 0 {
+            letters.extend(system.letter(digit * unit));
+        }
+    }
+
+    letters
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::abjad_word_value;
+    use crate::to_abjad;
+    use crate::AbjadSystem::*;
+    use crate::ArabicCharacter;
+    use crate::ArabicLetterAlef;
+    use crate::ArabicLetterGhain;
+    use crate::ArabicLetterSheen;
+    use crate::ArabicLetterYeh;
+    use crate::ArabicShadda;
+
+    fn abjad(number: u32, system: crate::AbjadSystem) -> String {
+        to_abjad(number, system)
+            .iter()
+            .map(ArabicCharacter::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn values_letters() {
+        assert_eq!(ArabicLetterAlef.abjad_value(), Some(1));
+        assert_eq!(ArabicLetterYeh.abjad_value(), Some(10));
+        assert_eq!(ArabicLetterGhain.abjad_value(), Some(1000));
+        assert_eq!(ArabicShadda.abjad_value(), None);
+
+        assert_eq!(Maghrebi.value(ArabicLetterSheen), Some(1000));
+        assert_eq!(Maghrebi.value(ArabicLetterGhain), Some(900));
+    }
+
+    #[test]
+    fn values_words() {
+        assert_eq!(abjad_word_value("مُحَمَّد", Mashriqi), 92);
+        assert_eq!(abjad_word_value("رحمة الله", Mashriqi), 253 + 66);
+    }
+
+    #[test]
+    fn writes_numbers() {
+        assert_eq!(abjad(0, Mashriqi), "");
+        assert_eq!(abjad(11, Mashriqi), "يا");
+        assert_eq!(abjad(1000, Mashriqi), "غ");
+        assert_eq!(abjad(1000, Maghrebi), "ش");
+        assert_eq!(abjad(3999, Mashriqi), "جغظصط");
+        assert_eq!(abjad(366, Maghrebi), "سصو");
+    }
+
+    #[test]
+    fn round_trips_numbers_below_two_thousand() {
+        for number in 1..2000 {
+            for system in [Mashriqi, Maghrebi] {
+                assert_eq!(abjad_word_value(&abjad(number, system), system), number);
+            }
+        }
+    }
+}
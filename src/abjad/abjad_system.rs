@@ -0,0 +1,132 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicCharacter;
+use crate::ArabicLetterAin;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterAlefMaksura;
+use crate::ArabicLetterAlefWithHamzaAbove;
+use crate::ArabicLetterAlefWithHamzaBelow;
+use crate::ArabicLetterAlefWithMaddaAbove;
+use crate::ArabicLetterBeh;
+use crate::ArabicLetterDad;
+use crate::ArabicLetterDal;
+use crate::ArabicLetterFeh;
+use crate::ArabicLetterGhain;
+use crate::ArabicLetterHah;
+use crate::ArabicLetterHamza;
+use crate::ArabicLetterHeh;
+use crate::ArabicLetterJeem;
+use crate::ArabicLetterKaf;
+use crate::ArabicLetterKhah;
+use crate::ArabicLetterLam;
+use crate::ArabicLetterMeem;
+use crate::ArabicLetterNoon;
+use crate::ArabicLetterQaf;
+use crate::ArabicLetterReh;
+use crate::ArabicLetterSad;
+use crate::ArabicLetterSeen;
+use crate::ArabicLetterSheen;
+use crate::ArabicLetterTah;
+use crate::ArabicLetterTeh;
+use crate::ArabicLetterTehMarbuta;
+use crate::ArabicLetterThal;
+use crate::ArabicLetterTheh;
+use crate::ArabicLetterWaw;
+use crate::ArabicLetterWawWithHamzaAbove;
+use crate::ArabicLetterYeh;
+use crate::ArabicLetterYehWithHamzaAbove;
+use crate::ArabicLetterZah;
+use crate::ArabicLetterZain;
+
+/// The values of the twenty-eight letters, in the Mashriqi and the Maghrebi systems.
+pub(crate) const VALUES: [(ArabicCharacter, u32, u32); 28] = [
+    (ArabicLetterAlef, 1, 1),
+    (ArabicLetterBeh, 2, 2),
+    (ArabicLetterJeem, 3, 3),
+    (ArabicLetterDal, 4, 4),
+    (ArabicLetterHeh, 5, 5),
+    (ArabicLetterWaw, 6, 6),
+    (ArabicLetterZain, 7, 7),
+    (ArabicLetterHah, 8, 8),
+    (ArabicLetterTah, 9, 9),
+    (ArabicLetterYeh, 10, 10),
+    (ArabicLetterKaf, 20, 20),
+    (ArabicLetterLam, 30, 30),
+    (ArabicLetterMeem, 40, 40),
+    (ArabicLetterNoon, 50, 50),
+    (ArabicLetterSeen, 60, 300),
+    (ArabicLetterAin, 70, 70),
+    (ArabicLetterFeh, 80, 80),
+    (ArabicLetterSad, 90, 60),
+    (ArabicLetterQaf, 100, 100),
+    (ArabicLetterReh, 200, 200),
+    (ArabicLetterSheen, 300, 1000),
+    (ArabicLetterTeh, 400, 400),
+    (ArabicLetterTheh, 500, 500),
+    (ArabicLetterKhah, 600, 600),
+    (ArabicLetterThal, 700, 700),
+    (ArabicLetterDad, 800, 90),
+    (ArabicLetterZah, 900, 800),
+    (ArabicLetterGhain, 1000, 900),
+];
+
+/// Represents a system of abjad numerals.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum AbjadSystem {
+    /// The Eastern system, following the order أبجد هوز حطي كلمن سعفص قرشت ثخذ ضظغ.
+    #[default]
+    Mashriqi,
+
+    /// The Western system, following the order أبجد هوز حطي كلمن صعفض قرست ثخذ ظغش.
+    Maghrebi,
+}
+
+impl AbjadSystem {
+    /// Returns the value of a letter, or [`None`] if the character is not a letter.
+    ///
+    /// The letters carrying a hamza count as their seat, the Arabic Letter Hamza counts as an
+    /// alef, the Arabic Letter Teh Marbuta as a heh, and the Arabic Letter Alef Maksura as a yeh.
+    pub fn value(&self, character: ArabicCharacter) -> Option<u32> {
+        let letter = match character {
+            ArabicLetterHamza
+            | ArabicLetterAlefWithMaddaAbove
+            | ArabicLetterAlefWithHamzaAbove
+            | ArabicLetterAlefWithHamzaBelow => ArabicLetterAlef,
+            ArabicLetterWawWithHamzaAbove => ArabicLetterWaw,
+            ArabicLetterYehWithHamzaAbove | ArabicLetterAlefMaksura => ArabicLetterYeh,
+            ArabicLetterTehMarbuta => ArabicLetterHeh,
+            c => c,
+        };
+
+        VALUES
+            .iter()
+            .find(|(l, _, _)| l == &letter)
+            .map(|(_, mashriqi, maghrebi)| match self {
+                AbjadSystem::Mashriqi => *mashriqi,
+                AbjadSystem::Maghrebi => *maghrebi,
+            })
+    }
+
+    /// Returns the letter of a value, or [`None`] if no letter has this exact value.
+    pub fn letter(&self, value: u32) -> Option<ArabicCharacter> {
+        VALUES
+            .iter()
+            .find(|(_, mashriqi, maghrebi)| match self {
+                AbjadSystem::Mashriqi => *mashriqi == value,
+                AbjadSystem::Maghrebi => *maghrebi == value,
+            })
+            .map(|(letter, _, _)| *letter)
+    }
+}
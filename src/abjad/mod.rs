@@ -0,0 +1,23 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+//! The **Abjad** module implements the abjad numerals (_hisab al-jummal_), which assign a
+//! numerical value to each letter.
+
+pub use abjad_numerals::abjad_word_value;
+pub use abjad_numerals::to_abjad;
+pub use abjad_system::AbjadSystem;
+
+mod abjad_numerals;
+mod abjad_system;
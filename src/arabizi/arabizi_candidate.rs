@@ -0,0 +1,36 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use std::fmt::{Display, Formatter};
+
+use crate::ArabicCharacter;
+
+/// Represents a candidate spelling, in the Arabic script, of a word written in Arabizi.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArabiziCandidate {
+    /// The characters of the candidate spelling.
+    pub characters: Vec<ArabicCharacter>,
+
+    /// The cost of the candidate spelling, where lower costs denote likelier spellings.
+    pub cost: u32,
+}
+
+impl Display for ArabiziCandidate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for character in &self.characters {
+            write!(f, "{}", character)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,415 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicCharacter;
+use crate::ArabicLetterAin;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterAlefMaksura;
+use crate::ArabicLetterAlefWithHamzaAbove;
+use crate::ArabicLetterAlefWithHamzaBelow;
+use crate::ArabicLetterAlefWithMaddaAbove;
+use crate::ArabicLetterBeh;
+use crate::ArabicLetterDad;
+use crate::ArabicLetterDal;
+use crate::ArabicLetterFeh;
+use crate::ArabicLetterGhain;
+use crate::ArabicLetterHah;
+use crate::ArabicLetterHamza;
+use crate::ArabicLetterHeh;
+use crate::ArabicLetterJeem;
+use crate::ArabicLetterKaf;
+use crate::ArabicLetterKhah;
+use crate::ArabicLetterLam;
+use crate::ArabicLetterMeem;
+use crate::ArabicLetterNoon;
+use crate::ArabicLetterQaf;
+use crate::ArabicLetterReh;
+use crate::ArabicLetterSad;
+use crate::ArabicLetterSeen;
+use crate::ArabicLetterSheen;
+use crate::ArabicLetterTah;
+use crate::ArabicLetterTeh;
+use crate::ArabicLetterTehMarbuta;
+use crate::ArabicLetterThal;
+use crate::ArabicLetterTheh;
+use crate::ArabicLetterWaw;
+use crate::ArabicLetterWawWithHamzaAbove;
+use crate::ArabicLetterYeh;
+use crate::ArabicLetterYehWithHamzaAbove;
+use crate::ArabicLetterZah;
+use crate::ArabicLetterZain;
+use crate::ArabicShadda;
+use crate::ArabiziCandidate;
+use crate::ArabiziProfile;
+use crate::UnicodeCharacter;
+
+/// The number of partial candidates kept at each step of the conversion of a word.
+const BEAM_WIDTH: usize = 32;
+
+/// The number of candidates returned for a word.
+const CANDIDATES: usize = 8;
+
+/// The multi-symbol units of Arabizi, matched before any single symbol.
+const DIGRAPHS: [&str; 15] = [
+    "3'", "6'", "9'", "sh", "ch", "kh", "gh", "th", "dh", "aa", "ee", "ii", "oo", "ou", "uu",
+];
+
+/// The adverbs whose final `an` is the tanween of the accusative, written with an alef.
+const ADVERBS: [&str; 16] = [
+    "7aqqan", "abadan", "afwan", "ahlan", "aslan", "aydan", "daiman", "fe3lan", "fi3lan", "jiddan",
+    "mar7aban", "mathalan", "shukran", "tab3an", "tamaman", "wa7edan",
+];
+
+/// Represents the position of a unit within a word.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Position {
+    Initial,
+    Medial,
+    Final,
+}
+
+/// Converts a single Arabizi word into its candidate spellings in the Arabic script, ranked from
+/// the most to the least likely.
+///
+/// ```
+/// use arabic_script::{convert_arabizi_word, ArabiziProfile};
+///
+/// let candidates = convert_arabizi_word("7abibi", ArabiziProfile::Levantine);
+///
+/// assert_eq!(candidates[0].to_string(), "حبيبي");
+/// ```
+///
+/// Symbols that have no reading in Arabizi are skipped.
+pub fn convert_arabizi_word(word: &str, profile: ArabiziProfile) -> Vec<ArabiziCandidate> {
+    let word = word.to_lowercase();
+    let units = read_units(&word);
+    let mut beam = vec![ArabiziCandidate {
+        characters: Vec::new(),
+        cost: 0,
+    }];
+
+    for (index, unit) in units.iter().enumerate() {
+        let position = match index {
+            0 => Position::Initial,
+            i if i == units.len() - 1 => Position::Final,
+            _ => Position::Medial,
+        };
+        let doubled = index > 0 && units[index - 1] == *unit && !is_vowel(unit);
+        let readings = if doubled {
+            vec![(vec![], 0), (vec![ArabicShadda], 1)]
+        } else {
+            readings(unit, position, profile)
+        };
+
+        if readings.is_empty() {
+            continue;
+        }
+
+        let mut next = Vec::with_capacity(beam.len() * readings.len());
+        for candidate in &beam {
+            for (characters, cost) in &readings {
+                let mut extended = candidate.clone();
+                extended.characters.extend(characters);
+                extended.cost += cost;
+                next.push(extended);
+            }
+        }
+
+        next.sort_by_key(|candidate| candidate.cost);
+        next.truncate(BEAM_WIDTH);
+        beam = next;
+    }
+
+    let mut candidates: Vec<ArabiziCandidate> = Vec::with_capacity(CANDIDATES);
+    for candidate in beam {
+        if !candidates
+            .iter()
+            .any(|c| c.characters == candidate.characters)
+        {
+            candidates.push(candidate);
+        }
+    }
+    candidates.truncate(CANDIDATES);
+    candidates
+}
+
+/// Converts Arabizi text into the Arabic script, using the most likely candidate of each word.
+///
+/// ```
+/// use arabic_script::{convert_arabizi, ArabiziProfile};
+///
+/// assert_eq!(convert_arabizi("3arabi, 2ana!", ArabiziProfile::Standard), "عربي, أنا!");
+/// ```
+///
+/// Runs of characters other than ASCII letters, digits, and apostrophes are left untouched, as are
+/// the words with no letter, such as numbers, and those with a letter or a digit that has no
+/// reading in Arabizi:
+///
+/// ```
+/// use arabic_script::{convert_arabizi, ArabiziProfile};
+///
+/// assert_eq!(convert_arabizi("sana 2024", ArabiziProfile::Standard), "سنا 2024");
+/// ```
+pub fn convert_arabizi(text: &str, profile: ArabiziProfile) -> String {
+    let mut converted = String::with_capacity(text.len() * 2);
+    let mut word = String::new();
+
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() || c == '\'' {
+            word.push(c);
+            continue;
+        }
+
+        convert_word(&word, profile, &mut converted);
+        word.clear();
+        converted.push(c);
+    }
+    convert_word(&word, profile, &mut converted);
+
+    converted
+}
+
+/// Pushes the most likely candidate of a word, or the word itself if it is not read as Arabizi.
+fn convert_word(word: &str, profile: ArabiziProfile, converted: &mut String) {
+    let unread = !word.chars().any(|c| c.is_ascii_alphabetic())
+        || word.chars().any(|c| {
+            c != '\'' && readings(c.encode_utf8(&mut [0; 4]), Position::Medial, profile).is_empty()
+        });
+
+    if unread {
+        converted.push_str(word);
+    } else if let Some(candidate) = convert_arabizi_word(word, profile).first() {
+        converted.extend(candidate.characters.iter().map(|c| c.scalar_value()));
+    }
+}
+
+/// Splits a word into its Arabizi units.
+///
+/// The final `an` of an adverb such as shukran or ahlan is read as a single unit, the tanween of
+/// the accusative.
+fn read_units(word: &str) -> Vec<&str> {
+    let mut units: Vec<&str> = Vec::new();
+    let mut rest = word;
+
+    while let Some(c) = rest.chars().next() {
+        if rest == "an" && ADVERBS.contains(&word) {
+            units.push(rest);
+            break;
+        }
+
+        let length = DIGRAPHS
+            .iter()
+            .find(|digraph| rest.starts_with(*digraph))
+            .map_or(c.len_utf8(), |digraph| digraph.len());
+
+        units.push(&rest[..length]);
+        rest = &rest[length..];
+    }
+
+    units
+}
+
+/// Returns `true` if the unit spells a vowel.
+fn is_vowel(unit: &str) -> bool {
+    unit.starts_with(['a', 'e', 'i', 'o', 'u'])
+}
+
+/// Returns the possible readings of a unit, each with its cost.
+fn readings(
+    unit: &str,
+    position: Position,
+    profile: ArabiziProfile,
+) -> Vec<(Vec<ArabicCharacter>, u32)> {
+    use ArabiziProfile::*;
+    use Position::*;
+
+    let readings: &[(&[ArabicCharacter], u32)] = match (unit, position, profile) {
+        ("a", Initial, _) => &[
+            (&[ArabicLetterAlefWithHamzaAbove], 0),
+            (&[ArabicLetterAlef], 1),
+            (&[ArabicLetterAin], 2),
+        ],
+        ("a", Medial, _) => &[(&[], 0), (&[ArabicLetterAlef], 1)],
+        ("a", Final, Egyptian) => &[
+            (&[ArabicLetterTehMarbuta], 0),
+            (&[ArabicLetterAlef], 1),
+            (&[ArabicLetterAlefMaksura], 1),
+        ],
+        ("a", Final, _) => &[
+            (&[ArabicLetterAlef], 0),
+            (&[ArabicLetterTehMarbuta], 1),
+            (&[ArabicLetterAlefMaksura], 2),
+        ],
+        ("aa", Initial, _) => &[
+            (&[ArabicLetterAlefWithMaddaAbove], 0),
+            (&[ArabicLetterAlef], 1),
+        ],
+        ("aa", _, _) => &[(&[ArabicLetterAlef], 0)],
+        ("an", _, _) => &[(&[ArabicLetterAlef], 0), (&[ArabicLetterNoon], 1)],
+        ("e" | "i", Initial, _) => &[
+            (&[ArabicLetterAlefWithHamzaBelow], 0),
+            (&[ArabicLetterAlef], 1),
+        ],
+        ("e", Medial, _) => &[(&[], 0), (&[ArabicLetterYeh], 1)],
+        ("e", Final, Levantine) => &[(&[ArabicLetterTehMarbuta], 0), (&[ArabicLetterYeh], 1)],
+        ("e", Final, _) => &[(&[ArabicLetterYeh], 0), (&[ArabicLetterTehMarbuta], 1)],
+        ("i", Medial, _) => &[(&[ArabicLetterYeh], 0), (&[], 1)],
+        ("i", Final, _) | ("ee" | "ii", _, _) => &[(&[ArabicLetterYeh], 0)],
+        ("o" | "u", Initial, _) => &[
+            (&[ArabicLetterAlefWithHamzaAbove], 0),
+            (&[ArabicLetterAlefWithHamzaAbove, ArabicLetterWaw], 1),
+        ],
+        ("o" | "u", Medial, _) => &[(&[], 0), (&[ArabicLetterWaw], 1)],
+        ("o" | "u", Final, _) | ("oo" | "ou" | "uu", _, _) => &[(&[ArabicLetterWaw], 0)],
+        ("2", Initial, _) => &[
+            (&[ArabicLetterAlefWithHamzaAbove], 0),
+            (&[ArabicLetterAlefWithHamzaBelow], 1),
+        ],
+        ("2", _, Levantine | Egyptian) => &[
+            (&[ArabicLetterHamza], 0),
+            (&[ArabicLetterQaf], 1),
+            (&[ArabicLetterAlefWithHamzaAbove], 1),
+            (&[ArabicLetterYehWithHamzaAbove], 2),
+            (&[ArabicLetterWawWithHamzaAbove], 2),
+        ],
+        ("2", _, _) => &[
+            (&[ArabicLetterHamza], 0),
+            (&[ArabicLetterAlefWithHamzaAbove], 1),
+            (&[ArabicLetterYehWithHamzaAbove], 2),
+            (&[ArabicLetterWawWithHamzaAbove], 2),
+        ],
+        ("3", _, _) => &[(&[ArabicLetterAin], 0)],
+        ("3'" | "gh", _, _) => &[(&[ArabicLetterGhain], 0)],
+        ("5" | "kh", _, _) => &[(&[ArabicLetterKhah], 0)],
+        ("6", _, _) => &[(&[ArabicLetterTah], 0)],
+        ("6'", _, _) => &[(&[ArabicLetterZah], 0)],
+        ("7", _, _) => &[(&[ArabicLetterHah], 0)],
+        ("8", _, _) => &[(&[ArabicLetterGhain], 0), (&[ArabicLetterQaf], 1)],
+        ("9", _, Gulf) => &[(&[ArabicLetterQaf], 0), (&[ArabicLetterSad], 1)],
+        ("9", _, _) => &[(&[ArabicLetterQaf], 0)],
+        ("9'", _, _) => &[(&[ArabicLetterDad], 0)],
+        ("b" | "p", _, _) => &[(&[ArabicLetterBeh], 0)],
+        ("ch", _, Maghrebi | Levantine) => &[(&[ArabicLetterSheen], 0)],
+        ("ch", _, _) => &[(&[ArabicLetterSheen], 0), (&[ArabicLetterKaf], 1)],
+        ("c" | "k", _, _) => &[(&[ArabicLetterKaf], 0)],
+        ("d", _, _) => &[(&[ArabicLetterDal], 0), (&[ArabicLetterDad], 2)],
+        ("dh", _, _) => &[
+            (&[ArabicLetterThal], 0),
+            (&[ArabicLetterZah], 1),
+            (&[ArabicLetterDad], 2),
+        ],
+        ("f" | "v", _, _) => &[(&[ArabicLetterFeh], 0)],
+        ("g", _, Egyptian) => &[(&[ArabicLetterJeem], 0), (&[ArabicLetterQaf], 2)],
+        ("g", _, Gulf) => &[(&[ArabicLetterQaf], 0), (&[ArabicLetterJeem], 1)],
+        ("g", _, _) => &[(&[ArabicLetterJeem], 0), (&[ArabicLetterGhain], 1)],
+        ("h", _, _) => &[(&[ArabicLetterHeh], 0), (&[ArabicLetterHah], 1)],
+        ("j", _, _) => &[(&[ArabicLetterJeem], 0)],
+        ("l", _, _) => &[(&[ArabicLetterLam], 0)],
+        ("m", _, _) => &[(&[ArabicLetterMeem], 0)],
+        ("n", _, _) => &[(&[ArabicLetterNoon], 0)],
+        ("q", _, _) => &[(&[ArabicLetterQaf], 0)],
+        ("r", _, _) => &[(&[ArabicLetterReh], 0)],
+        ("s", _, _) => &[(&[ArabicLetterSeen], 0), (&[ArabicLetterSad], 2)],
+        ("sh", _, _) => &[(&[ArabicLetterSheen], 0)],
+        ("t", _, _) => &[(&[ArabicLetterTeh], 0), (&[ArabicLetterTah], 2)],
+        ("th", _, _) => &[(&[ArabicLetterTheh], 0), (&[ArabicLetterThal], 1)],
+        ("w", _, _) => &[(&[ArabicLetterWaw], 0)],
+        ("y", _, _) => &[(&[ArabicLetterYeh], 0)],
+        ("z", _, _) => &[(&[ArabicLetterZain], 0), (&[ArabicLetterZah], 2)],
+        _ => &[],
+    };
+
+    readings
+        .iter()
+        .map(|(characters, cost)| (characters.to_vec(), *cost))
+        .collect()
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::convert_arabizi;
+    use crate::convert_arabizi_word;
+    use crate::ArabiziProfile::*;
+
+    fn spellings(word: &str, profile: crate::ArabiziProfile) -> Vec<String> {
+        convert_arabizi_word(word, profile)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn converts_digits() {
+        assert_eq!(convert_arabizi("3arabi", Standard), "عربي");
+        assert_eq!(convert_arabizi("5ubz", Standard), "خبز");
+        assert_eq!(convert_arabizi("9alb", Standard), "قلب");
+        assert_eq!(convert_arabizi("2ana", Standard), "أنا");
+    }
+
+    #[test]
+    fn converts_digraphs() {
+        assert_eq!(convert_arabizi("shukran", Standard), "شكرا");
+        assert_eq!(convert_arabizi("ahlan", Standard), "أهلا");
+        assert_eq!(convert_arabizi("khubz", Standard), "خبز");
+        assert_eq!(convert_arabizi("ghali", Standard), "غلي");
+    }
+
+    #[test]
+    fn reads_the_tanween_of_adverbs_only() {
+        assert_eq!(convert_arabizi("mar7aban", Standard), "مرحبا");
+        assert_eq!(convert_arabizi("lubnan", Standard), "لبنن");
+        assert_eq!(convert_arabizi("3adnan", Standard), "عدنن");
+        assert_eq!(convert_arabizi("sha3ban", Standard), "شعبن");
+        assert!(spellings("lubnan", Standard).contains(&"لبنان".to_string()));
+    }
+
+    #[test]
+    fn passes_numbers_through() {
+        assert_eq!(convert_arabizi("2024", Standard), "2024");
+        assert_eq!(convert_arabizi("covid19", Standard), "covid19");
+        assert_eq!(convert_arabizi("7 kutub", Standard), "7 كتب");
+        assert_eq!(convert_arabizi("x", Standard), "x");
+        assert_eq!(convert_arabizi("xbox 3arabi", Standard), "xbox عربي");
+        assert_eq!(convert_arabizi("a\0b", Standard), "أ\0ب");
+    }
+
+    #[test]
+    fn ranks_ambiguous_candidates() {
+        let candidates = convert_arabizi_word("salam", Standard);
+
+        assert!(candidates.windows(2).all(|w| w[0].cost <= w[1].cost));
+        assert_eq!(candidates[0].to_string(), "سلم");
+        assert!(spellings("salam", Standard).contains(&"سلام".to_string()));
+    }
+
+    #[test]
+    fn applies_dialect_profiles() {
+        assert_eq!(convert_arabizi("gamal", Egyptian), "جمل");
+        assert_eq!(convert_arabizi("gamal", Gulf), "قمل");
+        assert_eq!(convert_arabizi("7elwe", Levantine), "حلوة");
+        assert_eq!(convert_arabizi("7elwa", Egyptian), "حلوة");
+    }
+
+    #[test]
+    fn reads_doubled_consonants_as_shadda() {
+        let spellings = spellings("yalla", Standard);
+
+        assert_eq!(spellings[0], "يلا");
+        assert!(spellings.contains(&"يلّا".to_string()));
+    }
+}
@@ -0,0 +1,34 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+/// Represents the dialect whose Arabizi conventions are used to read a text.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ArabiziProfile {
+    /// The conventions shared by most writers, with no dialectal readings.
+    #[default]
+    Standard,
+
+    /// The Egyptian conventions, where `g` reads as a jeem and a final `a` as a teh marbuta.
+    Egyptian,
+
+    /// The Levantine conventions, where `2` may stand for a qaf and a final `e` reads as a teh
+    /// marbuta.
+    Levantine,
+
+    /// The Gulf conventions, where `g` reads as a qaf and `9` may stand for a sad.
+    Gulf,
+
+    /// The Maghrebi conventions, where `ch` reads as a sheen.
+    Maghrebi,
+}
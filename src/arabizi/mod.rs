@@ -0,0 +1,24 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+//! The **Arabizi** module converts the Arabic chat alphabet into the Arabic script.
+
+pub use arabizi_candidate::ArabiziCandidate;
+pub use arabizi_converter::convert_arabizi;
+pub use arabizi_converter::convert_arabizi_word;
+pub use arabizi_profile::ArabiziProfile;
+
+mod arabizi_candidate;
+mod arabizi_converter;
+mod arabizi_profile;
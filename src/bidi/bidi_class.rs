@@ -0,0 +1,268 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+/// Represents the bidirectional class of a character, as defined by the Unicode Bidirectional
+/// Algorithm (UAX #9).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BidiClass {
+    /// A strong left-to-right character, such as a Latin letter (L).
+    LeftToRight,
+
+    /// A strong right-to-left character, such as a Hebrew letter (R).
+    RightToLeft,
+
+    /// A strong right-to-left character of the Arabic script, such as an Arabic letter (AL).
+    ArabicLetter,
+
+    /// A European digit, or an Extended Arabic-Indic digit (EN).
+    EuropeanNumber,
+
+    /// A plus or a minus sign (ES).
+    EuropeanSeparator,
+
+    /// A currency symbol, a degree or a percent sign (ET).
+    EuropeanTerminator,
+
+    /// An Arabic-Indic digit, or an Arabic separator of numbers (AN).
+    ArabicNumber,
+
+    /// A separator common to numbers, such as the comma, the full stop, and the colon (CS).
+    CommonSeparator,
+
+    /// A combining mark, such as the Arabic short vowels (NSM).
+    NonspacingMark,
+
+    /// A control or a formatting character that does not bear on the direction (BN).
+    BoundaryNeutral,
+
+    /// A paragraph separator, such as the line feed (B).
+    ParagraphSeparator,
+
+    /// A segment separator, such as the tab (S).
+    SegmentSeparator,
+
+    /// A space (WS).
+    WhiteSpace,
+
+    /// Any other neutral character, such as most punctuation (ON).
+    OtherNeutral,
+
+    /// The Left-to-Right Embedding (LRE).
+    LeftToRightEmbedding,
+
+    /// The Left-to-Right Override (LRO).
+    LeftToRightOverride,
+
+    /// The Right-to-Left Embedding (RLE).
+    RightToLeftEmbedding,
+
+    /// The Right-to-Left Override (RLO).
+    RightToLeftOverride,
+
+    /// The Pop Directional Format (PDF).
+    PopDirectionalFormat,
+
+    /// The Left-to-Right Isolate (LRI).
+    LeftToRightIsolate,
+
+    /// The Right-to-Left Isolate (RLI).
+    RightToLeftIsolate,
+
+    /// The First Strong Isolate (FSI).
+    FirstStrongIsolate,
+
+    /// The Pop Directional Isolate (PDI).
+    PopDirectionalIsolate,
+}
+
+impl BidiClass {
+    /// Returns the bidirectional class of a character.
+    ///
+    /// The classes are exact for the Latin-1, Arabic, Hebrew, and General Punctuation blocks, and
+    /// for the Arabic presentation forms. Other letters are read as left-to-right, other spaces as
+    /// white space, and the remaining characters as neutral.
+    pub fn of(character: char) -> Self {
+        use BidiClass::*;
+
+        match character {
+            '\t' | '\u{000B}' | '\u{001F}' => SegmentSeparator,
+            '\n' | '\r' | '\u{001C}'..='\u{001E}' | '\u{0085}' | '\u{2029}' => ParagraphSeparator,
+            '\u{0000}'..='\u{0008}' | '\u{000E}'..='\u{001B}' | '\u{007F}'..='\u{009F}' => {
+                BoundaryNeutral
+            }
+            '\u{000C}' | ' ' | '\u{2000}'..='\u{200A}' | '\u{2028}' | '\u{205F}' | '\u{3000}' => {
+                WhiteSpace
+            }
+            '0'..='9' | '²' | '³' | '¹' | '\u{06F0}'..='\u{06F9}' | '\u{2070}' => EuropeanNumber,
+            '\u{2074}'..='\u{2079}' | '\u{2080}'..='\u{2089}' | '\u{FF10}'..='\u{FF19}' => {
+                EuropeanNumber
+            }
+            '+' | '-' | '\u{207A}' | '\u{207B}' | '\u{208A}' | '\u{208B}' | '\u{2212}' => {
+                EuropeanSeparator
+            }
+            '\u{FE62}' | '\u{FE63}' | '\u{FF0B}' | '\u{FF0D}' => EuropeanSeparator,
+            '#' | '$' | '%' | '¢'..='¥' | '°' | '±' | '\u{0609}' | '\u{060A}' | '\u{066A}' => {
+                EuropeanTerminator
+            }
+            '\u{2030}'..='\u{2034}' | '\u{20A0}'..='\u{20CF}' | '\u{FF03}'..='\u{FF05}' => {
+                EuropeanTerminator
+            }
+            '\u{0600}'..='\u{0605}' | '\u{0660}'..='\u{0669}' | '\u{066B}' | '\u{066C}' => {
+                ArabicNumber
+            }
+            '\u{06DD}' | '\u{0890}' | '\u{0891}' | '\u{08E2}' => ArabicNumber,
+            ',' | '.' | '/' | ':' | '\u{00A0}' | '\u{060C}' | '\u{202F}' | '\u{2044}' => {
+                CommonSeparator
+            }
+            '\u{0300}'..='\u{036F}' | '\u{0591}'..='\u{05BD}' | '\u{05BF}' | '\u{05C1}' => {
+                NonspacingMark
+            }
+            '\u{05C2}' | '\u{05C4}' | '\u{05C5}' | '\u{05C7}' | '\u{0610}'..='\u{061A}' => {
+                NonspacingMark
+            }
+            '\u{064B}'..='\u{065F}' | '\u{0670}' | '\u{06D6}'..='\u{06DC}' => NonspacingMark,
+            '\u{06DF}'..='\u{06E4}' | '\u{06E7}' | '\u{06E8}' | '\u{06EA}'..='\u{06ED}' => {
+                NonspacingMark
+            }
+            '\u{0898}'..='\u{089F}' | '\u{08CA}'..='\u{08E1}' | '\u{08E3}'..='\u{08FF}' => {
+                NonspacingMark
+            }
+            '\u{FE00}'..='\u{FE0F}' | '\u{FE20}'..='\u{FE2F}' => NonspacingMark,
+            '\u{00AD}' | '\u{180E}' | '\u{200B}'..='\u{200D}' | '\u{2060}'..='\u{2064}' => {
+                BoundaryNeutral
+            }
+            '\u{206A}'..='\u{206F}' | '\u{FEFF}' => BoundaryNeutral,
+            '\u{200E}' => LeftToRight,
+            '\u{200F}' | '\u{0590}'..='\u{05FF}' | '\u{07C0}'..='\u{085F}' => RightToLeft,
+            '\u{FB1D}'..='\u{FB4F}' => RightToLeft,
+            '\u{0606}' | '\u{0607}' | '\u{060E}' | '\u{060F}' | '\u{06DE}' | '\u{06E9}' => {
+                OtherNeutral
+            }
+            '\u{FD3E}' | '\u{FD3F}' => OtherNeutral,
+            '\u{0600}'..='\u{07BF}' | '\u{0860}'..='\u{08FF}' | '\u{FB50}'..='\u{FDFF}' => {
+                ArabicLetter
+            }
+            '\u{FE70}'..='\u{FEFE}' => ArabicLetter,
+            '\u{202A}' => LeftToRightEmbedding,
+            '\u{202B}' => RightToLeftEmbedding,
+            '\u{202C}' => PopDirectionalFormat,
+            '\u{202D}' => LeftToRightOverride,
+            '\u{202E}' => RightToLeftOverride,
+            '\u{2066}' => LeftToRightIsolate,
+            '\u{2067}' => RightToLeftIsolate,
+            '\u{2068}' => FirstStrongIsolate,
+            '\u{2069}' => PopDirectionalIsolate,
+            c if c.is_alphanumeric() => LeftToRight,
+            c if c.is_whitespace() => WhiteSpace,
+            _ => OtherNeutral,
+        }
+    }
+
+    /// Returns the abbreviation of the class in the Unicode Character Database, such as `AL`.
+    pub fn abbreviation(&self) -> &'static str {
+        use BidiClass::*;
+
+        match self {
+            LeftToRight => "L",
+            RightToLeft => "R",
+            ArabicLetter => "AL",
+            EuropeanNumber => "EN",
+            EuropeanSeparator => "ES",
+            EuropeanTerminator => "ET",
+            ArabicNumber => "AN",
+            CommonSeparator => "CS",
+            NonspacingMark => "NSM",
+            BoundaryNeutral => "BN",
+            ParagraphSeparator => "B",
+            SegmentSeparator => "S",
+            WhiteSpace => "WS",
+            OtherNeutral => "ON",
+            LeftToRightEmbedding => "LRE",
+            LeftToRightOverride => "LRO",
+            RightToLeftEmbedding => "RLE",
+            RightToLeftOverride => "RLO",
+            PopDirectionalFormat => "PDF",
+            LeftToRightIsolate => "LRI",
+            RightToLeftIsolate => "RLI",
+            FirstStrongIsolate => "FSI",
+            PopDirectionalIsolate => "PDI",
+        }
+    }
+
+    /// Returns `true` if the class is one of the strong classes, L, R, and AL.
+    pub fn is_strong(&self) -> bool {
+        matches!(
+            self,
+            BidiClass::LeftToRight | BidiClass::RightToLeft | BidiClass::ArabicLetter
+        )
+    }
+
+    /// Returns `true` if the class is one of the isolate initiators, LRI, RLI, and FSI.
+    pub(crate) fn is_isolate_initiator(&self) -> bool {
+        matches!(
+            self,
+            BidiClass::LeftToRightIsolate
+                | BidiClass::RightToLeftIsolate
+                | BidiClass::FirstStrongIsolate
+        )
+    }
+
+    /// Returns `true` if the class is removed from the resolution of the levels by the rule X9.
+    pub(crate) fn is_removed(&self) -> bool {
+        matches!(
+            self,
+            BidiClass::LeftToRightEmbedding
+                | BidiClass::RightToLeftEmbedding
+                | BidiClass::LeftToRightOverride
+                | BidiClass::RightToLeftOverride
+                | BidiClass::PopDirectionalFormat
+                | BidiClass::BoundaryNeutral
+        )
+    }
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::ArabicFatha;
+    use crate::ArabicIndicDigitOne;
+    use crate::ArabicLetterBeh;
+    use crate::BidiClass;
+    use crate::ExtendedArabicIndicDigitOne;
+
+    #[test]
+    fn classifies_characters() {
+        assert_eq!(BidiClass::of('a'), BidiClass::LeftToRight);
+        assert_eq!(BidiClass::of('א'), BidiClass::RightToLeft);
+        assert_eq!(BidiClass::of('،'), BidiClass::CommonSeparator);
+        assert_eq!(BidiClass::of('\u{061C}'), BidiClass::ArabicLetter);
+        assert_eq!(BidiClass::of('\u{2067}'), BidiClass::RightToLeftIsolate);
+        assert_eq!(BidiClass::of('$'), BidiClass::EuropeanTerminator);
+    }
+
+    #[test]
+    fn classifies_arabic_characters() {
+        assert_eq!(ArabicLetterBeh.bidi_class(), BidiClass::ArabicLetter);
+        assert_eq!(ArabicFatha.bidi_class(), BidiClass::NonspacingMark);
+        assert_eq!(ArabicIndicDigitOne.bidi_class(), BidiClass::ArabicNumber);
+        assert_eq!(
+            ExtendedArabicIndicDigitOne.bidi_class(),
+            BidiClass::EuropeanNumber
+        );
+        assert_eq!(ArabicFatha.bidi_class().abbreviation(), "NSM");
+    }
+}
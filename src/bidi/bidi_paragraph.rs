@@ -0,0 +1,176 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use std::ops::Range;
+
+use crate::BidiClass;
+
+/// The characters mirrored in right-to-left text, each with its mirror image.
+const MIRRORS: [(char, char); 14] = [
+    ('(', ')'),
+    (')', '('),
+    ('<', '>'),
+    ('>', '<'),
+    ('[', ']'),
+    (']', '['),
+    ('{', '}'),
+    ('}', '{'),
+    ('«', '»'),
+    ('»', '«'),
+    ('‹', '›'),
+    ('›', '‹'),
+    ('≤', '≥'),
+    ('≥', '≤'),
+];
+
+/// Represents a paragraph whose embedding levels were resolved by the Unicode Bidirectional
+/// Algorithm, ready to be reordered line by line.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BidiParagraph {
+    characters: Vec<char>,
+    classes: Vec<BidiClass>,
+    levels: Vec<u8>,
+    level: u8,
+}
+
+impl BidiParagraph {
+    /// Returns a new paragraph from its characters, their classes, and their resolved levels.
+    pub(crate) fn new(
+        characters: Vec<char>,
+        classes: Vec<BidiClass>,
+        levels: Vec<u8>,
+        level: u8,
+    ) -> Self {
+        Self {
+            characters,
+            classes,
+            levels,
+            level,
+        }
+    }
+
+    /// Returns the embedding level of the paragraph: 0 if it is left-to-right, or 1 if it is
+    /// right-to-left.
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// Returns the resolved embedding level of each character of the paragraph, before the lines
+    /// are reordered.
+    pub fn levels(&self) -> &[u8] {
+        &self.levels
+    }
+
+    /// Returns the bidirectional class of each character of the paragraph.
+    pub fn classes(&self) -> &[BidiClass] {
+        &self.classes
+    }
+
+    /// Returns the indices of the characters of a line of the paragraph, in visual order from left
+    /// to right.
+    ///
+    /// The line is given as a range of character indices, so that a paragraph wrapped over several
+    /// lines is reordered one line at a time. The range is clamped to the paragraph, and a
+    /// reversed range is read as an empty line.
+    pub fn visual_order(&self, line: Range<usize>) -> Vec<usize> {
+        let line = self.clamp(line);
+        let levels = self.line_levels(line.clone());
+        let mut order: Vec<usize> = line.clone().collect();
+
+        let highest = levels.iter().copied().max().unwrap_or_default();
+        let Some(lowest_odd) = levels.iter().copied().filter(|l| l % 2 == 1).min() else {
+            return order;
+        };
+
+        for level in (lowest_odd..=highest).rev() {
+            let mut position = 0;
+            while position < order.len() {
+                if levels[order[position] - line.start] < level {
+                    position += 1;
+                    continue;
+                }
+
+                let start = position;
+                while position < order.len() && levels[order[position] - line.start] >= level {
+                    position += 1;
+                }
+                order[start..position].reverse();
+            }
+        }
+
+        order
+    }
+
+    /// Returns a line of the paragraph in visual order, with the brackets and the other mirrored
+    /// characters of the right-to-left runs replaced by their mirror images.
+    ///
+    /// The line is clamped to the paragraph, as in [`BidiParagraph::visual_order()`].
+    pub fn reorder_line(&self, line: Range<usize>) -> String {
+        let line = self.clamp(line);
+        let levels = self.line_levels(line.clone());
+
+        self.visual_order(line.clone())
+            .into_iter()
+            .map(|index| {
+                let character = self.characters[index];
+                if levels[index - line.start] % 2 == 1 {
+                    return MIRRORS
+                        .iter()
+                        .find(|(c, _)| *c == character)
+                        .map_or(character, |(_, mirror)| *mirror);
+                }
+                character
+            })
+            .collect()
+    }
+
+    /// Returns the whole paragraph, as a single line, in visual order.
+    pub fn visual(&self) -> String {
+        self.reorder_line(0..self.characters.len())
+    }
+
+    /// Returns a line clamped to the characters of the paragraph.
+    fn clamp(&self, line: Range<usize>) -> Range<usize> {
+        let end = line.end.min(self.characters.len());
+        line.start.min(end)..end
+    }
+
+    /// Returns the levels of a line, with the separators and the trailing white space reset to
+    /// the level of the paragraph (rule L1).
+    fn line_levels(&self, line: Range<usize>) -> Vec<u8> {
+        let mut levels = self.levels[line.clone()].to_vec();
+        let mut trailing = true;
+
+        for (offset, index) in line.enumerate().rev() {
+            let class = self.classes[index];
+            match class {
+                BidiClass::SegmentSeparator | BidiClass::ParagraphSeparator => {
+                    levels[offset] = self.level;
+                    trailing = true;
+                }
+                _ if trailing
+                    && (class == BidiClass::WhiteSpace
+                        || class == BidiClass::PopDirectionalIsolate
+                        || class.is_isolate_initiator()
+                        || class.is_removed()) =>
+                {
+                    levels[offset] = self.level
+                }
+                _ => trailing = false,
+            }
+        }
+
+        levels
+    }
+}
@@ -0,0 +1,228 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::bidi::explicit_levels::explicit_levels;
+use crate::bidi::explicit_levels::first_strong_level;
+use crate::bidi::explicit_levels::isolating_run_sequences;
+use crate::bidi::explicit_levels::matching_isolates;
+use crate::bidi::implicit_levels::resolve_sequence;
+use crate::bidi::implicit_levels::RunSequence;
+use crate::BidiClass;
+use crate::BidiParagraph;
+use crate::ParagraphDirection;
+
+/// Resolves the embedding levels of paragraphs by the Unicode Bidirectional Algorithm (UAX #9),
+/// and reorders their lines for display.
+///
+/// Text is stored in logical order, the order it is read in, while terminals and most PDF
+/// libraries lay characters out from left to right. Reordering a line puts its right-to-left runs
+/// in the order they are displayed in:
+///
+/// ```
+/// use arabic_script::BidiResolver;
+///
+/// let resolver = BidiResolver::new();
+///
+/// assert_eq!(resolver.reorder("المجموع: 1,234.50 USD"), "USD 1,234.50 :عومجملا");
+/// assert_eq!(resolver.reorder("Total: ١٢٣ ريال"), "Total: لاير ١٢٣");
+/// ```
+///
+/// The explicit embeddings, overrides, and isolates are honoured, and the paired brackets are
+/// resolved to the direction of their content.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct BidiResolver {
+    direction: ParagraphDirection,
+}
+
+impl BidiResolver {
+    /// Returns a new resolver, that takes the direction of each paragraph from its first strong
+    /// character.
+    pub fn new() -> Self {
+        Self {
+            direction: ParagraphDirection::Auto,
+        }
+    }
+
+    /// Returns the resolver, with the given direction of the paragraphs.
+    pub fn with_direction(mut self, direction: ParagraphDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Returns the direction of the paragraphs.
+    pub fn direction(&self) -> ParagraphDirection {
+        self.direction
+    }
+
+    /// Resolves the embedding levels of a paragraph.
+    pub fn resolve(&self, text: &str) -> BidiParagraph {
+        let characters: Vec<char> = text.chars().collect();
+        let original: Vec<BidiClass> = characters.iter().map(|c| BidiClass::of(*c)).collect();
+        let matching = matching_isolates(&original);
+
+        let level = match self.direction {
+            ParagraphDirection::Auto => first_strong_level(&original).unwrap_or(0),
+            ParagraphDirection::LeftToRight => 0,
+            ParagraphDirection::RightToLeft => 1,
+        };
+
+        let mut classes = original.clone();
+        let mut levels = explicit_levels(&mut classes, level, &matching);
+
+        let sequences = isolating_run_sequences(&original, &levels, &matching);
+        let edges: Vec<(BidiClass, BidiClass)> = sequences
+            .iter()
+            .map(|sequence| edges(sequence, &original, &levels, level))
+            .collect();
+
+        for (indices, (start, end)) in sequences.iter().zip(edges) {
+            let sequence = RunSequence {
+                indices,
+                start,
+                end,
+            };
+            resolve_sequence(&sequence, &characters, &original, &mut classes, &mut levels);
+        }
+
+        for index in 0..levels.len() {
+            if original[index].is_removed() {
+                levels[index] = index.checked_sub(1).map_or(level, |i| levels[i]);
+            }
+        }
+
+        BidiParagraph::new(characters, original, levels, level)
+    }
+
+    /// Reorders a text for display, each of its lines as a paragraph of its own.
+    pub fn reorder(&self, text: &str) -> String {
+        text.split('\n')
+            .map(|line| match line.strip_suffix('\r') {
+                Some(line) => self.resolve(line).visual() + "\r",
+                None => self.resolve(line).visual(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Returns the classes of the start and of the end of an isolating run sequence, from the levels
+/// of the sequence and of the characters around it.
+fn edges(
+    sequence: &[usize],
+    classes: &[BidiClass],
+    levels: &[u8],
+    paragraph_level: u8,
+) -> (BidiClass, BidiClass) {
+    let first = sequence[0];
+    let last = sequence[sequence.len() - 1];
+    let level = levels[first];
+
+    let before = (0..first)
+        .rev()
+        .find(|index| !classes[*index].is_removed())
+        .map_or(paragraph_level, |index| levels[index]);
+    let after = if classes[last].is_isolate_initiator() {
+        paragraph_level
+    } else {
+        (last + 1..classes.len())
+            .find(|index| !classes[*index].is_removed())
+            .map_or(paragraph_level, |index| levels[index])
+    };
+
+    let direction = |level: u8| {
+        if level % 2 == 1 {
+            BidiClass::RightToLeft
+        } else {
+            BidiClass::LeftToRight
+        }
+    };
+
+    (direction(level.max(before)), direction(level.max(after)))
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use crate::BidiResolver;
+    use crate::ParagraphDirection;
+
+    #[test]
+    fn resolves_levels() {
+        let resolver = BidiResolver::new();
+
+        assert_eq!(resolver.resolve("abc ابت").levels(), [0, 0, 0, 0, 1, 1, 1]);
+        assert_eq!(resolver.resolve("ابت 12").levels(), [1, 1, 1, 1, 2, 2]);
+        assert_eq!(resolver.resolve("ابت abc").level(), 1);
+        assert_eq!(
+            resolver
+                .with_direction(ParagraphDirection::LeftToRight)
+                .resolve("ابت")
+                .level(),
+            0
+        );
+    }
+
+    #[test]
+    fn reorders_mixed_lines() {
+        let resolver = BidiResolver::new();
+
+        assert_eq!(resolver.reorder("abc ابت 123"), "abc 123 تبا");
+        assert_eq!(resolver.reorder("ابت\nabc"), "تبا\nabc");
+        assert_eq!(resolver.reorder("السعر 15% فقط"), "طقف %15 رعسلا");
+    }
+
+    #[test]
+    fn mirrors_brackets() {
+        let resolver = BidiResolver::new();
+
+        assert_eq!(resolver.reorder("ا(ب)"), "(ب)ا");
+        assert_eq!(resolver.reorder("ابت (abc)"), "(abc) تبا");
+    }
+
+    #[test]
+    fn honours_explicit_formatting() {
+        let resolver = BidiResolver::new();
+
+        assert_eq!(
+            resolver.reorder("a \u{2067}ب ج\u{2069} d"),
+            "a \u{2067}ج ب\u{2069} d"
+        );
+        assert_eq!(
+            resolver.reorder("\u{202E}abc\u{202C}"),
+            "\u{202E}cba\u{202C}"
+        );
+    }
+
+    #[test]
+    fn reorders_wrapped_lines() {
+        let paragraph = BidiResolver::new().resolve("ابت ثجح");
+
+        assert_eq!(paragraph.reorder_line(0..4), " تبا");
+        assert_eq!(paragraph.visual_order(4..7), [6, 5, 4]);
+    }
+
+    #[test]
+    fn clamps_lines_to_the_paragraph() {
+        let paragraph = BidiResolver::new().resolve("abc");
+
+        assert_eq!(paragraph.visual_order(0..10), [0, 1, 2]);
+        assert_eq!(paragraph.visual_order(5..10), []);
+        assert_eq!(paragraph.reorder_line(Range { start: 2, end: 1 }), "");
+    }
+}
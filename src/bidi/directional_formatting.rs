@@ -0,0 +1,336 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicLetterMark;
+use crate::BidiClass;
+use crate::ParagraphDirection;
+use crate::UnicodeCharacter;
+
+/// The Left-to-Right Mark, an invisible strong left-to-right character.
+const LEFT_TO_RIGHT_MARK: char = '\u{200E}';
+
+/// The Right-to-Left Mark, an invisible strong right-to-left character.
+const RIGHT_TO_LEFT_MARK: char = '\u{200F}';
+
+/// Wraps a fragment in an isolate, so that it is laid out on its own and does not affect, nor is
+/// affected by, the text it is interpolated into.
+///
+/// The fragment is opened by a First Strong Isolate (U+2068) if the direction is automatic, a
+/// Left-to-Right Isolate (U+2066), or a Right-to-Left Isolate (U+2067), and closed by a Pop
+/// Directional Isolate (U+2069):
+///
+/// ```
+/// use arabic_script::isolate_fragment;
+/// use arabic_script::BidiResolver;
+/// use arabic_script::ParagraphDirection;
+///
+/// let resolver = BidiResolver::new();
+///
+/// assert_eq!(resolver.reorder("قال Hello!"), "!Hello لاق");
+/// assert_eq!(
+///     resolver.reorder(&format!("قال {}", isolate_fragment("Hello!", ParagraphDirection::Auto))),
+///     "\u{2069}Hello!\u{2068} لاق"
+/// );
+/// ```
+pub fn isolate_fragment(fragment: &str, direction: ParagraphDirection) -> String {
+    let initiator = match direction {
+        ParagraphDirection::Auto => '\u{2068}',
+        ParagraphDirection::LeftToRight => '\u{2066}',
+        ParagraphDirection::RightToLeft => '\u{2067}',
+    };
+
+    format!("{initiator}{fragment}\u{2069}")
+}
+
+/// Inserts directional marks after the punctuation that would otherwise be displayed on the wrong
+/// side of a run, and before the signs that would otherwise be detached from their numbers.
+///
+/// Punctuation between two runs of opposite directions, or between a run and the end of a
+/// paragraph of the opposite direction, takes the direction of the paragraph. A mark of the
+/// direction of the run preceding the punctuation is inserted after it, so that it stays with that
+/// run: the Arabic Letter Mark (U+061C) after Arabic, the Right-to-Left Mark (U+200F) after other
+/// right-to-left scripts, and the Left-to-Right Mark (U+200E) after left-to-right scripts.
+///
+/// ```
+/// use arabic_script::insert_directional_marks;
+/// use arabic_script::ParagraphDirection;
+///
+/// assert_eq!(
+///     insert_directional_marks("مرحبا! John", ParagraphDirection::LeftToRight),
+///     "مرحبا!\u{061C} John"
+/// );
+/// assert_eq!(
+///     insert_directional_marks("قال Hello!", ParagraphDirection::Auto),
+///     "قال Hello!\u{200E}"
+/// );
+/// ```
+///
+/// A number, with its separators and its sign, is a run of the direction of the text around it, or
+/// of the paragraph at its start. In right-to-left text, its leading sign is preceded by an Arabic
+/// Letter Mark, as [`NumberFormatter`](crate::NumberFormatter) does:
+///
+/// ```
+/// use arabic_script::insert_directional_marks;
+/// use arabic_script::ParagraphDirection;
+///
+/// assert_eq!(
+///     insert_directional_marks("-5 درجات", ParagraphDirection::RightToLeft),
+///     "\u{061C}-5 درجات"
+/// );
+/// ```
+///
+/// Isolated fragments are left untouched, and treated as punctuation.
+pub fn insert_directional_marks(text: &str, direction: ParagraphDirection) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut paragraph = paragraph_class(direction);
+    let mut previous: Option<BidiClass> = None;
+    let mut mark_at: Option<usize> = None;
+    let mut sign_at: Option<usize> = None;
+    let mut number = false;
+    let mut depth = 0;
+
+    for character in text.chars() {
+        let class = BidiClass::of(character);
+        let sign = sign_at.take();
+
+        if depth > 0 || class.is_isolate_initiator() {
+            if class.is_isolate_initiator() {
+                depth += 1;
+            } else if class == BidiClass::PopDirectionalIsolate {
+                depth -= 1;
+            }
+            result.push(character);
+            mark_at = Some(result.len());
+            number = false;
+            continue;
+        }
+
+        match class {
+            BidiClass::LeftToRight | BidiClass::RightToLeft | BidiClass::ArabicLetter => {
+                if let (Some(previous), Some(at)) = (previous, mark_at) {
+                    insert_mark(&mut result, at, previous, class);
+                }
+                paragraph = paragraph.or(Some(class));
+                previous = Some(class);
+                mark_at = None;
+                number = false;
+            }
+            BidiClass::EuropeanNumber | BidiClass::ArabicNumber if !number => {
+                let context = previous.or(paragraph);
+                let run = context
+                    .or((class == BidiClass::ArabicNumber).then_some(BidiClass::RightToLeft));
+
+                if let Some(sign) = sign {
+                    if mark_at == Some(result.len()) {
+                        mark_at = Some(sign);
+                    }
+                    let right_to_left = context.is_some_and(|c| c != BidiClass::LeftToRight);
+                    let mark = ArabicLetterMark.scalar_value();
+                    if right_to_left && !result[..sign].ends_with(mark) {
+                        result.insert(sign, mark);
+                    }
+                }
+                if let (Some(previous), Some(at), Some(run)) = (previous, mark_at, run) {
+                    insert_mark(&mut result, at, previous, run);
+                }
+                previous = run.or(previous);
+                mark_at = None;
+                number = true;
+            }
+            BidiClass::EuropeanNumber | BidiClass::ArabicNumber => mark_at = None,
+            BidiClass::ParagraphSeparator => {
+                if let (Some(previous), Some(at), Some(paragraph)) = (previous, mark_at, paragraph)
+                {
+                    insert_mark(&mut result, at, previous, paragraph);
+                }
+                paragraph = paragraph_class(direction);
+                previous = None;
+                mark_at = None;
+                number = false;
+            }
+            BidiClass::CommonSeparator
+            | BidiClass::EuropeanSeparator
+            | BidiClass::EuropeanTerminator => {
+                if !number && is_sign(character) {
+                    sign_at = Some(result.len());
+                }
+                mark_at = Some(result.len() + character.len_utf8());
+            }
+            BidiClass::OtherNeutral => {
+                mark_at = Some(result.len() + character.len_utf8());
+                number = false;
+            }
+            BidiClass::NonspacingMark if mark_at == Some(result.len()) => {
+                mark_at = Some(result.len() + character.len_utf8())
+            }
+            _ => number = false,
+        }
+
+        result.push(character);
+    }
+
+    if let (Some(previous), Some(at), Some(paragraph)) = (previous, mark_at, paragraph) {
+        insert_mark(&mut result, at, previous, paragraph);
+    }
+
+    result
+}
+
+/// Returns `true` if the character is the sign of a number.
+fn is_sign(character: char) -> bool {
+    matches!(character, '+' | '-' | '\u{2212}')
+}
+
+/// Returns the class of a strong character of the given paragraph direction, or [`None`] if it
+/// is taken from the first strong character of the paragraph.
+fn paragraph_class(direction: ParagraphDirection) -> Option<BidiClass> {
+    match direction {
+        ParagraphDirection::Auto => None,
+        ParagraphDirection::LeftToRight => Some(BidiClass::LeftToRight),
+        ParagraphDirection::RightToLeft => Some(BidiClass::RightToLeft),
+    }
+}
+
+/// Inserts the mark of the direction of the preceding run at the given position, if the
+/// direction that follows is the opposite one.
+fn insert_mark(text: &mut String, at: usize, previous: BidiClass, next: BidiClass) {
+    let left_to_right = |class: BidiClass| class == BidiClass::LeftToRight;
+    if left_to_right(previous) == left_to_right(next) {
+        return;
+    }
+
+    let mark = match previous {
+        BidiClass::LeftToRight => LEFT_TO_RIGHT_MARK,
+        BidiClass::ArabicLetter => ArabicLetterMark.scalar_value(),
+        _ => RIGHT_TO_LEFT_MARK,
+    };
+    text.insert(at, mark);
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::insert_directional_marks;
+    use crate::isolate_fragment;
+    use crate::BidiResolver;
+    use crate::ParagraphDirection;
+
+    #[test]
+    fn isolates_fragments() {
+        assert_eq!(
+            isolate_fragment("ABC-123", ParagraphDirection::Auto),
+            "\u{2068}ABC-123\u{2069}"
+        );
+        assert_eq!(
+            isolate_fragment("ABC-123", ParagraphDirection::LeftToRight),
+            "\u{2066}ABC-123\u{2069}"
+        );
+        assert_eq!(
+            isolate_fragment("محمد", ParagraphDirection::RightToLeft),
+            "\u{2067}محمد\u{2069}"
+        );
+    }
+
+    #[test]
+    fn inserts_marks() {
+        let ltr = ParagraphDirection::LeftToRight;
+        let rtl = ParagraphDirection::RightToLeft;
+
+        assert_eq!(
+            insert_directional_marks("مرحبا! John", ltr),
+            "مرحبا!\u{061C} John"
+        );
+        assert_eq!(
+            insert_directional_marks("שלום! John", ltr),
+            "שלום!\u{200F} John"
+        );
+        assert_eq!(
+            insert_directional_marks("قال Hello!", rtl),
+            "قال Hello!\u{200E}"
+        );
+        assert_eq!(
+            insert_directional_marks("قال Hello!\nقال", ParagraphDirection::Auto),
+            "قال Hello!\u{200E}\nقال"
+        );
+    }
+
+    #[test]
+    fn marks_interpolated_numbers() {
+        let ltr = ParagraphDirection::LeftToRight;
+        let rtl = ParagraphDirection::RightToLeft;
+
+        assert_eq!(
+            insert_directional_marks(&format!("{} درجات", -5), rtl),
+            "\u{061C}-5 درجات"
+        );
+        assert_eq!(
+            insert_directional_marks(&format!("الحرارة {} اليوم", -5), rtl),
+            "الحرارة \u{061C}-5 اليوم"
+        );
+        assert_eq!(
+            insert_directional_marks(&format!("{}، John", 15), rtl),
+            "15،\u{200F} John"
+        );
+        assert_eq!(
+            insert_directional_marks(&format!("{}! مرحبا", "١٥"), ltr),
+            "١٥!\u{200E} مرحبا"
+        );
+        assert_eq!(insert_directional_marks("Hello -5", rtl), "Hello -5");
+        assert_eq!(insert_directional_marks("5-3 درجات", rtl), "5-3 درجات");
+        assert_eq!(
+            insert_directional_marks("\u{061C}-٥ درجات", rtl),
+            "\u{061C}-٥ درجات"
+        );
+    }
+
+    #[test]
+    fn keeps_punctuation_with_its_number() {
+        let resolver = BidiResolver::new().with_direction(ParagraphDirection::LeftToRight);
+        let marked = insert_directional_marks("١٥! مرحبا", ParagraphDirection::LeftToRight);
+
+        assert_eq!(resolver.reorder("١٥! مرحبا"), "ابحرم !١٥");
+        assert_eq!(resolver.reorder(&marked), "١٥!\u{200E} ابحرم");
+    }
+
+    #[test]
+    fn leaves_text_without_conflicts() {
+        let auto = ParagraphDirection::Auto;
+
+        assert_eq!(insert_directional_marks("مرحبا! أهلا", auto), "مرحبا! أهلا");
+        assert_eq!(
+            insert_directional_marks("Hello, world.", auto),
+            "Hello, world."
+        );
+        assert_eq!(
+            insert_directional_marks("العدد: 15 USD", auto),
+            "العدد: 15 USD"
+        );
+        assert_eq!(
+            insert_directional_marks("قال \u{2068}Hello!\u{2069}", auto),
+            "قال \u{2068}Hello!\u{2069}"
+        );
+    }
+
+    #[test]
+    fn keeps_punctuation_with_its_run() {
+        let resolver = BidiResolver::new().with_direction(ParagraphDirection::LeftToRight);
+        let marked = insert_directional_marks("مرحبا! John", ParagraphDirection::LeftToRight);
+
+        assert_eq!(resolver.reorder("مرحبا! John"), "ابحرم! John");
+        assert_eq!(resolver.reorder(&marked), "\u{061C}!ابحرم John");
+    }
+}
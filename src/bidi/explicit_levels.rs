@@ -0,0 +1,226 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::BidiClass;
+
+/// The deepest embedding level.
+const MAX_DEPTH: u8 = 125;
+
+/// Represents an entry of the directional status stack.
+#[derive(Clone, Copy)]
+struct Status {
+    level: u8,
+    override_class: Option<BidiClass>,
+    isolate: bool,
+}
+
+/// Returns the level of the first strong character, skipping the isolates, or [`None`] if there
+/// is none (rules P2 and P3).
+pub(crate) fn first_strong_level(classes: &[BidiClass]) -> Option<u8> {
+    let mut depth = 0;
+
+    for class in classes {
+        match class {
+            c if c.is_isolate_initiator() => depth += 1,
+            BidiClass::PopDirectionalIsolate if depth > 0 => depth -= 1,
+            BidiClass::ParagraphSeparator => break,
+            BidiClass::LeftToRight if depth == 0 => return Some(0),
+            BidiClass::RightToLeft | BidiClass::ArabicLetter if depth == 0 => return Some(1),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Returns, for each isolate initiator, the index of its matching isolate terminator (rule BD9).
+pub(crate) fn matching_isolates(classes: &[BidiClass]) -> Vec<Option<usize>> {
+    let mut matching = vec![None; classes.len()];
+    let mut open = Vec::new();
+
+    for (index, class) in classes.iter().enumerate() {
+        match class {
+            c if c.is_isolate_initiator() => open.push(index),
+            BidiClass::PopDirectionalIsolate => {
+                if let Some(initiator) = open.pop() {
+                    matching[initiator] = Some(index);
+                }
+            }
+            BidiClass::ParagraphSeparator => open.clear(),
+            _ => {}
+        }
+    }
+
+    matching
+}
+
+/// Resolves the explicit embedding levels, applying the directional overrides to the classes
+/// (rules X1 to X8).
+pub(crate) fn explicit_levels(
+    classes: &mut [BidiClass],
+    paragraph_level: u8,
+    matching: &[Option<usize>],
+) -> Vec<u8> {
+    use BidiClass::*;
+
+    let mut levels = vec![paragraph_level; classes.len()];
+    let mut stack = vec![Status {
+        level: paragraph_level,
+        override_class: None,
+        isolate: false,
+    }];
+    let mut overflow_isolates = 0;
+    let mut overflow_embeddings = 0;
+    let mut valid_isolates = 0;
+
+    for index in 0..classes.len() {
+        let class = classes[index];
+        let top = stack[stack.len() - 1];
+
+        match class {
+            RightToLeftEmbedding | LeftToRightEmbedding | RightToLeftOverride
+            | LeftToRightOverride => {
+                levels[index] = top.level;
+                let rtl = matches!(class, RightToLeftEmbedding | RightToLeftOverride);
+                let level = next_level(top.level, rtl);
+
+                if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    stack.push(Status {
+                        level,
+                        override_class: match class {
+                            RightToLeftOverride => Some(RightToLeft),
+                            LeftToRightOverride => Some(LeftToRight),
+                            _ => None,
+                        },
+                        isolate: false,
+                    });
+                } else if overflow_isolates == 0 {
+                    overflow_embeddings += 1;
+                }
+            }
+            RightToLeftIsolate | LeftToRightIsolate | FirstStrongIsolate => {
+                levels[index] = top.level;
+                if let Some(override_class) = top.override_class {
+                    classes[index] = override_class;
+                }
+
+                let rtl = match class {
+                    RightToLeftIsolate => true,
+                    LeftToRightIsolate => false,
+                    _ => {
+                        let end = matching[index].unwrap_or(classes.len());
+                        first_strong_level(&classes[index + 1..end]) == Some(1)
+                    }
+                };
+                let level = next_level(top.level, rtl);
+
+                if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    valid_isolates += 1;
+                    stack.push(Status {
+                        level,
+                        override_class: None,
+                        isolate: true,
+                    });
+                } else {
+                    overflow_isolates += 1;
+                }
+            }
+            PopDirectionalIsolate => {
+                if overflow_isolates > 0 {
+                    overflow_isolates -= 1;
+                } else if valid_isolates > 0 {
+                    overflow_embeddings = 0;
+                    while !stack[stack.len() - 1].isolate {
+                        stack.pop();
+                    }
+                    stack.pop();
+                    valid_isolates -= 1;
+                }
+
+                let top = stack[stack.len() - 1];
+                levels[index] = top.level;
+                if let Some(override_class) = top.override_class {
+                    classes[index] = override_class;
+                }
+            }
+            PopDirectionalFormat => {
+                levels[index] = top.level;
+                if overflow_isolates == 0 {
+                    if overflow_embeddings > 0 {
+                        overflow_embeddings -= 1;
+                    } else if !top.isolate && stack.len() > 1 {
+                        stack.pop();
+                    }
+                }
+            }
+            ParagraphSeparator => levels[index] = paragraph_level,
+            BoundaryNeutral => levels[index] = top.level,
+            _ => {
+                levels[index] = top.level;
+                if let Some(override_class) = top.override_class {
+                    classes[index] = override_class;
+                }
+            }
+        }
+    }
+
+    levels
+}
+
+/// Returns the isolating run sequences of a paragraph, as the indices of their characters (rules
+/// X10 and BD13).
+pub(crate) fn isolating_run_sequences(
+    classes: &[BidiClass],
+    levels: &[u8],
+    matching: &[Option<usize>],
+) -> Vec<Vec<usize>> {
+    let mut runs: Vec<Vec<usize>> = Vec::new();
+
+    for index in (0..classes.len()).filter(|index| !classes[*index].is_removed()) {
+        match runs.last_mut() {
+            Some(run) if levels[run[run.len() - 1]] == levels[index] => run.push(index),
+            _ => runs.push(vec![index]),
+        }
+    }
+
+    let mut continued = vec![false; classes.len()];
+    for run in &runs {
+        if let Some(terminator) = matching[run[run.len() - 1]] {
+            continued[terminator] = true;
+        }
+    }
+
+    let mut sequences = Vec::new();
+    for run in runs.iter().filter(|run| !continued[run[0]]) {
+        let mut sequence = run.clone();
+        while let Some(terminator) = matching[sequence[sequence.len() - 1]] {
+            match runs.iter().find(|run| run[0] == terminator) {
+                Some(run) => sequence.extend(run),
+                None => break,
+            }
+        }
+        sequences.push(sequence);
+    }
+
+    sequences
+}
+
+/// Returns the least level above the given one, odd for a right-to-left level or even otherwise.
+fn next_level(level: u8, rtl: bool) -> u8 {
+    if rtl {
+        (level + 1) | 1
+    } else {
+        (level + 2) & !1
+    }
+}
@@ -0,0 +1,293 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::BidiClass;
+
+/// The deepest nesting of bracket pairs that is resolved.
+const MAX_BRACKET_DEPTH: usize = 63;
+
+/// The opening brackets, each with its closing bracket.
+const BRACKETS: [(char, char); 8] = [
+    ('(', ')'),
+    ('[', ']'),
+    ('{', '}'),
+    ('\u{2045}', '\u{2046}'),
+    ('\u{207D}', '\u{207E}'),
+    ('\u{208D}', '\u{208E}'),
+    ('\u{2329}', '\u{232A}'),
+    ('\u{3008}', '\u{3009}'),
+];
+
+/// Represents an isolating run sequence, with the classes of the characters at its edges.
+pub(crate) struct RunSequence<'a> {
+    /// The indices of the characters of the sequence.
+    pub(crate) indices: &'a [usize],
+
+    /// The class of the start of the sequence, either L or R.
+    pub(crate) start: BidiClass,
+
+    /// The class of the end of the sequence, either L or R.
+    pub(crate) end: BidiClass,
+}
+
+/// Resolves the weak types, the neutral types, and the implicit levels of an isolating run
+/// sequence (rules W1 to W7, N0 to N2, and I1 and I2).
+pub(crate) fn resolve_sequence(
+    sequence: &RunSequence,
+    characters: &[char],
+    original: &[BidiClass],
+    classes: &mut [BidiClass],
+    levels: &mut [u8],
+) {
+    let indices = sequence.indices;
+    let embedding = if levels[indices[0]] % 2 == 1 {
+        BidiClass::RightToLeft
+    } else {
+        BidiClass::LeftToRight
+    };
+
+    resolve_weak_types(sequence, classes);
+    resolve_brackets(sequence, embedding, characters, original, classes);
+    resolve_neutral_types(sequence, embedding, classes);
+
+    for &index in indices {
+        let level = levels[index];
+        levels[index] += match (level % 2, classes[index]) {
+            (0, BidiClass::RightToLeft) => 1,
+            (0, BidiClass::ArabicNumber | BidiClass::EuropeanNumber) => 2,
+            (1, BidiClass::LeftToRight | BidiClass::ArabicNumber | BidiClass::EuropeanNumber) => 1,
+            _ => 0,
+        };
+    }
+}
+
+/// Resolves the weak types (rules W1 to W7).
+fn resolve_weak_types(sequence: &RunSequence, classes: &mut [BidiClass]) {
+    use BidiClass::*;
+
+    let indices = sequence.indices;
+
+    let mut previous = sequence.start;
+    for &index in indices {
+        if classes[index] == NonspacingMark {
+            classes[index] = if previous.is_isolate_initiator() || previous == PopDirectionalIsolate
+            {
+                OtherNeutral
+            } else {
+                previous
+            };
+        }
+        previous = classes[index];
+    }
+
+    let mut strong = sequence.start;
+    for &index in indices {
+        match classes[index] {
+            LeftToRight | RightToLeft | ArabicLetter => strong = classes[index],
+            EuropeanNumber if strong == ArabicLetter => classes[index] = ArabicNumber,
+            _ => {}
+        }
+    }
+
+    for &index in indices {
+        if classes[index] == ArabicLetter {
+            classes[index] = RightToLeft;
+        }
+    }
+
+    for window in indices.windows(3) {
+        let (before, after) = (classes[window[0]], classes[window[2]]);
+        match classes[window[1]] {
+            EuropeanSeparator if before == EuropeanNumber && after == EuropeanNumber => {
+                classes[window[1]] = EuropeanNumber
+            }
+            CommonSeparator
+                if before == after && matches!(before, EuropeanNumber | ArabicNumber) =>
+            {
+                classes[window[1]] = before
+            }
+            _ => {}
+        }
+    }
+
+    let mut position = 0;
+    while position < indices.len() {
+        if classes[indices[position]] != EuropeanTerminator {
+            position += 1;
+            continue;
+        }
+
+        let start = position;
+        while position < indices.len() && classes[indices[position]] == EuropeanTerminator {
+            position += 1;
+        }
+        let adjacent = (start > 0 && classes[indices[start - 1]] == EuropeanNumber)
+            || (position < indices.len() && classes[indices[position]] == EuropeanNumber);
+        if adjacent {
+            for &index in &indices[start..position] {
+                classes[index] = EuropeanNumber;
+            }
+        }
+    }
+
+    for &index in indices {
+        if matches!(
+            classes[index],
+            EuropeanSeparator | EuropeanTerminator | CommonSeparator
+        ) {
+            classes[index] = OtherNeutral;
+        }
+    }
+
+    let mut strong = sequence.start;
+    for &index in indices {
+        match classes[index] {
+            LeftToRight | RightToLeft => strong = classes[index],
+            EuropeanNumber if strong == LeftToRight => classes[index] = LeftToRight,
+            _ => {}
+        }
+    }
+}
+
+/// Resolves the paired brackets to the direction of their content or of their context (rule N0).
+fn resolve_brackets(
+    sequence: &RunSequence,
+    embedding: BidiClass,
+    characters: &[char],
+    original: &[BidiClass],
+    classes: &mut [BidiClass],
+) {
+    let indices = sequence.indices;
+
+    for (open, close) in bracket_pairs(indices, characters, classes) {
+        let mut inside = indices[open + 1..close]
+            .iter()
+            .filter_map(|index| direction(classes[*index]));
+
+        let resolved = if inside.clone().any(|d| d == embedding) {
+            embedding
+        } else if inside.next().is_some() {
+            indices[..open]
+                .iter()
+                .rev()
+                .find_map(|index| direction(classes[*index]))
+                .unwrap_or(sequence.start)
+        } else {
+            continue;
+        };
+
+        for bracket in [open, close] {
+            classes[indices[bracket]] = resolved;
+            for &index in indices[bracket + 1..]
+                .iter()
+                .take_while(|index| original[**index] == BidiClass::NonspacingMark)
+            {
+                classes[index] = resolved;
+            }
+        }
+    }
+}
+
+/// Returns the bracket pairs of a sequence, as the positions of their brackets within the
+/// sequence, in the order of their opening brackets (rule BD16).
+fn bracket_pairs(
+    indices: &[usize],
+    characters: &[char],
+    classes: &[BidiClass],
+) -> Vec<(usize, usize)> {
+    let mut open: Vec<(char, usize)> = Vec::new();
+    let mut pairs = Vec::new();
+
+    for (position, &index) in indices.iter().enumerate() {
+        if classes[index] != BidiClass::OtherNeutral {
+            continue;
+        }
+
+        let character = characters[index];
+        if let Some(&(_, closing)) = BRACKETS.iter().find(|(opening, _)| *opening == character) {
+            if open.len() == MAX_BRACKET_DEPTH {
+                break;
+            }
+            open.push((closing, position));
+        } else if let Some(depth) = open.iter().rposition(|(closing, _)| *closing == character) {
+            pairs.push((open[depth].1, position));
+            open.truncate(depth);
+        }
+    }
+
+    pairs.sort_unstable();
+    pairs
+}
+
+/// Resolves the neutral types to the direction of the text around them, or to the embedding
+/// direction (rules N1 and N2).
+fn resolve_neutral_types(sequence: &RunSequence, embedding: BidiClass, classes: &mut [BidiClass]) {
+    let indices = sequence.indices;
+
+    let mut position = 0;
+    while position < indices.len() {
+        if !is_neutral(classes[indices[position]]) {
+            position += 1;
+            continue;
+        }
+
+        let start = position;
+        while position < indices.len() && is_neutral(classes[indices[position]]) {
+            position += 1;
+        }
+
+        let before = match start {
+            0 => sequence.start,
+            _ => direction(classes[indices[start - 1]]).unwrap_or(embedding),
+        };
+        let after = match indices.get(position) {
+            Some(index) => direction(classes[*index]).unwrap_or(embedding),
+            None => sequence.end,
+        };
+        let resolved = if before == after { before } else { embedding };
+
+        for &index in &indices[start..position] {
+            classes[index] = resolved;
+        }
+    }
+}
+
+/// Returns `true` if the class is neutral or an isolate formatting character.
+fn is_neutral(class: BidiClass) -> bool {
+    use BidiClass::*;
+
+    matches!(
+        class,
+        ParagraphSeparator
+            | SegmentSeparator
+            | WhiteSpace
+            | OtherNeutral
+            | LeftToRightIsolate
+            | RightToLeftIsolate
+            | FirstStrongIsolate
+            | PopDirectionalIsolate
+    )
+}
+
+/// Returns the strong direction a resolved class counts as, the numbers counting as
+/// right-to-left.
+fn direction(class: BidiClass) -> Option<BidiClass> {
+    match class {
+        BidiClass::LeftToRight => Some(BidiClass::LeftToRight),
+        BidiClass::RightToLeft | BidiClass::EuropeanNumber | BidiClass::ArabicNumber => {
+            Some(BidiClass::RightToLeft)
+        }
+        _ => None,
+    }
+}
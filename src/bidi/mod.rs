@@ -0,0 +1,31 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+//! The **Bidi** module implements the Unicode Bidirectional Algorithm (UAX #9), which lays out
+//! mixed right-to-left and left-to-right text.
+
+pub use bidi_class::BidiClass;
+pub use bidi_paragraph::BidiParagraph;
+pub use bidi_resolver::BidiResolver;
+pub use directional_formatting::insert_directional_marks;
+pub use directional_formatting::isolate_fragment;
+pub use paragraph_direction::ParagraphDirection;
+
+mod bidi_class;
+mod bidi_paragraph;
+mod bidi_resolver;
+mod directional_formatting;
+mod explicit_levels;
+mod implicit_levels;
+mod paragraph_direction;
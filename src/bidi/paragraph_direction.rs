@@ -0,0 +1,28 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+/// Represents the base direction of a paragraph.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ParagraphDirection {
+    /// The direction of the first strong character of the paragraph, or left-to-right if it has
+    /// none.
+    #[default]
+    Auto,
+
+    /// Left-to-right, whatever the characters of the paragraph.
+    LeftToRight,
+
+    /// Right-to-left, whatever the characters of the paragraph.
+    RightToLeft,
+}
@@ -0,0 +1,181 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use std::cmp::Ordering;
+
+use crate::collation::letter_order::variant;
+use crate::ArabicCharacter;
+use crate::ArabicTatweel;
+use crate::LetterOrder;
+use crate::UnicodeCharacter;
+
+/// Represents the levels at which a string is compared, from the first to the third.
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct SortKey {
+    /// The letters, digits, and other characters of the string.
+    primary: Vec<(u32, u32)>,
+
+    /// The variants of the letters.
+    secondary: Vec<u32>,
+
+    /// The diacritical marks of each character, preceded by those that no character carries.
+    tertiary: Vec<Vec<char>>,
+}
+
+/// Compares Arabic strings in dictionary order.
+///
+/// The strings are compared at three levels. The first compares their letters, ignoring the
+/// diacritical marks and the tatweel, and counting the variants of a letter, such as أ or ة, as
+/// the letter itself. The second breaks the ties on the variants, and the third on the marks:
+///
+/// ```
+/// use arabic_script::Collator;
+///
+/// let collator = Collator::new();
+/// let mut words = vec!["تمر", "ثوب", "بَاب", "أحمد", "ـباب", "اب"];
+/// words.sort_by(|a, b| collator.compare(a, b));
+///
+/// assert_eq!(words, ["اب", "أحمد", "ـباب", "بَاب", "تمر", "ثوب"]);
+/// ```
+///
+/// Other characters sort before the letters, by their scalar value, and the digits of every system
+/// sort as their ASCII counterpart.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Collator {
+    order: LetterOrder,
+}
+
+impl Collator {
+    /// Returns a new collator, that sorts the letters in the hija'i order.
+    pub fn new() -> Self {
+        Self {
+            order: LetterOrder::Hijai,
+        }
+    }
+
+    /// Returns the collator, sorting the letters in the given order.
+    pub fn with_order(mut self, order: LetterOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Compares two strings.
+    pub fn compare(&self, left: &str, right: &str) -> Ordering {
+        self.key(left)
+            .cmp(&self.key(right))
+            .then_with(|| left.cmp(right))
+    }
+
+    /// Returns the key of a string, which orders it level by level.
+    fn key(&self, text: &str) -> SortKey {
+        let mut primary = Vec::new();
+        let mut secondary = Vec::new();
+        let mut tertiary = vec![Vec::new()];
+
+        for c in text.chars() {
+            let character = ArabicCharacter::try_from(c).ok();
+
+            match character {
+                Some(ArabicTatweel) => continue,
+                Some(mark) if mark.is_diacritic() => {
+                    if let Some(marks) = tertiary.last_mut() {
+                        marks.push(mark.scalar_value());
+                        marks.sort_unstable();
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+
+            let letter = character.and_then(|l| Some((self.order.rank(l)?, variant(l)?.1)));
+            let digit = character
+                .and_then(|d| d.digit_value())
+                .or_else(|| c.to_digit(10));
+
+            match (letter, digit) {
+                (Some((rank, variant)), _) => {
+                    primary.push((1, rank));
+                    secondary.push(variant);
+                }
+                (None, Some(digit)) => {
+                    primary.push((0, '0' as u32 + digit));
+                    secondary.push(0);
+                }
+                (None, None) => {
+                    primary.push((0, c as u32));
+                    secondary.push(0);
+                }
+            }
+            tertiary.push(Vec::new());
+        }
+
+        SortKey {
+            primary,
+            secondary,
+            tertiary,
+        }
+    }
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::Collator;
+    use crate::LetterOrder;
+
+    #[test]
+    fn sorts_variants_with_their_letter() {
+        let collator = Collator::new();
+        let mut words = vec!["ثمرة", "تمر", "إسلام", "ادب", "آمال", "تاء", "ة"];
+        words.sort_by(|a, b| collator.compare(a, b));
+
+        assert_eq!(words, ["ادب", "إسلام", "آمال", "ة", "تاء", "تمر", "ثمرة"]);
+    }
+
+    #[test]
+    fn breaks_ties_on_variants_then_marks() {
+        let collator = Collator::new();
+
+        assert_eq!(collator.compare("مدرسة", "مدرسه"), Ordering::Less);
+        assert_eq!(collator.compare("مدرسة", "مدرست"), Ordering::Greater);
+        assert_eq!(collator.compare("علم", "عَلَم"), Ordering::Less);
+        assert_eq!(collator.compare("عَلَم", "عِلْم"), Ordering::Less);
+        assert_eq!(collator.compare("كتاب", "كـتـاب"), Ordering::Less);
+        assert_eq!(collator.compare("كتب", "كَتَبَ"), Ordering::Less);
+        assert_eq!(collator.compare("كَتَبَ", "كتبة"), Ordering::Less);
+    }
+
+    #[test]
+    fn sorts_in_the_abjadi_order() {
+        let collator = Collator::new().with_order(LetterOrder::Abjadi);
+        let mut letters = vec!["غ", "ب", "ه", "ي", "س", "ا", "ض"];
+        letters.sort_by(|a, b| collator.compare(a, b));
+
+        assert_eq!(letters, ["ا", "ب", "ه", "ي", "س", "ض", "غ"]);
+    }
+
+    #[test]
+    fn sorts_digits_by_value() {
+        let collator = Collator::new();
+
+        assert_eq!(collator.compare("٣", "3"), "٣".cmp("3"));
+        assert_eq!(collator.compare("٣", "4"), Ordering::Less);
+        assert_eq!(collator.compare("۹", "ا"), Ordering::Less);
+    }
+}
@@ -0,0 +1,138 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::AbjadSystem;
+use crate::ArabicCharacter;
+use crate::ArabicLetterAin;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterAlefMaksura;
+use crate::ArabicLetterAlefWithHamzaAbove;
+use crate::ArabicLetterAlefWithHamzaBelow;
+use crate::ArabicLetterAlefWithMaddaAbove;
+use crate::ArabicLetterBeh;
+use crate::ArabicLetterDad;
+use crate::ArabicLetterDal;
+use crate::ArabicLetterFeh;
+use crate::ArabicLetterGhain;
+use crate::ArabicLetterHah;
+use crate::ArabicLetterHamza;
+use crate::ArabicLetterHeh;
+use crate::ArabicLetterJeem;
+use crate::ArabicLetterKaf;
+use crate::ArabicLetterKhah;
+use crate::ArabicLetterLam;
+use crate::ArabicLetterMeem;
+use crate::ArabicLetterNoon;
+use crate::ArabicLetterQaf;
+use crate::ArabicLetterReh;
+use crate::ArabicLetterSad;
+use crate::ArabicLetterSeen;
+use crate::ArabicLetterSheen;
+use crate::ArabicLetterTah;
+use crate::ArabicLetterTeh;
+use crate::ArabicLetterTehMarbuta;
+use crate::ArabicLetterThal;
+use crate::ArabicLetterTheh;
+use crate::ArabicLetterWaw;
+use crate::ArabicLetterWawWithHamzaAbove;
+use crate::ArabicLetterYeh;
+use crate::ArabicLetterYehWithHamzaAbove;
+use crate::ArabicLetterZah;
+use crate::ArabicLetterZain;
+
+/// The twenty-eight letters, in the hija'i order.
+const HIJAI: [ArabicCharacter; 28] = [
+    ArabicLetterAlef,
+    ArabicLetterBeh,
+    ArabicLetterTeh,
+    ArabicLetterTheh,
+    ArabicLetterJeem,
+    ArabicLetterHah,
+    ArabicLetterKhah,
+    ArabicLetterDal,
+    ArabicLetterThal,
+    ArabicLetterReh,
+    ArabicLetterZain,
+    ArabicLetterSeen,
+    ArabicLetterSheen,
+    ArabicLetterSad,
+    ArabicLetterDad,
+    ArabicLetterTah,
+    ArabicLetterZah,
+    ArabicLetterAin,
+    ArabicLetterGhain,
+    ArabicLetterFeh,
+    ArabicLetterQaf,
+    ArabicLetterKaf,
+    ArabicLetterLam,
+    ArabicLetterMeem,
+    ArabicLetterNoon,
+    ArabicLetterHeh,
+    ArabicLetterWaw,
+    ArabicLetterYeh,
+];
+
+/// Represents an order of the twenty-eight letters of the alphabet.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum LetterOrder {
+    /// The dictionary order, grouping the letters by shape: ا ب ت ث ج ح خ د ذ ر ز س ش ص ض ط ظ ع غ ف
+    /// ق ك ل م ن ه و ي.
+    #[default]
+    Hijai,
+
+    /// The order of the Mashriqi abjad numerals: أبجد هوز حطي كلمن سعفص قرشت ثخذ ضظغ.
+    Abjadi,
+}
+
+impl LetterOrder {
+    /// Returns the rank of a letter, from 1 to 28, or [`None`] if the character is not a letter.
+    ///
+    /// The hamza and the letters carrying it are ranked as their seat, the hamza on its own as an
+    /// alef, the Arabic Letter Teh Marbuta as a teh, and the Arabic Letter Alef Maksura as a yeh.
+    pub fn rank(&self, character: ArabicCharacter) -> Option<u32> {
+        let (letter, _) = variant(character)?;
+
+        match self {
+            LetterOrder::Hijai => HIJAI
+                .iter()
+                .position(|l| *l == letter)
+                .map(|r| r as u32 + 1),
+            LetterOrder::Abjadi => match AbjadSystem::Mashriqi.value(letter)? {
+                value @ 1..=9 => Some(value),
+                value @ 10..=99 => Some(9 + value / 10),
+                value @ 100..=999 => Some(18 + value / 100),
+                _ => Some(28),
+            },
+        }
+    }
+}
+
+/// Returns the letter of the alphabet a character is a variant of, and the rank of the variant
+/// among the others, the letter itself ranking first.
+pub(crate) fn variant(character: ArabicCharacter) -> Option<(ArabicCharacter, u32)> {
+    let variant = match character {
+        ArabicLetterAlefWithMaddaAbove => (ArabicLetterAlef, 1),
+        ArabicLetterAlefWithHamzaAbove => (ArabicLetterAlef, 2),
+        ArabicLetterAlefWithHamzaBelow => (ArabicLetterAlef, 3),
+        ArabicLetterHamza => (ArabicLetterAlef, 4),
+        ArabicLetterTehMarbuta => (ArabicLetterTeh, 1),
+        ArabicLetterWawWithHamzaAbove => (ArabicLetterWaw, 1),
+        ArabicLetterAlefMaksura => (ArabicLetterYeh, 1),
+        ArabicLetterYehWithHamzaAbove => (ArabicLetterYeh, 2),
+        letter if letter.is_letter() => (letter, 0),
+        _ => return None,
+    };
+
+    Some(variant)
+}
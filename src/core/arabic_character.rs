@@ -15,10 +15,22 @@
 use std::fmt::{Debug, Display, Formatter};
 
 use crate::core::UnicodeCharacter;
+use crate::AbjadSystem;
 use crate::ArabicDamma;
 use crate::ArabicDammatan;
+use crate::ArabicDecimalSeparator;
 use crate::ArabicFatha;
 use crate::ArabicFathatan;
+use crate::ArabicIndicDigitEight;
+use crate::ArabicIndicDigitFive;
+use crate::ArabicIndicDigitFour;
+use crate::ArabicIndicDigitNine;
+use crate::ArabicIndicDigitOne;
+use crate::ArabicIndicDigitSeven;
+use crate::ArabicIndicDigitSix;
+use crate::ArabicIndicDigitThree;
+use crate::ArabicIndicDigitTwo;
+use crate::ArabicIndicDigitZero;
 use crate::ArabicKasra;
 use crate::ArabicKasratan;
 use crate::ArabicLetterAin;
@@ -39,6 +51,7 @@ use crate::ArabicLetterJeem;
 use crate::ArabicLetterKaf;
 use crate::ArabicLetterKhah;
 use crate::ArabicLetterLam;
+use crate::ArabicLetterMark;
 use crate::ArabicLetterMeem;
 use crate::ArabicLetterNoon;
 use crate::ArabicLetterQaf;
@@ -57,9 +70,25 @@ use crate::ArabicLetterYeh;
 use crate::ArabicLetterYehWithHamzaAbove;
 use crate::ArabicLetterZah;
 use crate::ArabicLetterZain;
+use crate::ArabicPercentSign;
 use crate::ArabicShadda;
 use crate::ArabicSukun;
 use crate::ArabicTatweel;
+use crate::ArabicThousandsSeparator;
+use crate::BidiClass;
+use crate::ExtendedArabicIndicDigitEight;
+use crate::ExtendedArabicIndicDigitFive;
+use crate::ExtendedArabicIndicDigitFour;
+use crate::ExtendedArabicIndicDigitNine;
+use crate::ExtendedArabicIndicDigitOne;
+use crate::ExtendedArabicIndicDigitSeven;
+use crate::ExtendedArabicIndicDigitSix;
+use crate::ExtendedArabicIndicDigitThree;
+use crate::ExtendedArabicIndicDigitTwo;
+use crate::ExtendedArabicIndicDigitZero;
+use crate::LetterOrder;
+use crate::Makhraj;
+use crate::Sifat;
 
 /// Represents a character of the Arabic script.
 ///
@@ -77,7 +106,9 @@ use crate::ArabicTatweel;
 ///
 /// Note that the [`ArabicCharacter::try_from()`] method will return an [Error][`Err`] for any
 /// character that does not belong to the Arabic script.
+#[derive(Clone, Copy)]
 pub enum ArabicCharacter {
+    ArabicLetterMark,
     ArabicLetterHamza,
     ArabicLetterAlefWithMaddaAbove,
     ArabicLetterAlefWithHamzaAbove,
@@ -123,12 +154,36 @@ pub enum ArabicCharacter {
     ArabicKasra,
     ArabicShadda,
     ArabicSukun,
+    ArabicIndicDigitZero,
+    ArabicIndicDigitOne,
+    ArabicIndicDigitTwo,
+    ArabicIndicDigitThree,
+    ArabicIndicDigitFour,
+    ArabicIndicDigitFive,
+    ArabicIndicDigitSix,
+    ArabicIndicDigitSeven,
+    ArabicIndicDigitEight,
+    ArabicIndicDigitNine,
+    ArabicPercentSign,
+    ArabicDecimalSeparator,
+    ArabicThousandsSeparator,
+    ExtendedArabicIndicDigitZero,
+    ExtendedArabicIndicDigitOne,
+    ExtendedArabicIndicDigitTwo,
+    ExtendedArabicIndicDigitThree,
+    ExtendedArabicIndicDigitFour,
+    ExtendedArabicIndicDigitFive,
+    ExtendedArabicIndicDigitSix,
+    ExtendedArabicIndicDigitSeven,
+    ExtendedArabicIndicDigitEight,
+    ExtendedArabicIndicDigitNine,
 }
 
 impl ArabicCharacter {
     /// Returns the underlying Unicode character.
     fn character(&self) -> Box<dyn UnicodeCharacter> {
         match self {
+            ArabicLetterMark => Box::new(crate::core::characters::ArabicLetterMark::new()),
             ArabicLetterHamza => Box::new(crate::core::characters::ArabicLetterHamza::new()),
             ArabicLetterAlefWithMaddaAbove => {
                 Box::new(crate::core::characters::ArabicLetterAlefWithMaddaAbove::new())
@@ -188,8 +243,245 @@ impl ArabicCharacter {
             ArabicKasra => Box::new(crate::core::characters::ArabicKasra::new()),
             ArabicShadda => Box::new(crate::core::characters::ArabicShadda::new()),
             ArabicSukun => Box::new(crate::core::characters::ArabicSukun::new()),
+            ArabicIndicDigitZero => Box::new(crate::core::characters::ArabicIndicDigitZero::new()),
+            ArabicIndicDigitOne => Box::new(crate::core::characters::ArabicIndicDigitOne::new()),
+            ArabicIndicDigitTwo => Box::new(crate::core::characters::ArabicIndicDigitTwo::new()),
+            ArabicIndicDigitThree => {
+                Box::new(crate::core::characters::ArabicIndicDigitThree::new())
+            }
+            ArabicIndicDigitFour => Box::new(crate::core::characters::ArabicIndicDigitFour::new()),
+            ArabicIndicDigitFive => Box::new(crate::core::characters::ArabicIndicDigitFive::new()),
+            ArabicIndicDigitSix => Box::new(crate::core::characters::ArabicIndicDigitSix::new()),
+            ArabicIndicDigitSeven => {
+                Box::new(crate::core::characters::ArabicIndicDigitSeven::new())
+            }
+            ArabicIndicDigitEight => {
+                Box::new(crate::core::characters::ArabicIndicDigitEight::new())
+            }
+            ArabicIndicDigitNine => Box::new(crate::core::characters::ArabicIndicDigitNine::new()),
+            ArabicPercentSign => Box::new(crate::core::characters::ArabicPercentSign::new()),
+            ArabicDecimalSeparator => {
+                Box::new(crate::core::characters::ArabicDecimalSeparator::new())
+            }
+            ArabicThousandsSeparator => {
+                Box::new(crate::core::characters::ArabicThousandsSeparator::new())
+            }
+            ExtendedArabicIndicDigitZero => {
+                Box::new(crate::core::characters::ExtendedArabicIndicDigitZero::new())
+            }
+            ExtendedArabicIndicDigitOne => {
+                Box::new(crate::core::characters::ExtendedArabicIndicDigitOne::new())
+            }
+            ExtendedArabicIndicDigitTwo => {
+                Box::new(crate::core::characters::ExtendedArabicIndicDigitTwo::new())
+            }
+            ExtendedArabicIndicDigitThree => {
+                Box::new(crate::core::characters::ExtendedArabicIndicDigitThree::new())
+            }
+            ExtendedArabicIndicDigitFour => {
+                Box::new(crate::core::characters::ExtendedArabicIndicDigitFour::new())
+            }
+            ExtendedArabicIndicDigitFive => {
+                Box::new(crate::core::characters::ExtendedArabicIndicDigitFive::new())
+            }
+            ExtendedArabicIndicDigitSix => {
+                Box::new(crate::core::characters::ExtendedArabicIndicDigitSix::new())
+            }
+            ExtendedArabicIndicDigitSeven => {
+                Box::new(crate::core::characters::ExtendedArabicIndicDigitSeven::new())
+            }
+            ExtendedArabicIndicDigitEight => {
+                Box::new(crate::core::characters::ExtendedArabicIndicDigitEight::new())
+            }
+            ExtendedArabicIndicDigitNine => {
+                Box::new(crate::core::characters::ExtendedArabicIndicDigitNine::new())
+            }
+        }
+    }
+
+    /// Returns the value of the letter in the Mashriqi abjad numerals, or [`None`] if the character
+    /// is not a letter.
+    ///
+    /// See [`AbjadSystem::value()`] for the values of the letters outside the abjad.
+    pub fn abjad_value(&self) -> Option<u32> {
+        AbjadSystem::Mashriqi.value(*self)
+    }
+
+    /// Returns the Arabic name of the character, as it is read aloud, such as باء for the Arabic
+    /// Letter Beh, or فتحة for the Arabic Fatha.
+    ///
+    /// The letters carrying a hamza are named after the hamza and its seat, and the digits after
+    /// their value.
+    pub fn arabic_name(&self) -> &'static str {
+        match self {
+            ArabicLetterMark => "علامة الحرف العربي",
+            ArabicLetterHamza => "همزة",
+            ArabicLetterAlefWithMaddaAbove => "ألف ممدودة",
+            ArabicLetterAlefWithHamzaAbove => "همزة على الألف",
+            ArabicLetterAlefWithHamzaBelow => "همزة تحت الألف",
+            ArabicLetterWawWithHamzaAbove => "همزة على الواو",
+            ArabicLetterYehWithHamzaAbove => "همزة على الياء",
+            ArabicLetterAlef => "ألف",
+            ArabicLetterBeh => "باء",
+            ArabicLetterTehMarbuta => "تاء مربوطة",
+            ArabicLetterTeh => "تاء",
+            ArabicLetterTheh => "ثاء",
+            ArabicLetterJeem => "جيم",
+            ArabicLetterHah => "حاء",
+            ArabicLetterKhah => "خاء",
+            ArabicLetterDal => "دال",
+            ArabicLetterThal => "ذال",
+            ArabicLetterReh => "راء",
+            ArabicLetterZain => "زاي",
+            ArabicLetterSeen => "سين",
+            ArabicLetterSheen => "شين",
+            ArabicLetterSad => "صاد",
+            ArabicLetterDad => "ضاد",
+            ArabicLetterTah => "طاء",
+            ArabicLetterZah => "ظاء",
+            ArabicLetterAin => "عين",
+            ArabicLetterGhain => "غين",
+            ArabicTatweel => "تطويل",
+            ArabicLetterFeh => "فاء",
+            ArabicLetterQaf => "قاف",
+            ArabicLetterKaf => "كاف",
+            ArabicLetterLam => "لام",
+            ArabicLetterMeem => "ميم",
+            ArabicLetterNoon => "نون",
+            ArabicLetterHeh => "هاء",
+            ArabicLetterWaw => "واو",
+            ArabicLetterAlefMaksura => "ألف مقصورة",
+            ArabicLetterYeh => "ياء",
+            ArabicFathatan => "تنوين فتح",
+            ArabicDammatan => "تنوين ضم",
+            ArabicKasratan => "تنوين كسر",
+            ArabicFatha => "فتحة",
+            ArabicDamma => "ضمة",
+            ArabicKasra => "كسرة",
+            ArabicShadda => "شدة",
+            ArabicSukun => "سكون",
+            ArabicPercentSign => "علامة النسبة المئوية",
+            ArabicDecimalSeparator => "فاصلة عشرية",
+            ArabicThousandsSeparator => "فاصل الآلاف",
+            ArabicIndicDigitZero | ExtendedArabicIndicDigitZero => "صفر",
+            ArabicIndicDigitOne | ExtendedArabicIndicDigitOne => "واحد",
+            ArabicIndicDigitTwo | ExtendedArabicIndicDigitTwo => "اثنان",
+            ArabicIndicDigitThree | ExtendedArabicIndicDigitThree => "ثلاثة",
+            ArabicIndicDigitFour | ExtendedArabicIndicDigitFour => "أربعة",
+            ArabicIndicDigitFive | ExtendedArabicIndicDigitFive => "خمسة",
+            ArabicIndicDigitSix | ExtendedArabicIndicDigitSix => "ستة",
+            ArabicIndicDigitSeven | ExtendedArabicIndicDigitSeven => "سبعة",
+            ArabicIndicDigitEight | ExtendedArabicIndicDigitEight => "ثمانية",
+            ArabicIndicDigitNine | ExtendedArabicIndicDigitNine => "تسعة",
         }
     }
+
+    /// Returns the rank of the letter in the hija'i order, from 1 to 28, or [`None`] if the
+    /// character is not a letter.
+    ///
+    /// See [`LetterOrder::rank()`] for the ranks of the letters outside the alphabet.
+    pub fn hijai_rank(&self) -> Option<u32> {
+        LetterOrder::Hijai.rank(*self)
+    }
+
+    /// Returns the rank of the letter in the abjadi order, from 1 to 28, or [`None`] if the
+    /// character is not a letter.
+    pub fn abjadi_rank(&self) -> Option<u32> {
+        LetterOrder::Abjadi.rank(*self)
+    }
+
+    /// Returns the point of articulation of the letter, or [`None`] if the character is not a
+    /// letter.
+    ///
+    /// See [`Makhraj::of()`] for the points of the letters with more than one sound.
+    pub fn makhraj(&self) -> Option<Makhraj> {
+        Makhraj::of(*self)
+    }
+
+    /// Returns the characteristics of the letter, or [`None`] if the character is not a letter.
+    pub fn sifat(&self) -> Option<Sifat> {
+        Sifat::of(*self)
+    }
+
+    /// Returns the bidirectional class of the character.
+    pub fn bidi_class(&self) -> BidiClass {
+        BidiClass::of(self.scalar_value())
+    }
+
+    /// Returns `true` if the character is a letter of the Arabic alphabet, including the hamza and
+    /// its seated forms, the teh marbuta, and the alef maksura.
+    pub fn is_letter(&self) -> bool {
+        matches!(
+            self.scalar_value(),
+            '\u{0621}'..='\u{063A}' | '\u{0641}'..='\u{064A}'
+        )
+    }
+
+    /// Returns `true` if the character is one of the fourteen sun letters, which assimilate the lam
+    /// of the definite article.
+    pub fn is_sun_letter(&self) -> bool {
+        matches!(
+            self,
+            ArabicLetterTeh
+                | ArabicLetterTheh
+                | ArabicLetterDal
+                | ArabicLetterThal
+                | ArabicLetterReh
+                | ArabicLetterZain
+                | ArabicLetterSeen
+                | ArabicLetterSheen
+                | ArabicLetterSad
+                | ArabicLetterDad
+                | ArabicLetterTah
+                | ArabicLetterZah
+                | ArabicLetterLam
+                | ArabicLetterNoon
+        )
+    }
+
+    /// Returns `true` if the character is the hamza, or a letter carrying the hamza.
+    pub fn has_hamza(&self) -> bool {
+        matches!(
+            self,
+            ArabicLetterHamza
+                | ArabicLetterAlefWithMaddaAbove
+                | ArabicLetterAlefWithHamzaAbove
+                | ArabicLetterAlefWithHamzaBelow
+                | ArabicLetterWawWithHamzaAbove
+                | ArabicLetterYehWithHamzaAbove
+        )
+    }
+
+    /// Returns `true` if the character is an Arabic-Indic or an Extended Arabic-Indic digit.
+    pub fn is_digit(&self) -> bool {
+        self.digit_value().is_some()
+    }
+
+    /// Returns the numerical value of the digit, or [`None`] if the character is not a digit.
+    pub fn digit_value(&self) -> Option<u32> {
+        match self.scalar_value() {
+            c @ '\u{0660}'..='\u{0669}' => Some(c as u32 - 0x0660),
+            c @ '\u{06F0}'..='\u{06F9}' => Some(c as u32 - 0x06F0),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the character is a diacritical mark (_tashkeel_) that combines with the
+    /// letter preceding it.
+    pub fn is_diacritic(&self) -> bool {
+        self.is_short_vowel() || self.is_tanween() || matches!(self, ArabicShadda | ArabicSukun)
+    }
+
+    /// Returns `true` if the character is one of the three short vowels (_harakat_): the fatha,
+    /// the damma, or the kasra.
+    pub fn is_short_vowel(&self) -> bool {
+        matches!(self, ArabicFatha | ArabicDamma | ArabicKasra)
+    }
+
+    /// Returns `true` if the character is one of the three nunation marks (_tanween_).
+    pub fn is_tanween(&self) -> bool {
+        matches!(self, ArabicFathatan | ArabicDammatan | ArabicKasratan)
+    }
 }
 
 impl UnicodeCharacter for ArabicCharacter {
@@ -253,6 +545,7 @@ impl TryFrom<char> for ArabicCharacter {
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
+            '\u{061C}' => Ok(ArabicLetterMark),
             '\u{0621}' => Ok(ArabicLetterHamza),
             '\u{0622}' => Ok(ArabicLetterAlefWithMaddaAbove),
             '\u{0623}' => Ok(ArabicLetterAlefWithHamzaAbove),
@@ -298,6 +591,29 @@ impl TryFrom<char> for ArabicCharacter {
             '\u{0650}' => Ok(ArabicKasra),
             '\u{0651}' => Ok(ArabicShadda),
             '\u{0652}' => Ok(ArabicSukun),
+            '\u{0660}' => Ok(ArabicIndicDigitZero),
+            '\u{0661}' => Ok(ArabicIndicDigitOne),
+            '\u{0662}' => Ok(ArabicIndicDigitTwo),
+            '\u{0663}' => Ok(ArabicIndicDigitThree),
+            '\u{0664}' => Ok(ArabicIndicDigitFour),
+            '\u{0665}' => Ok(ArabicIndicDigitFive),
+            '\u{0666}' => Ok(ArabicIndicDigitSix),
+            '\u{0667}' => Ok(ArabicIndicDigitSeven),
+            '\u{0668}' => Ok(ArabicIndicDigitEight),
+            '\u{0669}' => Ok(ArabicIndicDigitNine),
+            '\u{066A}' => Ok(ArabicPercentSign),
+            '\u{066B}' => Ok(ArabicDecimalSeparator),
+            '\u{066C}' => Ok(ArabicThousandsSeparator),
+            '\u{06F0}' => Ok(ExtendedArabicIndicDigitZero),
+            '\u{06F1}' => Ok(ExtendedArabicIndicDigitOne),
+            '\u{06F2}' => Ok(ExtendedArabicIndicDigitTwo),
+            '\u{06F3}' => Ok(ExtendedArabicIndicDigitThree),
+            '\u{06F4}' => Ok(ExtendedArabicIndicDigitFour),
+            '\u{06F5}' => Ok(ExtendedArabicIndicDigitFive),
+            '\u{06F6}' => Ok(ExtendedArabicIndicDigitSix),
+            '\u{06F7}' => Ok(ExtendedArabicIndicDigitSeven),
+            '\u{06F8}' => Ok(ExtendedArabicIndicDigitEight),
+            '\u{06F9}' => Ok(ExtendedArabicIndicDigitNine),
             _ => Err(()),
         }
     }
@@ -311,11 +627,25 @@ impl TryFrom<char> for ArabicCharacter {
 mod tests {
     use crate::core::UnicodeCharacter;
     use crate::ArabicCharacter;
+    use crate::ArticulationRegion;
+    use crate::Makhraj;
+    use crate::Strength;
 
     use super::ArabicDamma;
     use super::ArabicDammatan;
+    use super::ArabicDecimalSeparator;
     use super::ArabicFatha;
     use super::ArabicFathatan;
+    use super::ArabicIndicDigitEight;
+    use super::ArabicIndicDigitFive;
+    use super::ArabicIndicDigitFour;
+    use super::ArabicIndicDigitNine;
+    use super::ArabicIndicDigitOne;
+    use super::ArabicIndicDigitSeven;
+    use super::ArabicIndicDigitSix;
+    use super::ArabicIndicDigitThree;
+    use super::ArabicIndicDigitTwo;
+    use super::ArabicIndicDigitZero;
     use super::ArabicKasra;
     use super::ArabicKasratan;
     use super::ArabicLetterAin;
@@ -336,6 +666,7 @@ mod tests {
     use super::ArabicLetterKaf;
     use super::ArabicLetterKhah;
     use super::ArabicLetterLam;
+    use super::ArabicLetterMark;
     use super::ArabicLetterMeem;
     use super::ArabicLetterNoon;
     use super::ArabicLetterQaf;
@@ -354,9 +685,21 @@ mod tests {
     use super::ArabicLetterYehWithHamzaAbove;
     use super::ArabicLetterZah;
     use super::ArabicLetterZain;
+    use super::ArabicPercentSign;
     use super::ArabicShadda;
     use super::ArabicSukun;
     use super::ArabicTatweel;
+    use super::ArabicThousandsSeparator;
+    use super::ExtendedArabicIndicDigitEight;
+    use super::ExtendedArabicIndicDigitFive;
+    use super::ExtendedArabicIndicDigitFour;
+    use super::ExtendedArabicIndicDigitNine;
+    use super::ExtendedArabicIndicDigitOne;
+    use super::ExtendedArabicIndicDigitSeven;
+    use super::ExtendedArabicIndicDigitSix;
+    use super::ExtendedArabicIndicDigitThree;
+    use super::ExtendedArabicIndicDigitTwo;
+    use super::ExtendedArabicIndicDigitZero;
 
     #[test]
     #[should_panic]
@@ -364,6 +707,110 @@ mod tests {
         ArabicCharacter::try_from('a').unwrap();
     }
 
+    #[test]
+    fn classifies_letters_and_diacritics() {
+        assert!(ArabicLetterHamza.is_letter());
+        assert!(ArabicLetterTehMarbuta.is_letter());
+        assert!(!ArabicTatweel.is_letter());
+        assert!(!ArabicShadda.is_letter());
+
+        assert!(ArabicSukun.is_diacritic());
+        assert!(ArabicKasratan.is_diacritic());
+        assert!(!ArabicTatweel.is_diacritic());
+
+        assert!(ArabicDamma.is_short_vowel());
+        assert!(!ArabicDammatan.is_short_vowel());
+        assert!(ArabicDammatan.is_tanween());
+        assert!(!ArabicSukun.is_tanween());
+
+        assert!(!ArabicIndicDigitOne.is_letter());
+        assert!(!ArabicThousandsSeparator.is_letter());
+        assert_eq!(ArabicIndicDigitSeven.digit_value(), Some(7));
+        assert_eq!(ExtendedArabicIndicDigitFour.digit_value(), Some(4));
+        assert!(!ArabicDecimalSeparator.is_digit());
+
+        assert!(ArabicLetterSheen.is_sun_letter());
+        assert!(!ArabicLetterQaf.is_sun_letter());
+
+        assert!(ArabicLetterWawWithHamzaAbove.has_hamza());
+        assert!(!ArabicLetterAlef.has_hamza());
+    }
+
+    #[test]
+    fn ranks_letters() {
+        assert_eq!(ArabicLetterAlef.hijai_rank(), Some(1));
+        assert_eq!(ArabicLetterTehMarbuta.hijai_rank(), Some(3));
+        assert_eq!(ArabicLetterYeh.hijai_rank(), Some(28));
+        assert_eq!(ArabicLetterHeh.abjadi_rank(), Some(5));
+        assert_eq!(ArabicLetterGhain.abjadi_rank(), Some(28));
+        assert_eq!(ArabicFatha.hijai_rank(), None);
+    }
+
+    #[test]
+    fn describes_articulation() {
+        assert_eq!(ArabicLetterQaf.makhraj(), Some(Makhraj::DeepestTongue));
+        assert_eq!(
+            ArabicLetterAlefWithHamzaBelow.makhraj(),
+            Some(Makhraj::DeepestThroat)
+        );
+        assert_eq!(
+            ArabicLetterMeem.makhraj().map(|m| m.region()),
+            Some(ArticulationRegion::Lips)
+        );
+        assert_eq!(
+            ArabicLetterAlefMaksura.makhraj().map(|m| m.region()),
+            Some(ArticulationRegion::Cavity)
+        );
+        assert_eq!(ArabicKasra.makhraj(), None);
+
+        let sad = ArabicLetterSad.sifat().unwrap();
+        assert!(!sad.voiced && sad.emphatic && sad.adhesive && sad.whistling);
+        assert_eq!(sad.strength, Strength::Fricative);
+
+        let dal = ArabicLetterDal.sifat().unwrap();
+        assert!(dal.voiced && dal.qalqala && !dal.emphatic);
+        assert_eq!(dal.strength, Strength::Plosive);
+
+        let reh = ArabicLetterReh.sifat().unwrap();
+        assert!(reh.trilled && reh.deviating && reh.fluent);
+        assert_eq!(reh.strength, Strength::Intermediate);
+
+        assert_eq!(ArabicLetterTehMarbuta.sifat(), ArabicLetterTeh.sifat());
+        assert!(ArabicLetterNoon.sifat().unwrap().nasal);
+    }
+
+    #[test]
+    fn names_characters_in_arabic() {
+        assert_eq!(ArabicLetterBeh.arabic_name(), "باء");
+        assert_eq!(ArabicLetterHamza.arabic_name(), "همزة");
+        assert_eq!(ArabicLetterTehMarbuta.arabic_name(), "تاء مربوطة");
+        assert_eq!(ArabicFatha.arabic_name(), "فتحة");
+        assert_eq!(ArabicKasratan.arabic_name(), "تنوين كسر");
+        assert_eq!(ArabicIndicDigitThree.arabic_name(), "ثلاثة");
+        assert_eq!(ExtendedArabicIndicDigitZero.arabic_name(), "صفر");
+    }
+
+    #[test]
+    fn arabic_letter_mark() {
+        assert_eq!(ArabicLetterMark.block(), "Arabic");
+        assert_eq!(ArabicLetterMark.name(), "Arabic Letter Mark");
+        assert_eq!(ArabicLetterMark.scalar_value(), '\u{061C}');
+
+        assert_eq!(
+            format!("{:?}", ArabicLetterMark),
+            "Arabic Letter Mark { \u{061C} }"
+        );
+        assert_eq!(format!("{}", ArabicLetterMark), "\u{061C}");
+        assert_eq!(ArabicLetterMark, ArabicLetterMark);
+        assert_eq!(ArabicLetterMark, '\u{061C}');
+        assert_eq!(ArabicLetterMark, "\u{061C}");
+        assert_eq!(ArabicLetterMark, "\u{061C}".to_string());
+        assert_eq!(
+            ArabicLetterMark,
+            ArabicCharacter::try_from('\u{061C}').unwrap()
+        );
+    }
+
     #[test]
     fn arabic_letter_hamza() {
         assert_eq!(ArabicLetterHamza.block(), "Arabic");
@@ -1302,4 +1749,520 @@ mod tests {
         assert_eq!(ArabicSukun, "\u{0652}".to_string());
         assert_eq!(ArabicSukun, ArabicCharacter::try_from('\u{0652}').unwrap());
     }
+
+    #[test]
+    fn arabic_indic_digit_zero() {
+        assert_eq!(ArabicIndicDigitZero.block(), "Arabic");
+        assert_eq!(ArabicIndicDigitZero.name(), "Arabic-Indic Digit Zero");
+        assert_eq!(ArabicIndicDigitZero.scalar_value(), '\u{0660}');
+
+        assert_eq!(
+            format!("{:?}", ArabicIndicDigitZero),
+            "Arabic-Indic Digit Zero { \u{0660} }"
+        );
+        assert_eq!(format!("{}", ArabicIndicDigitZero), "\u{0660}");
+        assert_eq!(ArabicIndicDigitZero, ArabicIndicDigitZero);
+        assert_eq!(ArabicIndicDigitZero, '\u{0660}');
+        assert_eq!(ArabicIndicDigitZero, "\u{0660}");
+        assert_eq!(ArabicIndicDigitZero, "\u{0660}".to_string());
+        assert_eq!(
+            ArabicIndicDigitZero,
+            ArabicCharacter::try_from('\u{0660}').unwrap()
+        );
+    }
+
+    #[test]
+    fn arabic_indic_digit_one() {
+        assert_eq!(ArabicIndicDigitOne.block(), "Arabic");
+        assert_eq!(ArabicIndicDigitOne.name(), "Arabic-Indic Digit One");
+        assert_eq!(ArabicIndicDigitOne.scalar_value(), '\u{0661}');
+
+        assert_eq!(
+            format!("{:?}", ArabicIndicDigitOne),
+            "Arabic-Indic Digit One { \u{0661} }"
+        );
+        assert_eq!(format!("{}", ArabicIndicDigitOne), "\u{0661}");
+        assert_eq!(ArabicIndicDigitOne, ArabicIndicDigitOne);
+        assert_eq!(ArabicIndicDigitOne, '\u{0661}');
+        assert_eq!(ArabicIndicDigitOne, "\u{0661}");
+        assert_eq!(ArabicIndicDigitOne, "\u{0661}".to_string());
+        assert_eq!(
+            ArabicIndicDigitOne,
+            ArabicCharacter::try_from('\u{0661}').unwrap()
+        );
+    }
+
+    #[test]
+    fn arabic_indic_digit_two() {
+        assert_eq!(ArabicIndicDigitTwo.block(), "Arabic");
+        assert_eq!(ArabicIndicDigitTwo.name(), "Arabic-Indic Digit Two");
+        assert_eq!(ArabicIndicDigitTwo.scalar_value(), '\u{0662}');
+
+        assert_eq!(
+            format!("{:?}", ArabicIndicDigitTwo),
+            "Arabic-Indic Digit Two { \u{0662} }"
+        );
+        assert_eq!(format!("{}", ArabicIndicDigitTwo), "\u{0662}");
+        assert_eq!(ArabicIndicDigitTwo, ArabicIndicDigitTwo);
+        assert_eq!(ArabicIndicDigitTwo, '\u{0662}');
+        assert_eq!(ArabicIndicDigitTwo, "\u{0662}");
+        assert_eq!(ArabicIndicDigitTwo, "\u{0662}".to_string());
+        assert_eq!(
+            ArabicIndicDigitTwo,
+            ArabicCharacter::try_from('\u{0662}').unwrap()
+        );
+    }
+
+    #[test]
+    fn arabic_indic_digit_three() {
+        assert_eq!(ArabicIndicDigitThree.block(), "Arabic");
+        assert_eq!(ArabicIndicDigitThree.name(), "Arabic-Indic Digit Three");
+        assert_eq!(ArabicIndicDigitThree.scalar_value(), '\u{0663}');
+
+        assert_eq!(
+            format!("{:?}", ArabicIndicDigitThree),
+            "Arabic-Indic Digit Three { \u{0663} }"
+        );
+        assert_eq!(format!("{}", ArabicIndicDigitThree), "\u{0663}");
+        assert_eq!(ArabicIndicDigitThree, ArabicIndicDigitThree);
+        assert_eq!(ArabicIndicDigitThree, '\u{0663}');
+        assert_eq!(ArabicIndicDigitThree, "\u{0663}");
+        assert_eq!(ArabicIndicDigitThree, "\u{0663}".to_string());
+        assert_eq!(
+            ArabicIndicDigitThree,
+            ArabicCharacter::try_from('\u{0663}').unwrap()
+        );
+    }
+
+    #[test]
+    fn arabic_indic_digit_four() {
+        assert_eq!(ArabicIndicDigitFour.block(), "Arabic");
+        assert_eq!(ArabicIndicDigitFour.name(), "Arabic-Indic Digit Four");
+        assert_eq!(ArabicIndicDigitFour.scalar_value(), '\u{0664}');
+
+        assert_eq!(
+            format!("{:?}", ArabicIndicDigitFour),
+            "Arabic-Indic Digit Four { \u{0664} }"
+        );
+        assert_eq!(format!("{}", ArabicIndicDigitFour), "\u{0664}");
+        assert_eq!(ArabicIndicDigitFour, ArabicIndicDigitFour);
+        assert_eq!(ArabicIndicDigitFour, '\u{0664}');
+        assert_eq!(ArabicIndicDigitFour, "\u{0664}");
+        assert_eq!(ArabicIndicDigitFour, "\u{0664}".to_string());
+        assert_eq!(
+            ArabicIndicDigitFour,
+            ArabicCharacter::try_from('\u{0664}').unwrap()
+        );
+    }
+
+    #[test]
+    fn arabic_indic_digit_five() {
+        assert_eq!(ArabicIndicDigitFive.block(), "Arabic");
+        assert_eq!(ArabicIndicDigitFive.name(), "Arabic-Indic Digit Five");
+        assert_eq!(ArabicIndicDigitFive.scalar_value(), '\u{0665}');
+
+        assert_eq!(
+            format!("{:?}", ArabicIndicDigitFive),
+            "Arabic-Indic Digit Five { \u{0665} }"
+        );
+        assert_eq!(format!("{}", ArabicIndicDigitFive), "\u{0665}");
+        assert_eq!(ArabicIndicDigitFive, ArabicIndicDigitFive);
+        assert_eq!(ArabicIndicDigitFive, '\u{0665}');
+        assert_eq!(ArabicIndicDigitFive, "\u{0665}");
+        assert_eq!(ArabicIndicDigitFive, "\u{0665}".to_string());
+        assert_eq!(
+            ArabicIndicDigitFive,
+            ArabicCharacter::try_from('\u{0665}').unwrap()
+        );
+    }
+
+    #[test]
+    fn arabic_indic_digit_six() {
+        assert_eq!(ArabicIndicDigitSix.block(), "Arabic");
+        assert_eq!(ArabicIndicDigitSix.name(), "Arabic-Indic Digit Six");
+        assert_eq!(ArabicIndicDigitSix.scalar_value(), '\u{0666}');
+
+        assert_eq!(
+            format!("{:?}", ArabicIndicDigitSix),
+            "Arabic-Indic Digit Six { \u{0666} }"
+        );
+        assert_eq!(format!("{}", ArabicIndicDigitSix), "\u{0666}");
+        assert_eq!(ArabicIndicDigitSix, ArabicIndicDigitSix);
+        assert_eq!(ArabicIndicDigitSix, '\u{0666}');
+        assert_eq!(ArabicIndicDigitSix, "\u{0666}");
+        assert_eq!(ArabicIndicDigitSix, "\u{0666}".to_string());
+        assert_eq!(
+            ArabicIndicDigitSix,
+            ArabicCharacter::try_from('\u{0666}').unwrap()
+        );
+    }
+
+    #[test]
+    fn arabic_indic_digit_seven() {
+        assert_eq!(ArabicIndicDigitSeven.block(), "Arabic");
+        assert_eq!(ArabicIndicDigitSeven.name(), "Arabic-Indic Digit Seven");
+        assert_eq!(ArabicIndicDigitSeven.scalar_value(), '\u{0667}');
+
+        assert_eq!(
+            format!("{:?}", ArabicIndicDigitSeven),
+            "Arabic-Indic Digit Seven { \u{0667} }"
+        );
+        assert_eq!(format!("{}", ArabicIndicDigitSeven), "\u{0667}");
+        assert_eq!(ArabicIndicDigitSeven, ArabicIndicDigitSeven);
+        assert_eq!(ArabicIndicDigitSeven, '\u{0667}');
+        assert_eq!(ArabicIndicDigitSeven, "\u{0667}");
+        assert_eq!(ArabicIndicDigitSeven, "\u{0667}".to_string());
+        assert_eq!(
+            ArabicIndicDigitSeven,
+            ArabicCharacter::try_from('\u{0667}').unwrap()
+        );
+    }
+
+    #[test]
+    fn arabic_indic_digit_eight() {
+        assert_eq!(ArabicIndicDigitEight.block(), "Arabic");
+        assert_eq!(ArabicIndicDigitEight.name(), "Arabic-Indic Digit Eight");
+        assert_eq!(ArabicIndicDigitEight.scalar_value(), '\u{0668}');
+
+        assert_eq!(
+            format!("{:?}", ArabicIndicDigitEight),
+            "Arabic-Indic Digit Eight { \u{0668} }"
+        );
+        assert_eq!(format!("{}", ArabicIndicDigitEight), "\u{0668}");
+        assert_eq!(ArabicIndicDigitEight, ArabicIndicDigitEight);
+        assert_eq!(ArabicIndicDigitEight, '\u{0668}');
+        assert_eq!(ArabicIndicDigitEight, "\u{0668}");
+        assert_eq!(ArabicIndicDigitEight, "\u{0668}".to_string());
+        assert_eq!(
+            ArabicIndicDigitEight,
+            ArabicCharacter::try_from('\u{0668}').unwrap()
+        );
+    }
+
+    #[test]
+    fn arabic_indic_digit_nine() {
+        assert_eq!(ArabicIndicDigitNine.block(), "Arabic");
+        assert_eq!(ArabicIndicDigitNine.name(), "Arabic-Indic Digit Nine");
+        assert_eq!(ArabicIndicDigitNine.scalar_value(), '\u{0669}');
+
+        assert_eq!(
+            format!("{:?}", ArabicIndicDigitNine),
+            "Arabic-Indic Digit Nine { \u{0669} }"
+        );
+        assert_eq!(format!("{}", ArabicIndicDigitNine), "\u{0669}");
+        assert_eq!(ArabicIndicDigitNine, ArabicIndicDigitNine);
+        assert_eq!(ArabicIndicDigitNine, '\u{0669}');
+        assert_eq!(ArabicIndicDigitNine, "\u{0669}");
+        assert_eq!(ArabicIndicDigitNine, "\u{0669}".to_string());
+        assert_eq!(
+            ArabicIndicDigitNine,
+            ArabicCharacter::try_from('\u{0669}').unwrap()
+        );
+    }
+
+    #[test]
+    fn arabic_percent_sign() {
+        assert_eq!(ArabicPercentSign.block(), "Arabic");
+        assert_eq!(ArabicPercentSign.name(), "Arabic Percent Sign");
+        assert_eq!(ArabicPercentSign.scalar_value(), '\u{066A}');
+
+        assert_eq!(
+            format!("{:?}", ArabicPercentSign),
+            "Arabic Percent Sign { \u{066A} }"
+        );
+        assert_eq!(format!("{}", ArabicPercentSign), "\u{066A}");
+        assert_eq!(ArabicPercentSign, ArabicPercentSign);
+        assert_eq!(ArabicPercentSign, '\u{066A}');
+        assert_eq!(ArabicPercentSign, "\u{066A}");
+        assert_eq!(ArabicPercentSign, "\u{066A}".to_string());
+        assert_eq!(
+            ArabicPercentSign,
+            ArabicCharacter::try_from('\u{066A}').unwrap()
+        );
+    }
+
+    #[test]
+    fn arabic_decimal_separator() {
+        assert_eq!(ArabicDecimalSeparator.block(), "Arabic");
+        assert_eq!(ArabicDecimalSeparator.name(), "Arabic Decimal Separator");
+        assert_eq!(ArabicDecimalSeparator.scalar_value(), '\u{066B}');
+
+        assert_eq!(
+            format!("{:?}", ArabicDecimalSeparator),
+            "Arabic Decimal Separator { \u{066B} }"
+        );
+        assert_eq!(format!("{}", ArabicDecimalSeparator), "\u{066B}");
+        assert_eq!(ArabicDecimalSeparator, ArabicDecimalSeparator);
+        assert_eq!(ArabicDecimalSeparator, '\u{066B}');
+        assert_eq!(ArabicDecimalSeparator, "\u{066B}");
+        assert_eq!(ArabicDecimalSeparator, "\u{066B}".to_string());
+        assert_eq!(
+            ArabicDecimalSeparator,
+            ArabicCharacter::try_from('\u{066B}').unwrap()
+        );
+    }
+
+    #[test]
+    fn arabic_thousands_separator() {
+        assert_eq!(ArabicThousandsSeparator.block(), "Arabic");
+        assert_eq!(
+            ArabicThousandsSeparator.name(),
+            "Arabic Thousands Separator"
+        );
+        assert_eq!(ArabicThousandsSeparator.scalar_value(), '\u{066C}');
+
+        assert_eq!(
+            format!("{:?}", ArabicThousandsSeparator),
+            "Arabic Thousands Separator { \u{066C} }"
+        );
+        assert_eq!(format!("{}", ArabicThousandsSeparator), "\u{066C}");
+        assert_eq!(ArabicThousandsSeparator, ArabicThousandsSeparator);
+        assert_eq!(ArabicThousandsSeparator, '\u{066C}');
+        assert_eq!(ArabicThousandsSeparator, "\u{066C}");
+        assert_eq!(ArabicThousandsSeparator, "\u{066C}".to_string());
+        assert_eq!(
+            ArabicThousandsSeparator,
+            ArabicCharacter::try_from('\u{066C}').unwrap()
+        );
+    }
+
+    #[test]
+    fn extended_arabic_indic_digit_zero() {
+        assert_eq!(ExtendedArabicIndicDigitZero.block(), "Arabic");
+        assert_eq!(
+            ExtendedArabicIndicDigitZero.name(),
+            "Extended Arabic-Indic Digit Zero"
+        );
+        assert_eq!(ExtendedArabicIndicDigitZero.scalar_value(), '\u{06F0}');
+
+        assert_eq!(
+            format!("{:?}", ExtendedArabicIndicDigitZero),
+            "Extended Arabic-Indic Digit Zero { \u{06F0} }"
+        );
+        assert_eq!(format!("{}", ExtendedArabicIndicDigitZero), "\u{06F0}");
+        assert_eq!(ExtendedArabicIndicDigitZero, ExtendedArabicIndicDigitZero);
+        assert_eq!(ExtendedArabicIndicDigitZero, '\u{06F0}');
+        assert_eq!(ExtendedArabicIndicDigitZero, "\u{06F0}");
+        assert_eq!(ExtendedArabicIndicDigitZero, "\u{06F0}".to_string());
+        assert_eq!(
+            ExtendedArabicIndicDigitZero,
+            ArabicCharacter::try_from('\u{06F0}').unwrap()
+        );
+    }
+
+    #[test]
+    fn extended_arabic_indic_digit_one() {
+        assert_eq!(ExtendedArabicIndicDigitOne.block(), "Arabic");
+        assert_eq!(
+            ExtendedArabicIndicDigitOne.name(),
+            "Extended Arabic-Indic Digit One"
+        );
+        assert_eq!(ExtendedArabicIndicDigitOne.scalar_value(), '\u{06F1}');
+
+        assert_eq!(
+            format!("{:?}", ExtendedArabicIndicDigitOne),
+            "Extended Arabic-Indic Digit One { \u{06F1} }"
+        );
+        assert_eq!(format!("{}", ExtendedArabicIndicDigitOne), "\u{06F1}");
+        assert_eq!(ExtendedArabicIndicDigitOne, ExtendedArabicIndicDigitOne);
+        assert_eq!(ExtendedArabicIndicDigitOne, '\u{06F1}');
+        assert_eq!(ExtendedArabicIndicDigitOne, "\u{06F1}");
+        assert_eq!(ExtendedArabicIndicDigitOne, "\u{06F1}".to_string());
+        assert_eq!(
+            ExtendedArabicIndicDigitOne,
+            ArabicCharacter::try_from('\u{06F1}').unwrap()
+        );
+    }
+
+    #[test]
+    fn extended_arabic_indic_digit_two() {
+        assert_eq!(ExtendedArabicIndicDigitTwo.block(), "Arabic");
+        assert_eq!(
+            ExtendedArabicIndicDigitTwo.name(),
+            "Extended Arabic-Indic Digit Two"
+        );
+        assert_eq!(ExtendedArabicIndicDigitTwo.scalar_value(), '\u{06F2}');
+
+        assert_eq!(
+            format!("{:?}", ExtendedArabicIndicDigitTwo),
+            "Extended Arabic-Indic Digit Two { \u{06F2} }"
+        );
+        assert_eq!(format!("{}", ExtendedArabicIndicDigitTwo), "\u{06F2}");
+        assert_eq!(ExtendedArabicIndicDigitTwo, ExtendedArabicIndicDigitTwo);
+        assert_eq!(ExtendedArabicIndicDigitTwo, '\u{06F2}');
+        assert_eq!(ExtendedArabicIndicDigitTwo, "\u{06F2}");
+        assert_eq!(ExtendedArabicIndicDigitTwo, "\u{06F2}".to_string());
+        assert_eq!(
+            ExtendedArabicIndicDigitTwo,
+            ArabicCharacter::try_from('\u{06F2}').unwrap()
+        );
+    }
+
+    #[test]
+    fn extended_arabic_indic_digit_three() {
+        assert_eq!(ExtendedArabicIndicDigitThree.block(), "Arabic");
+        assert_eq!(
+            ExtendedArabicIndicDigitThree.name(),
+            "Extended Arabic-Indic Digit Three"
+        );
+        assert_eq!(ExtendedArabicIndicDigitThree.scalar_value(), '\u{06F3}');
+
+        assert_eq!(
+            format!("{:?}", ExtendedArabicIndicDigitThree),
+            "Extended Arabic-Indic Digit Three { \u{06F3} }"
+        );
+        assert_eq!(format!("{}", ExtendedArabicIndicDigitThree), "\u{06F3}");
+        assert_eq!(ExtendedArabicIndicDigitThree, ExtendedArabicIndicDigitThree);
+        assert_eq!(ExtendedArabicIndicDigitThree, '\u{06F3}');
+        assert_eq!(ExtendedArabicIndicDigitThree, "\u{06F3}");
+        assert_eq!(ExtendedArabicIndicDigitThree, "\u{06F3}".to_string());
+        assert_eq!(
+            ExtendedArabicIndicDigitThree,
+            ArabicCharacter::try_from('\u{06F3}').unwrap()
+        );
+    }
+
+    #[test]
+    fn extended_arabic_indic_digit_four() {
+        assert_eq!(ExtendedArabicIndicDigitFour.block(), "Arabic");
+        assert_eq!(
+            ExtendedArabicIndicDigitFour.name(),
+            "Extended Arabic-Indic Digit Four"
+        );
+        assert_eq!(ExtendedArabicIndicDigitFour.scalar_value(), '\u{06F4}');
+
+        assert_eq!(
+            format!("{:?}", ExtendedArabicIndicDigitFour),
+            "Extended Arabic-Indic Digit Four { \u{06F4} }"
+        );
+        assert_eq!(format!("{}", ExtendedArabicIndicDigitFour), "\u{06F4}");
+        assert_eq!(ExtendedArabicIndicDigitFour, ExtendedArabicIndicDigitFour);
+        assert_eq!(ExtendedArabicIndicDigitFour, '\u{06F4}');
+        assert_eq!(ExtendedArabicIndicDigitFour, "\u{06F4}");
+        assert_eq!(ExtendedArabicIndicDigitFour, "\u{06F4}".to_string());
+        assert_eq!(
+            ExtendedArabicIndicDigitFour,
+            ArabicCharacter::try_from('\u{06F4}').unwrap()
+        );
+    }
+
+    #[test]
+    fn extended_arabic_indic_digit_five() {
+        assert_eq!(ExtendedArabicIndicDigitFive.block(), "Arabic");
+        assert_eq!(
+            ExtendedArabicIndicDigitFive.name(),
+            "Extended Arabic-Indic Digit Five"
+        );
+        assert_eq!(ExtendedArabicIndicDigitFive.scalar_value(), '\u{06F5}');
+
+        assert_eq!(
+            format!("{:?}", ExtendedArabicIndicDigitFive),
+            "Extended Arabic-Indic Digit Five { \u{06F5} }"
+        );
+        assert_eq!(format!("{}", ExtendedArabicIndicDigitFive), "\u{06F5}");
+        assert_eq!(ExtendedArabicIndicDigitFive, ExtendedArabicIndicDigitFive);
+        assert_eq!(ExtendedArabicIndicDigitFive, '\u{06F5}');
+        assert_eq!(ExtendedArabicIndicDigitFive, "\u{06F5}");
+        assert_eq!(ExtendedArabicIndicDigitFive, "\u{06F5}".to_string());
+        assert_eq!(
+            ExtendedArabicIndicDigitFive,
+            ArabicCharacter::try_from('\u{06F5}').unwrap()
+        );
+    }
+
+    #[test]
+    fn extended_arabic_indic_digit_six() {
+        assert_eq!(ExtendedArabicIndicDigitSix.block(), "Arabic");
+        assert_eq!(
+            ExtendedArabicIndicDigitSix.name(),
+            "Extended Arabic-Indic Digit Six"
+        );
+        assert_eq!(ExtendedArabicIndicDigitSix.scalar_value(), '\u{06F6}');
+
+        assert_eq!(
+            format!("{:?}", ExtendedArabicIndicDigitSix),
+            "Extended Arabic-Indic Digit Six { \u{06F6} }"
+        );
+        assert_eq!(format!("{}", ExtendedArabicIndicDigitSix), "\u{06F6}");
+        assert_eq!(ExtendedArabicIndicDigitSix, ExtendedArabicIndicDigitSix);
+        assert_eq!(ExtendedArabicIndicDigitSix, '\u{06F6}');
+        assert_eq!(ExtendedArabicIndicDigitSix, "\u{06F6}");
+        assert_eq!(ExtendedArabicIndicDigitSix, "\u{06F6}".to_string());
+        assert_eq!(
+            ExtendedArabicIndicDigitSix,
+            ArabicCharacter::try_from('\u{06F6}').unwrap()
+        );
+    }
+
+    #[test]
+    fn extended_arabic_indic_digit_seven() {
+        assert_eq!(ExtendedArabicIndicDigitSeven.block(), "Arabic");
+        assert_eq!(
+            ExtendedArabicIndicDigitSeven.name(),
+            "Extended Arabic-Indic Digit Seven"
+        );
+        assert_eq!(ExtendedArabicIndicDigitSeven.scalar_value(), '\u{06F7}');
+
+        assert_eq!(
+            format!("{:?}", ExtendedArabicIndicDigitSeven),
+            "Extended Arabic-Indic Digit Seven { \u{06F7} }"
+        );
+        assert_eq!(format!("{}", ExtendedArabicIndicDigitSeven), "\u{06F7}");
+        assert_eq!(ExtendedArabicIndicDigitSeven, ExtendedArabicIndicDigitSeven);
+        assert_eq!(ExtendedArabicIndicDigitSeven, '\u{06F7}');
+        assert_eq!(ExtendedArabicIndicDigitSeven, "\u{06F7}");
+        assert_eq!(ExtendedArabicIndicDigitSeven, "\u{06F7}".to_string());
+        assert_eq!(
+            ExtendedArabicIndicDigitSeven,
+            ArabicCharacter::try_from('\u{06F7}').unwrap()
+        );
+    }
+
+    #[test]
+    fn extended_arabic_indic_digit_eight() {
+        assert_eq!(ExtendedArabicIndicDigitEight.block(), "Arabic");
+        assert_eq!(
+            ExtendedArabicIndicDigitEight.name(),
+            "Extended Arabic-Indic Digit Eight"
+        );
+        assert_eq!(ExtendedArabicIndicDigitEight.scalar_value(), '\u{06F8}');
+
+        assert_eq!(
+            format!("{:?}", ExtendedArabicIndicDigitEight),
+            "Extended Arabic-Indic Digit Eight { \u{06F8} }"
+        );
+        assert_eq!(format!("{}", ExtendedArabicIndicDigitEight), "\u{06F8}");
+        assert_eq!(ExtendedArabicIndicDigitEight, ExtendedArabicIndicDigitEight);
+        assert_eq!(ExtendedArabicIndicDigitEight, '\u{06F8}');
+        assert_eq!(ExtendedArabicIndicDigitEight, "\u{06F8}");
+        assert_eq!(ExtendedArabicIndicDigitEight, "\u{06F8}".to_string());
+        assert_eq!(
+            ExtendedArabicIndicDigitEight,
+            ArabicCharacter::try_from('\u{06F8}').unwrap()
+        );
+    }
+
+    #[test]
+    fn extended_arabic_indic_digit_nine() {
+        assert_eq!(ExtendedArabicIndicDigitNine.block(), "Arabic");
+        assert_eq!(
+            ExtendedArabicIndicDigitNine.name(),
+            "Extended Arabic-Indic Digit Nine"
+        );
+        assert_eq!(ExtendedArabicIndicDigitNine.scalar_value(), '\u{06F9}');
+
+        assert_eq!(
+            format!("{:?}", ExtendedArabicIndicDigitNine),
+            "Extended Arabic-Indic Digit Nine { \u{06F9} }"
+        );
+        assert_eq!(format!("{}", ExtendedArabicIndicDigitNine), "\u{06F9}");
+        assert_eq!(ExtendedArabicIndicDigitNine, ExtendedArabicIndicDigitNine);
+        assert_eq!(ExtendedArabicIndicDigitNine, '\u{06F9}');
+        assert_eq!(ExtendedArabicIndicDigitNine, "\u{06F9}");
+        assert_eq!(ExtendedArabicIndicDigitNine, "\u{06F9}".to_string());
+        assert_eq!(
+            ExtendedArabicIndicDigitNine,
+            ArabicCharacter::try_from('\u{06F9}').unwrap()
+        );
+    }
 }
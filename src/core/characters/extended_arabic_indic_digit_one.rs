@@ -0,0 +1,39 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::core::UnicodeCharacter;
+
+/// Represents the Extended Arabic-Indic Digit One.
+pub struct ExtendedArabicIndicDigitOne;
+
+impl ExtendedArabicIndicDigitOne {
+    /// Returns a new instance of the Extended Arabic-Indic Digit One.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl UnicodeCharacter for ExtendedArabicIndicDigitOne {
+    fn block(&self) -> &'static str {
+        "Arabic"
+    }
+
+    fn name(&self) -> &'static str {
+        "Extended Arabic-Indic Digit One"
+    }
+
+    fn scalar_value(&self) -> char {
+        '\u{06F1}'
+    }
+}
@@ -16,8 +16,19 @@
 
 pub use arabic_damma::ArabicDamma;
 pub use arabic_dammatan::ArabicDammatan;
+pub use arabic_decimal_separator::ArabicDecimalSeparator;
 pub use arabic_fatha::ArabicFatha;
 pub use arabic_fathatan::ArabicFathatan;
+pub use arabic_indic_digit_eight::ArabicIndicDigitEight;
+pub use arabic_indic_digit_five::ArabicIndicDigitFive;
+pub use arabic_indic_digit_four::ArabicIndicDigitFour;
+pub use arabic_indic_digit_nine::ArabicIndicDigitNine;
+pub use arabic_indic_digit_one::ArabicIndicDigitOne;
+pub use arabic_indic_digit_seven::ArabicIndicDigitSeven;
+pub use arabic_indic_digit_six::ArabicIndicDigitSix;
+pub use arabic_indic_digit_three::ArabicIndicDigitThree;
+pub use arabic_indic_digit_two::ArabicIndicDigitTwo;
+pub use arabic_indic_digit_zero::ArabicIndicDigitZero;
 pub use arabic_kasra::ArabicKasra;
 pub use arabic_kasratan::ArabicKasratan;
 pub use arabic_letter_ain::ArabicLetterAin;
@@ -38,6 +49,7 @@ pub use arabic_letter_jeem::ArabicLetterJeem;
 pub use arabic_letter_kaf::ArabicLetterKaf;
 pub use arabic_letter_khah::ArabicLetterKhah;
 pub use arabic_letter_lam::ArabicLetterLam;
+pub use arabic_letter_mark::ArabicLetterMark;
 pub use arabic_letter_meem::ArabicLetterMeem;
 pub use arabic_letter_noon::ArabicLetterNoon;
 pub use arabic_letter_qaf::ArabicLetterQaf;
@@ -56,14 +68,37 @@ pub use arabic_letter_yeh::ArabicLetterYeh;
 pub use arabic_letter_yeh_with_hamza_above::ArabicLetterYehWithHamzaAbove;
 pub use arabic_letter_zah::ArabicLetterZah;
 pub use arabic_letter_zain::ArabicLetterZain;
+pub use arabic_percent_sign::ArabicPercentSign;
 pub use arabic_shadda::ArabicShadda;
 pub use arabic_sukun::ArabicSukun;
 pub use arabic_tatweel::ArabicTatweel;
+pub use arabic_thousands_separator::ArabicThousandsSeparator;
+pub use extended_arabic_indic_digit_eight::ExtendedArabicIndicDigitEight;
+pub use extended_arabic_indic_digit_five::ExtendedArabicIndicDigitFive;
+pub use extended_arabic_indic_digit_four::ExtendedArabicIndicDigitFour;
+pub use extended_arabic_indic_digit_nine::ExtendedArabicIndicDigitNine;
+pub use extended_arabic_indic_digit_one::ExtendedArabicIndicDigitOne;
+pub use extended_arabic_indic_digit_seven::ExtendedArabicIndicDigitSeven;
+pub use extended_arabic_indic_digit_six::ExtendedArabicIndicDigitSix;
+pub use extended_arabic_indic_digit_three::ExtendedArabicIndicDigitThree;
+pub use extended_arabic_indic_digit_two::ExtendedArabicIndicDigitTwo;
+pub use extended_arabic_indic_digit_zero::ExtendedArabicIndicDigitZero;
 
 mod arabic_damma;
 mod arabic_dammatan;
+mod arabic_decimal_separator;
 mod arabic_fatha;
 mod arabic_fathatan;
+mod arabic_indic_digit_eight;
+mod arabic_indic_digit_five;
+mod arabic_indic_digit_four;
+mod arabic_indic_digit_nine;
+mod arabic_indic_digit_one;
+mod arabic_indic_digit_seven;
+mod arabic_indic_digit_six;
+mod arabic_indic_digit_three;
+mod arabic_indic_digit_two;
+mod arabic_indic_digit_zero;
 mod arabic_kasra;
 mod arabic_kasratan;
 mod arabic_letter_ain;
@@ -84,6 +119,7 @@ mod arabic_letter_jeem;
 mod arabic_letter_kaf;
 mod arabic_letter_khah;
 mod arabic_letter_lam;
+mod arabic_letter_mark;
 mod arabic_letter_meem;
 mod arabic_letter_noon;
 mod arabic_letter_qaf;
@@ -102,6 +138,18 @@ mod arabic_letter_yeh;
 mod arabic_letter_yeh_with_hamza_above;
 mod arabic_letter_zah;
 mod arabic_letter_zain;
+mod arabic_percent_sign;
 mod arabic_shadda;
 mod arabic_sukun;
 mod arabic_tatweel;
+mod arabic_thousands_separator;
+mod extended_arabic_indic_digit_eight;
+mod extended_arabic_indic_digit_five;
+mod extended_arabic_indic_digit_four;
+mod extended_arabic_indic_digit_nine;
+mod extended_arabic_indic_digit_one;
+mod extended_arabic_indic_digit_seven;
+mod extended_arabic_indic_digit_six;
+mod extended_arabic_indic_digit_three;
+mod extended_arabic_indic_digit_two;
+mod extended_arabic_indic_digit_zero;
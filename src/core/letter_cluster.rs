@@ -0,0 +1,111 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicCharacter;
+use crate::ArabicDamma;
+use crate::ArabicFatha;
+use crate::ArabicKasra;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterAlefMaksura;
+use crate::ArabicLetterLam;
+use crate::ArabicLetterWaw;
+use crate::ArabicLetterYeh;
+use crate::ArabicShadda;
+
+/// Represents a letter together with the diacritical marks it carries.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct LetterCluster {
+    /// The letter carrying the marks.
+    pub letter: ArabicCharacter,
+
+    /// The short vowel, tanween, or sukun carried by the letter, if any.
+    pub vowel: Option<ArabicCharacter>,
+
+    /// Whether the letter carries a shadda.
+    pub shadda: bool,
+
+    /// The index of the letter within the sequence it was read from.
+    pub start: usize,
+
+    /// The index following the last mark of the letter within the sequence it was read from.
+    pub end: usize,
+}
+
+impl LetterCluster {
+    /// Returns `true` if the letter carries the given mark as its vowel.
+    pub fn has_vowel(&self, vowel: ArabicCharacter) -> bool {
+        self.vowel == Some(vowel)
+    }
+
+    /// Returns `true` if the letter carries neither a short vowel nor a tanween.
+    pub fn is_vowelless(&self) -> bool {
+        !self
+            .vowel
+            .is_some_and(|v| v.is_short_vowel() || v.is_tanween())
+    }
+
+    /// Returns `true` if the letter lengthens the given short vowel preceding it.
+    pub fn lengthens(&self, vowel: ArabicCharacter) -> bool {
+        matches!(
+            (vowel, self.letter),
+            (ArabicFatha, ArabicLetterAlef | ArabicLetterAlefMaksura)
+                | (ArabicDamma, ArabicLetterWaw)
+                | (ArabicKasra, ArabicLetterYeh)
+        )
+    }
+
+    /// Returns `true` if the clusters of a word begin with the definite article.
+    pub fn has_definite_article(clusters: &[LetterCluster]) -> bool {
+        clusters.len() > 2
+            && clusters[0].letter == ArabicLetterAlef
+            && (clusters[0].vowel.is_none() || clusters[0].has_vowel(ArabicFatha))
+            && clusters[1].letter == ArabicLetterLam
+            && clusters[1].is_vowelless()
+            && !clusters[1].shadda
+    }
+
+    /// Reads the letters of a sequence into clusters.
+    ///
+    /// Tatweels are absorbed into the cluster preceding them, and marks that no letter carries are
+    /// skipped.
+    pub fn read(characters: &[ArabicCharacter]) -> Vec<LetterCluster> {
+        let mut clusters: Vec<LetterCluster> = Vec::new();
+
+        for (index, character) in characters.iter().enumerate() {
+            if character.is_letter() {
+                clusters.push(LetterCluster {
+                    letter: *character,
+                    vowel: None,
+                    shadda: false,
+                    start: index,
+                    end: index + 1,
+                });
+                continue;
+            }
+
+            let Some(cluster) = clusters.last_mut() else {
+                continue;
+            };
+
+            cluster.end = index + 1;
+            if matches!(character, ArabicShadda) {
+                cluster.shadda = true;
+            } else if character.is_diacritic() {
+                cluster.vowel = Some(*character);
+            }
+        }
+
+        clusters
+    }
+}
@@ -15,8 +15,12 @@
 //! The **Core** module houses the internal structure of the library.
 
 pub use arabic_character::ArabicCharacter;
+pub(crate) use letter_cluster::LetterCluster;
+pub(crate) use text_segment::TextSegment;
 pub use unicode_character::UnicodeCharacter;
 
 mod arabic_character;
 mod characters;
+mod letter_cluster;
+mod text_segment;
 mod unicode_character;
@@ -0,0 +1,52 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicCharacter;
+
+/// Represents a segment of a text: either a word of Arabic characters, or the run of other
+/// characters separating two words.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum TextSegment {
+    /// A run of Arabic characters, along with the index of its first character within the text.
+    Word {
+        start: usize,
+        characters: Vec<ArabicCharacter>,
+    },
+
+    /// A run of characters that do not belong to the Arabic script.
+    Other(String),
+}
+
+impl TextSegment {
+    /// Reads a text into its segments.
+    pub fn read(text: &str) -> Vec<TextSegment> {
+        let mut segments = Vec::new();
+
+        for (index, c) in text.chars().enumerate() {
+            match (ArabicCharacter::try_from(c), segments.last_mut()) {
+                (Ok(character), Some(TextSegment::Word { characters, .. })) => {
+                    characters.push(character)
+                }
+                (Ok(character), _) => segments.push(TextSegment::Word {
+                    start: index,
+                    characters: vec![character],
+                }),
+                (Err(()), Some(TextSegment::Other(other))) => other.push(c),
+                (Err(()), _) => segments.push(TextSegment::Other(c.to_string())),
+            }
+        }
+
+        segments
+    }
+}
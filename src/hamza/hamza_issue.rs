@@ -0,0 +1,28 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicCharacter;
+
+/// Represents a hamza written on the wrong seat.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HamzaIssue {
+    /// The index, within the checked text, of the hamza at fault.
+    pub index: usize,
+
+    /// The hamza as written.
+    pub found: ArabicCharacter,
+
+    /// The hamza on the seat its vowels call for.
+    pub expected: ArabicCharacter,
+}
@@ -0,0 +1,392 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::core::LetterCluster;
+use crate::core::TextSegment;
+use crate::ArabicCharacter;
+use crate::ArabicDamma;
+use crate::ArabicDammatan;
+use crate::ArabicFatha;
+use crate::ArabicFathatan;
+use crate::ArabicKasra;
+use crate::ArabicKasratan;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterAlefWithHamzaAbove;
+use crate::ArabicLetterAlefWithHamzaBelow;
+use crate::ArabicLetterBeh;
+use crate::ArabicLetterFeh;
+use crate::ArabicLetterHamza;
+use crate::ArabicLetterKaf;
+use crate::ArabicLetterLam;
+use crate::ArabicLetterWaw;
+use crate::ArabicLetterWawWithHamzaAbove;
+use crate::ArabicLetterYeh;
+use crate::ArabicLetterYehWithHamzaAbove;
+use crate::ArabicSukun;
+use crate::HamzaIssue;
+use crate::UnicodeCharacter;
+
+/// Represents the vowel of a letter, as it bears on the seat of a hamza.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Vowel {
+    Fatha,
+    Damma,
+    Kasra,
+    Sukun,
+
+    /// The alef of a long vowel.
+    Alef,
+
+    /// A waw without a vowel, either silent or lengthening a damma.
+    Waw,
+
+    /// A yeh without a vowel, either silent or lengthening a kasra.
+    Yeh,
+
+    /// A letter whose vowel is not written.
+    Unknown,
+}
+
+/// Represents the position of a hamza within its word.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Position {
+    Initial,
+    Medial,
+    Final,
+
+    /// Before the alef that carries the tanween of the accusative, as in شَيْئًا.
+    BeforeTanweenAlef,
+}
+
+/// Seats each hamza of a vocalized word on the letter its vowels call for, whether it is written
+/// as a free-standing hamza or on any seat.
+///
+/// An initial hamza sits on an alef, above it or below it by its own vowel. A medial hamza sits on
+/// the seat of the stronger of its own vowel and the vowel before it, the kasra being stronger
+/// than the damma, the damma than the fatha, and the fatha than the sukun. A final hamza sits on
+/// the seat of the vowel before it, or stands free after a silent letter or a long vowel:
+///
+/// ```
+/// use arabic_script::{seat_hamza, ArabicCharacter};
+///
+/// let read = |word: &str| -> Vec<ArabicCharacter> {
+///     word.chars().map(|c| ArabicCharacter::try_from(c).unwrap()).collect()
+/// };
+///
+/// assert_eq!(seat_hamza(&read("سُءَال")), read("سُؤَال"));
+/// assert_eq!(seat_hamza(&read("شَاطِء")), read("شَاطِئ"));
+/// ```
+///
+/// A letter whose vowel is not written is read as silent. The hamza following a vocalized
+/// proclitic or the definite article, as in بِأَنَّ or الْإِسْلَام, begins the stem, and is read as
+/// initial.
+pub fn seat_hamza(word: &[ArabicCharacter]) -> Vec<ArabicCharacter> {
+    let clusters = LetterCluster::read(word);
+    let mut seated = word.to_vec();
+
+    for index in 0..clusters.len() {
+        if !is_hamza(clusters[index].letter) {
+            continue;
+        }
+
+        let (own, previous) = vowels(&clusters, index);
+        let own = if own == Vowel::Unknown {
+            Vowel::Sukun
+        } else {
+            own
+        };
+        let previous = if previous == Vowel::Unknown {
+            Vowel::Sukun
+        } else {
+            previous
+        };
+
+        if let Some(seat) = seat(&clusters, index, own, previous) {
+            seated[clusters[index].start] = seat;
+        }
+    }
+
+    seated
+}
+
+/// Checks the seats of the hamzas of a text, reporting every hamza written on the wrong seat.
+///
+/// A hamza is only checked if the vowels it depends on are written, so that an unvocalized text
+/// is checked for the seats that do not depend on its vowels alone:
+///
+/// ```
+/// use arabic_script::{check_hamza, ArabicLetterAlefWithHamzaAbove, ArabicLetterYehWithHamzaAbove};
+///
+/// let issues = check_hamza("سَئَلَ ومسؤول");
+///
+/// assert_eq!(issues.len(), 1);
+/// assert_eq!(issues[0].index, 2);
+/// assert_eq!(issues[0].found, ArabicLetterYehWithHamzaAbove);
+/// assert_eq!(issues[0].expected, ArabicLetterAlefWithHamzaAbove);
+/// ```
+pub fn check_hamza(text: &str) -> Vec<HamzaIssue> {
+    let mut issues = Vec::new();
+
+    for segment in TextSegment::read(text) {
+        let TextSegment::Word { start, characters } = segment else {
+            continue;
+        };
+        let clusters = LetterCluster::read(&characters);
+        let stem = stem_start(&clusters);
+
+        for (index, cluster) in clusters.iter().enumerate() {
+            if !is_hamza(cluster.letter) {
+                continue;
+            }
+
+            let (own, previous) = vowels(&clusters, index);
+            let expected = match seat(&clusters, index, own, previous) {
+                Some(expected) => expected,
+                None if index == stem => ArabicLetterAlefWithHamzaAbove,
+                None => continue,
+            };
+
+            let initial = [
+                ArabicLetterAlefWithHamzaAbove,
+                ArabicLetterAlefWithHamzaBelow,
+            ];
+            let accepted = if index == stem && own == Vowel::Unknown {
+                initial.contains(&cluster.letter)
+            } else {
+                cluster.letter == expected
+            };
+
+            if !accepted {
+                issues.push(HamzaIssue {
+                    index: start + cluster.start,
+                    found: cluster.letter,
+                    expected,
+                });
+            }
+        }
+    }
+
+    issues
+}
+
+/// Returns `true` if the letter is a hamza, free-standing or on a seat.
+fn is_hamza(letter: ArabicCharacter) -> bool {
+    matches!(
+        letter,
+        ArabicLetterHamza
+            | ArabicLetterAlefWithHamzaAbove
+            | ArabicLetterAlefWithHamzaBelow
+            | ArabicLetterWawWithHamzaAbove
+            | ArabicLetterYehWithHamzaAbove
+    )
+}
+
+/// Returns the vowel of the hamza at the given index, and the vowel of the letter before it.
+fn vowels(clusters: &[LetterCluster], index: usize) -> (Vowel, Vowel) {
+    let own = match clusters[index].vowel {
+        Some(ArabicFatha | ArabicFathatan) => Vowel::Fatha,
+        Some(ArabicDamma | ArabicDammatan) => Vowel::Damma,
+        Some(ArabicKasra | ArabicKasratan) => Vowel::Kasra,
+        Some(ArabicSukun) => Vowel::Sukun,
+        _ => Vowel::Unknown,
+    };
+
+    let Some(previous) = index.checked_sub(1).map(|i| &clusters[i]) else {
+        return (own, Vowel::Unknown);
+    };
+    let previous = match (previous.letter, previous.vowel) {
+        (ArabicLetterAlef, _) => Vowel::Alef,
+        (_, Some(ArabicFatha)) => Vowel::Fatha,
+        (_, Some(ArabicDamma)) => Vowel::Damma,
+        (_, Some(ArabicKasra)) => Vowel::Kasra,
+        (ArabicLetterWaw, Some(ArabicSukun) | None) => Vowel::Waw,
+        (ArabicLetterYeh, Some(ArabicSukun) | None) => Vowel::Yeh,
+        (_, Some(ArabicSukun)) => Vowel::Sukun,
+        _ => Vowel::Unknown,
+    };
+
+    (own, previous)
+}
+
+/// Returns the seated hamza for the hamza at the given index, or [`None`] if the vowels it depends
+/// on are unknown.
+fn seat(
+    clusters: &[LetterCluster],
+    index: usize,
+    own: Vowel,
+    previous: Vowel,
+) -> Option<ArabicCharacter> {
+    let last = clusters.len() - 1;
+    let position = if index == stem_start(clusters) {
+        Position::Initial
+    } else if index == last {
+        Position::Final
+    } else if index + 1 == last && clusters[last].letter == ArabicLetterAlef {
+        Position::BeforeTanweenAlef
+    } else {
+        Position::Medial
+    };
+
+    let seat = match (position, own, previous) {
+        (Position::Initial, Vowel::Unknown, _) => return None,
+        (Position::Initial, Vowel::Kasra, _) => ArabicLetterAlefWithHamzaBelow,
+        (Position::Initial, _, _) => ArabicLetterAlefWithHamzaAbove,
+
+        (Position::BeforeTanweenAlef, _, Vowel::Sukun | Vowel::Waw | Vowel::Yeh)
+            if joins_next(clusters[index - 1].letter) =>
+        {
+            ArabicLetterYehWithHamzaAbove
+        }
+        (Position::Final | Position::BeforeTanweenAlef, _, previous) => match previous {
+            Vowel::Fatha => ArabicLetterAlefWithHamzaAbove,
+            Vowel::Damma => ArabicLetterWawWithHamzaAbove,
+            Vowel::Kasra => ArabicLetterYehWithHamzaAbove,
+            Vowel::Unknown => return None,
+            _ => ArabicLetterHamza,
+        },
+
+        (Position::Medial, _, Vowel::Yeh) => ArabicLetterYehWithHamzaAbove,
+        (Position::Medial, Vowel::Unknown, _) | (Position::Medial, _, Vowel::Unknown) => {
+            return None
+        }
+        (Position::Medial, Vowel::Kasra, _) | (Position::Medial, _, Vowel::Kasra) => {
+            ArabicLetterYehWithHamzaAbove
+        }
+        (Position::Medial, Vowel::Damma, _) => ArabicLetterWawWithHamzaAbove,
+        (Position::Medial, _, Vowel::Alef | Vowel::Waw) => ArabicLetterHamza,
+        (Position::Medial, _, Vowel::Damma) => ArabicLetterWawWithHamzaAbove,
+        (Position::Medial, _, _) => ArabicLetterAlefWithHamzaAbove,
+    };
+
+    Some(seat)
+}
+
+/// Returns the index of the first letter of the stem of a word, following a proclitic written with
+/// its vowel, and the definite article.
+fn stem_start(clusters: &[LetterCluster]) -> usize {
+    let mut start = 0;
+
+    let proclitic = matches!(
+        clusters.first().map(|c| (c.letter, c.vowel)),
+        Some((
+            ArabicLetterWaw | ArabicLetterFeh | ArabicLetterKaf,
+            Some(ArabicFatha)
+        )) | Some((ArabicLetterBeh, Some(ArabicKasra)))
+            | Some((ArabicLetterLam, Some(ArabicFatha | ArabicKasra)))
+    );
+    let voweled = |index: usize| {
+        clusters
+            .get(index)
+            .is_some_and(|c| c.vowel.is_some_and(|v| v != ArabicSukun))
+    };
+    if proclitic && voweled(1) {
+        start = 1;
+    }
+
+    let article = clusters
+        .get(start)
+        .is_some_and(|c| c.letter == ArabicLetterAlef)
+        && clusters.get(start + 1).is_some_and(|c| {
+            c.letter == ArabicLetterLam && c.vowel.is_none_or(|v| v == ArabicSukun)
+        });
+    if article && start + 2 < clusters.len() {
+        start += 2;
+    }
+
+    start
+}
+
+/// Returns `true` if the letter joins the letter that follows it.
+fn joins_next(letter: ArabicCharacter) -> bool {
+    !matches!(
+        letter.scalar_value(),
+        'ا' | 'أ' | 'إ' | 'آ' | 'د' | 'ذ' | 'ر' | 'ز' | 'و' | 'ؤ' | 'ء' | 'ة' | 'ى'
+    )
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::check_hamza;
+    use crate::seat_hamza;
+    use crate::ArabicCharacter;
+    use crate::ArabicLetterAlefWithHamzaAbove;
+    use crate::ArabicLetterHamza;
+    use crate::ArabicLetterWawWithHamzaAbove;
+
+    fn seat(word: &str) -> String {
+        let word: Vec<ArabicCharacter> = word
+            .chars()
+            .map(|c| ArabicCharacter::try_from(c).unwrap())
+            .collect();
+
+        seat_hamza(&word).iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn seats_the_initial_hamza() {
+        assert_eq!(seat("ءَكَلَ"), "أَكَلَ");
+        assert_eq!(seat("ءُمّ"), "أُمّ");
+        assert_eq!(seat("ءِسْلَام"), "إِسْلَام");
+        assert_eq!(seat("لِءَنَّ"), "لِأَنَّ");
+        assert_eq!(seat("الْءِسْلَام"), "الْإِسْلَام");
+    }
+
+    #[test]
+    fn seats_the_medial_hamza() {
+        assert_eq!(seat("سَءَلَ"), "سَأَلَ");
+        assert_eq!(seat("رَءْس"), "رَأْس");
+        assert_eq!(seat("مُءْمِن"), "مُؤْمِن");
+        assert_eq!(seat("بِءْر"), "بِئْر");
+        assert_eq!(seat("سُءَال"), "سُؤَال");
+        assert_eq!(seat("مَسْءَلَة"), "مَسْأَلَة");
+        assert_eq!(seat("مَسْءُول"), "مَسْؤُول");
+        assert_eq!(seat("أَسْءِلَة"), "أَسْئِلَة");
+        assert_eq!(seat("قَاءِل"), "قَائِل");
+        assert_eq!(seat("تَسَاأَلَ"), "تَسَاءَلَ");
+        assert_eq!(seat("تَفَاءُل"), "تَفَاؤُل");
+        assert_eq!(seat("مُرُوأَة"), "مُرُوءَة");
+        assert_eq!(seat("بَرِيءَة"), "بَرِيئَة");
+    }
+
+    #[test]
+    fn seats_the_final_hamza() {
+        assert_eq!(seat("بَدَءَ"), "بَدَأَ");
+        assert_eq!(seat("تَكَافُء"), "تَكَافُؤ");
+        assert_eq!(seat("شَاطِء"), "شَاطِئ");
+        assert_eq!(seat("جُزْأ"), "جُزْء");
+        assert_eq!(seat("سَمَاأ"), "سَمَاء");
+        assert_eq!(seat("ضَوْؤ"), "ضَوْء");
+        assert_eq!(seat("شَيْءًا"), "شَيْئًا");
+        assert_eq!(seat("جُزْءًا"), "جُزْءًا");
+    }
+
+    #[test]
+    fn checks_the_seats_of_a_text() {
+        let issues = check_hamza("ؤكل مسؤول سماأ وسُئَال");
+        let proclitics = check_hamza("وَإِنَّ لِأَنَّ بِأَنَّ فَإِذَا بِئْر الْإِسْلَام");
+
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[0].index, 0);
+        assert_eq!(issues[0].expected, ArabicLetterAlefWithHamzaAbove);
+        assert_eq!(issues[1].index, 13);
+        assert_eq!(issues[1].expected, ArabicLetterHamza);
+        assert_eq!(issues[2].index, 18);
+        assert_eq!(issues[2].expected, ArabicLetterWawWithHamzaAbove);
+        assert_eq!(proclitics, []);
+    }
+}
@@ -0,0 +1,278 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::extract_roots;
+use crate::ArabicCharacter;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterAlefMaksura;
+use crate::ArabicLetterHamza;
+use crate::ArabicLetterTehMarbuta;
+use crate::ArabicLetterWaw;
+use crate::ArabicLetterYeh;
+use crate::ArticulationRegion;
+use crate::KeyboardLayout;
+use crate::RootCandidate;
+use crate::UnicodeCharacter;
+
+/// The longest run of consonants expected in a word of a Latin language.
+const MAX_CONSONANT_RUN: usize = 5;
+
+/// The pairs of consonants a word of a Latin language may begin with.
+const LATIN_ONSETS: [&str; 40] = [
+    "bl", "br", "ch", "cl", "cr", "dr", "dw", "fl", "fr", "gh", "gl", "gn", "gr", "kl", "kn", "kr",
+    "ph", "pl", "pr", "ps", "qu", "rh", "sc", "sh", "sk", "sl", "sm", "sn", "sp", "sq", "st", "sw",
+    "th", "tr", "ts", "tw", "vl", "wh", "wr", "zh",
+];
+
+/// The highest cost of a root for a word to read as Arabic.
+const MAX_ROOT_COST: u32 = 3;
+
+/// Retypes text that was typed with the Latin layout active, as the Arabic characters of the same
+/// keys in the given layout.
+///
+/// The characters that no key of the layout types as an Arabic character are copied as-is:
+///
+/// ```
+/// use arabic_script::{retype_as_arabic, KeyboardLayout};
+///
+/// assert_eq!(retype_as_arabic("hgsghl ugd;l", KeyboardLayout::Arabic101), "السلام عليكم");
+/// assert_eq!(retype_as_arabic("hgsghl ugdml", KeyboardLayout::Arabic102), "السلام عليكم");
+/// ```
+pub fn retype_as_arabic(text: &str, layout: KeyboardLayout) -> String {
+    let mut retyped = String::with_capacity(text.len() * 2);
+
+    for key in text.chars() {
+        match layout.characters(key) {
+            Some(characters) => retyped.extend(characters.iter().map(|c| c.scalar_value())),
+            None => retyped.push(key),
+        }
+    }
+
+    retyped
+}
+
+/// Retypes text that was typed with the given Arabic layout active, as the Latin characters of the
+/// same keys.
+///
+/// A lam followed by an alef is retyped as the key of the lam-alef, and the characters that the
+/// layout does not type are copied as-is:
+///
+/// ```
+/// use arabic_script::{retype_as_latin, KeyboardLayout};
+///
+/// assert_eq!(retype_as_latin("اثممخ صخقمي", KeyboardLayout::Arabic101), "hello world");
+/// ```
+pub fn retype_as_latin(text: &str, layout: KeyboardLayout) -> String {
+    let characters: Vec<char> = text.chars().collect();
+    let mut retyped = String::with_capacity(characters.len());
+    let mut index = 0;
+
+    while index < characters.len() {
+        let key = layout
+            .keys()
+            .filter(|(_, typed)| {
+                typed.len() <= characters.len() - index
+                    && typed
+                        .iter()
+                        .zip(&characters[index..])
+                        .all(|(t, c)| t.scalar_value() == *c)
+            })
+            .max_by_key(|(_, typed)| typed.len());
+
+        match key {
+            Some((key, typed)) => {
+                retyped.push(key);
+                index += typed.len();
+            }
+            None => {
+                retyped.push(characters[index]);
+                index += 1;
+            }
+        }
+    }
+
+    retyped
+}
+
+/// Scores how likely a text typed with the Latin layout active was meant to be typed with the
+/// given Arabic layout, from 0 for plausible Latin text to 1 for text that only reads as Arabic.
+///
+/// Each word is scored on whether it reads as a word of a Latin language, and whether it reads as
+/// an Arabic word once retyped, and the scores are weighted by the lengths of the words:
+///
+/// ```
+/// use arabic_script::{mistyping_score, KeyboardLayout};
+///
+/// assert!(mistyping_score("hgsghl ugd;l", KeyboardLayout::Arabic101) > 0.9);
+/// assert!(mistyping_score("Hello, how are you?", KeyboardLayout::Arabic101) < 0.5);
+/// ```
+///
+/// A text with no Latin letter scores 0.
+pub fn mistyping_score(text: &str, layout: KeyboardLayout) -> f64 {
+    let mut score = 0.0;
+    let mut length = 0;
+
+    for word in text.split_whitespace() {
+        if !word.chars().any(|c| c.is_ascii_alphabetic()) {
+            continue;
+        }
+
+        let typed: Option<Vec<ArabicCharacter>> = word
+            .chars()
+            .map(|key| layout.characters(key))
+            .collect::<Option<Vec<_>>>()
+            .map(|keys| keys.concat());
+        let arabic = typed.is_some_and(|typed| is_arabic_word(&typed));
+
+        let count = word.chars().count();
+        score += match (arabic, is_latin_word(word)) {
+            (true, false) => 1.0,
+            (true, true) => 0.5,
+            (false, false) => 0.25,
+            (false, true) => 0.0,
+        } * count as f64;
+        length += count;
+    }
+
+    if length == 0 {
+        0.0
+    } else {
+        score / length as f64
+    }
+}
+
+/// Returns `true` if a word reads as a word of a Latin language: ASCII letters, with a vowel, no
+/// long run of consonants, and a common onset, between optional punctuation.
+fn is_latin_word(word: &str) -> bool {
+    let word = word
+        .trim_start_matches(['"', '\'', '('])
+        .trim_end_matches(['.', ',', '!', '?', ';', ':', '"', '\'', ')']);
+    let letters: Vec<char> = word
+        .chars()
+        .filter(|c| *c != '\'' && *c != '-')
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+
+    if letters.is_empty() || !letters.iter().all(|c| c.is_ascii_alphabetic()) {
+        return false;
+    }
+
+    let is_vowel = |c: &char| "aeiouy".contains(*c);
+    let onset: String = letters.iter().take(2).collect();
+    letters.iter().any(is_vowel)
+        && letters
+            .split(is_vowel)
+            .all(|consonants| consonants.len() <= MAX_CONSONANT_RUN)
+        && (letters.len() < 2
+            || letters[..2].iter().any(is_vowel)
+            || LATIN_ONSETS.contains(&onset.as_str()))
+}
+
+/// Returns `true` if a word reads as an Arabic word: letters only, with the teh marbuta and the
+/// alef maksura last, and a likely root.
+fn is_arabic_word(word: &[ArabicCharacter]) -> bool {
+    let Some((_, body)) = word.split_last() else {
+        return false;
+    };
+
+    word.len() > 1
+        && word.iter().all(|c| c.is_letter())
+        && !body
+            .iter()
+            .any(|c| matches!(c, ArabicLetterTehMarbuta | ArabicLetterAlefMaksura))
+        && !word.windows(3).any(|w| w[0] == w[1] && w[1] == w[2])
+        && extract_roots(word)
+            .iter()
+            .any(|root| root.cost <= MAX_ROOT_COST && is_compatible(root))
+}
+
+/// Returns `true` if no two adjacent letters of a root are pronounced at the same point, or both
+/// in the throat, which the roots of Arabic avoid. A doubled last letter, and the weak letters and
+/// the hamza, are allowed.
+fn is_compatible(root: &RootCandidate) -> bool {
+    root.letters.windows(2).enumerate().all(|(index, pair)| {
+        let (first, second) = (pair[0], pair[1]);
+        if first == second {
+            return index > 0;
+        }
+
+        let weak = |c: ArabicCharacter| {
+            matches!(
+                c,
+                ArabicLetterHamza | ArabicLetterAlef | ArabicLetterWaw | ArabicLetterYeh
+            )
+        };
+        if weak(first) || weak(second) {
+            return true;
+        }
+
+        match (first.makhraj(), second.makhraj()) {
+            (Some(a), Some(b)) => {
+                a != b
+                    && !(a.region() == ArticulationRegion::Throat
+                        && b.region() == ArticulationRegion::Throat)
+            }
+            _ => true,
+        }
+    })
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::mistyping_score;
+    use crate::retype_as_arabic;
+    use crate::retype_as_latin;
+    use crate::KeyboardLayout::*;
+
+    #[test]
+    fn retypes_as_arabic() {
+        assert_eq!(retype_as_arabic("hgsghl ugd;l", Arabic101), "السلام عليكم");
+        assert_eq!(retype_as_arabic("hgsghl ugd;l", ArabicMac), "السلام عليكم");
+        assert_eq!(retype_as_arabic("hgsghl ugdml", Arabic102), "السلام عليكم");
+        assert_eq!(retype_as_arabic("sghl", Persian), "سلام");
+        assert_eq!(retype_as_arabic("slam", Urdu), "سلام");
+    }
+
+    #[test]
+    fn retypes_ligatures_and_digits() {
+        assert_eq!(retype_as_arabic("bHlf", Arabic101), "لاأمب");
+        assert_eq!(retype_as_arabic("2024", ArabicMac), "٢٠٢٤");
+        assert_eq!(retype_as_arabic("2024", Persian), "۲۰۲۴");
+        assert_eq!(retype_as_arabic("2024", Arabic101), "2024");
+        assert_eq!(retype_as_latin("لا أحد", Arabic101), "b Hp]");
+    }
+
+    #[test]
+    fn retypes_as_latin() {
+        assert_eq!(retype_as_latin("اثممخ صخقمي", Arabic101), "hello world");
+        assert_eq!(retype_as_latin("اثممخ صخقمي", Arabic102), "hello zorld");
+        assert_eq!(retype_as_latin("السلام عليكم", Arabic101), "hgsbl ugd;l");
+        assert_eq!(retype_as_latin("سلام", Urdu), "slam");
+    }
+
+    #[test]
+    fn scores_mistyped_text() {
+        assert!(mistyping_score("hgsghl ugd;l", Arabic101) > 0.9);
+        assert!(mistyping_score("lhgh jtug", Arabic101) > 0.5);
+        assert!(mistyping_score("wfhp hgodv", Arabic101) > 0.5);
+        assert!(mistyping_score("Hello, how are you?", Arabic101) < 0.25);
+        assert!(mistyping_score("The keyboard is broken", Arabic101) < 0.5);
+        assert!(mistyping_score("this is a test", Arabic101) < 0.5);
+        assert_eq!(mistyping_score("١٢٣ 456", Arabic101), 0.0);
+    }
+}
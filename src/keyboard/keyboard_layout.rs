@@ -0,0 +1,353 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicCharacter;
+use crate::ArabicDamma;
+use crate::ArabicDammatan;
+use crate::ArabicFatha;
+use crate::ArabicFathatan;
+use crate::ArabicIndicDigitEight;
+use crate::ArabicIndicDigitFive;
+use crate::ArabicIndicDigitFour;
+use crate::ArabicIndicDigitNine;
+use crate::ArabicIndicDigitOne;
+use crate::ArabicIndicDigitSeven;
+use crate::ArabicIndicDigitSix;
+use crate::ArabicIndicDigitThree;
+use crate::ArabicIndicDigitTwo;
+use crate::ArabicIndicDigitZero;
+use crate::ArabicKasra;
+use crate::ArabicKasratan;
+use crate::ArabicLetterAin;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterAlefMaksura;
+use crate::ArabicLetterAlefWithHamzaAbove;
+use crate::ArabicLetterAlefWithHamzaBelow;
+use crate::ArabicLetterAlefWithMaddaAbove;
+use crate::ArabicLetterBeh;
+use crate::ArabicLetterDad;
+use crate::ArabicLetterDal;
+use crate::ArabicLetterFeh;
+use crate::ArabicLetterGhain;
+use crate::ArabicLetterHah;
+use crate::ArabicLetterHamza;
+use crate::ArabicLetterHeh;
+use crate::ArabicLetterJeem;
+use crate::ArabicLetterKaf;
+use crate::ArabicLetterKhah;
+use crate::ArabicLetterLam;
+use crate::ArabicLetterMeem;
+use crate::ArabicLetterNoon;
+use crate::ArabicLetterQaf;
+use crate::ArabicLetterReh;
+use crate::ArabicLetterSad;
+use crate::ArabicLetterSeen;
+use crate::ArabicLetterSheen;
+use crate::ArabicLetterTah;
+use crate::ArabicLetterTeh;
+use crate::ArabicLetterTehMarbuta;
+use crate::ArabicLetterThal;
+use crate::ArabicLetterTheh;
+use crate::ArabicLetterWaw;
+use crate::ArabicLetterWawWithHamzaAbove;
+use crate::ArabicLetterYeh;
+use crate::ArabicLetterYehWithHamzaAbove;
+use crate::ArabicLetterZah;
+use crate::ArabicLetterZain;
+use crate::ArabicShadda;
+use crate::ArabicSukun;
+use crate::ArabicTatweel;
+use crate::ExtendedArabicIndicDigitEight;
+use crate::ExtendedArabicIndicDigitFive;
+use crate::ExtendedArabicIndicDigitFour;
+use crate::ExtendedArabicIndicDigitNine;
+use crate::ExtendedArabicIndicDigitOne;
+use crate::ExtendedArabicIndicDigitSeven;
+use crate::ExtendedArabicIndicDigitSix;
+use crate::ExtendedArabicIndicDigitThree;
+use crate::ExtendedArabicIndicDigitTwo;
+use crate::ExtendedArabicIndicDigitZero;
+
+/// The keys of the US QWERTY layout, unshifted then shifted, row by row.
+const QWERTY: [&str; 8] = [
+    "`1234567890-=",
+    "qwertyuiop[]\\",
+    "asdfghjkl;'",
+    "zxcvbnm,./",
+    "~!@#$%^&*()_+",
+    "QWERTYUIOP{}|",
+    "ASDFGHJKL:\"",
+    "ZXCVBNM<>?",
+];
+
+/// The keys of the French AZERTY layout, at the same positions as those of [`QWERTY`].
+const AZERTY: [&str; 8] = [
+    "²&é\"'(-è_çà)=",
+    "azertyuiop^$*",
+    "qsdfghjklmù",
+    "wxcvbn,;:!",
+    "\u{0}1234567890°+",
+    "AZERTYUIOP¨£µ",
+    "QSDFGHJKLM%",
+    "WXCVBN?./§",
+];
+
+/// The keys of the Arabic PC layout, as the QWERTY keys at the same positions.
+const ARABIC_PC: [(char, &[ArabicCharacter]); 49] = [
+    ('`', &[ArabicLetterThal]),
+    ('q', &[ArabicLetterDad]),
+    ('w', &[ArabicLetterSad]),
+    ('e', &[ArabicLetterTheh]),
+    ('r', &[ArabicLetterQaf]),
+    ('t', &[ArabicLetterFeh]),
+    ('y', &[ArabicLetterGhain]),
+    ('u', &[ArabicLetterAin]),
+    ('i', &[ArabicLetterHeh]),
+    ('o', &[ArabicLetterKhah]),
+    ('p', &[ArabicLetterHah]),
+    ('[', &[ArabicLetterJeem]),
+    (']', &[ArabicLetterDal]),
+    ('a', &[ArabicLetterSheen]),
+    ('s', &[ArabicLetterSeen]),
+    ('d', &[ArabicLetterYeh]),
+    ('f', &[ArabicLetterBeh]),
+    ('g', &[ArabicLetterLam]),
+    ('h', &[ArabicLetterAlef]),
+    ('j', &[ArabicLetterTeh]),
+    ('k', &[ArabicLetterNoon]),
+    ('l', &[ArabicLetterMeem]),
+    (';', &[ArabicLetterKaf]),
+    ('\'', &[ArabicLetterTah]),
+    ('z', &[ArabicLetterYehWithHamzaAbove]),
+    ('x', &[ArabicLetterHamza]),
+    ('c', &[ArabicLetterWawWithHamzaAbove]),
+    ('v', &[ArabicLetterReh]),
+    ('b', &[ArabicLetterLam, ArabicLetterAlef]),
+    ('n', &[ArabicLetterAlefMaksura]),
+    ('m', &[ArabicLetterTehMarbuta]),
+    (',', &[ArabicLetterWaw]),
+    ('.', &[ArabicLetterZain]),
+    ('/', &[ArabicLetterZah]),
+    ('~', &[ArabicShadda]),
+    ('Q', &[ArabicFatha]),
+    ('W', &[ArabicFathatan]),
+    ('E', &[ArabicDamma]),
+    ('R', &[ArabicDammatan]),
+    ('T', &[ArabicLetterLam, ArabicLetterAlefWithHamzaBelow]),
+    ('Y', &[ArabicLetterAlefWithHamzaBelow]),
+    ('A', &[ArabicKasra]),
+    ('S', &[ArabicKasratan]),
+    ('G', &[ArabicLetterLam, ArabicLetterAlefWithHamzaAbove]),
+    ('H', &[ArabicLetterAlefWithHamzaAbove]),
+    ('J', &[ArabicTatweel]),
+    ('X', &[ArabicSukun]),
+    ('B', &[ArabicLetterLam, ArabicLetterAlefWithMaddaAbove]),
+    ('N', &[ArabicLetterAlefWithMaddaAbove]),
+];
+
+/// The digit keys of the Arabic Mac layout, which types Arabic-Indic digits.
+const ARABIC_MAC_DIGITS: [(char, &[ArabicCharacter]); 10] = [
+    ('0', &[ArabicIndicDigitZero]),
+    ('1', &[ArabicIndicDigitOne]),
+    ('2', &[ArabicIndicDigitTwo]),
+    ('3', &[ArabicIndicDigitThree]),
+    ('4', &[ArabicIndicDigitFour]),
+    ('5', &[ArabicIndicDigitFive]),
+    ('6', &[ArabicIndicDigitSix]),
+    ('7', &[ArabicIndicDigitSeven]),
+    ('8', &[ArabicIndicDigitEight]),
+    ('9', &[ArabicIndicDigitNine]),
+];
+
+/// The keys of the standard Persian layout (ISIRI 9147), as the QWERTY keys at the same
+/// positions.
+const PERSIAN: [(char, &[ArabicCharacter]); 55] = [
+    ('0', &[ExtendedArabicIndicDigitZero]),
+    ('1', &[ExtendedArabicIndicDigitOne]),
+    ('2', &[ExtendedArabicIndicDigitTwo]),
+    ('3', &[ExtendedArabicIndicDigitThree]),
+    ('4', &[ExtendedArabicIndicDigitFour]),
+    ('5', &[ExtendedArabicIndicDigitFive]),
+    ('6', &[ExtendedArabicIndicDigitSix]),
+    ('7', &[ExtendedArabicIndicDigitSeven]),
+    ('8', &[ExtendedArabicIndicDigitEight]),
+    ('9', &[ExtendedArabicIndicDigitNine]),
+    ('q', &[ArabicLetterDad]),
+    ('w', &[ArabicLetterSad]),
+    ('e', &[ArabicLetterTheh]),
+    ('r', &[ArabicLetterQaf]),
+    ('t', &[ArabicLetterFeh]),
+    ('y', &[ArabicLetterGhain]),
+    ('u', &[ArabicLetterAin]),
+    ('i', &[ArabicLetterHeh]),
+    ('o', &[ArabicLetterKhah]),
+    ('p', &[ArabicLetterHah]),
+    ('[', &[ArabicLetterJeem]),
+    ('a', &[ArabicLetterSheen]),
+    ('s', &[ArabicLetterSeen]),
+    ('d', &[ArabicLetterYeh]),
+    ('f', &[ArabicLetterBeh]),
+    ('g', &[ArabicLetterLam]),
+    ('h', &[ArabicLetterAlef]),
+    ('j', &[ArabicLetterTeh]),
+    ('k', &[ArabicLetterNoon]),
+    ('l', &[ArabicLetterMeem]),
+    (';', &[ArabicLetterKaf]),
+    ('z', &[ArabicLetterZah]),
+    ('x', &[ArabicLetterTah]),
+    ('c', &[ArabicLetterZain]),
+    ('v', &[ArabicLetterReh]),
+    ('b', &[ArabicLetterThal]),
+    ('n', &[ArabicLetterDal]),
+    (',', &[ArabicLetterWaw]),
+    ('Q', &[ArabicSukun]),
+    ('W', &[ArabicDammatan]),
+    ('E', &[ArabicKasratan]),
+    ('R', &[ArabicFathatan]),
+    ('T', &[ArabicDamma]),
+    ('Y', &[ArabicKasra]),
+    ('U', &[ArabicFatha]),
+    ('I', &[ArabicShadda]),
+    ('A', &[ArabicLetterWawWithHamzaAbove]),
+    ('S', &[ArabicLetterYehWithHamzaAbove]),
+    ('D', &[ArabicLetterYeh]),
+    ('F', &[ArabicLetterAlefWithHamzaBelow]),
+    ('G', &[ArabicLetterAlefWithHamzaAbove]),
+    ('H', &[ArabicLetterAlefWithMaddaAbove]),
+    ('J', &[ArabicLetterTehMarbuta]),
+    ('Z', &[ArabicLetterKaf]),
+    ('M', &[ArabicLetterHamza]),
+];
+
+/// The keys of the phonetic Urdu layout, as the QWERTY keys of the same sounds.
+const URDU: [(char, &[ArabicCharacter]); 30] = [
+    ('a', &[ArabicLetterAlef]),
+    ('b', &[ArabicLetterBeh]),
+    ('d', &[ArabicLetterDal]),
+    ('e', &[ArabicLetterAin]),
+    ('f', &[ArabicLetterFeh]),
+    ('i', &[ArabicLetterYeh]),
+    ('j', &[ArabicLetterJeem]),
+    ('k', &[ArabicLetterKaf]),
+    ('l', &[ArabicLetterLam]),
+    ('m', &[ArabicLetterMeem]),
+    ('n', &[ArabicLetterNoon]),
+    ('q', &[ArabicLetterQaf]),
+    ('r', &[ArabicLetterReh]),
+    ('s', &[ArabicLetterSeen]),
+    ('t', &[ArabicLetterTeh]),
+    ('u', &[ArabicLetterHamza]),
+    ('v', &[ArabicLetterTah]),
+    ('w', &[ArabicLetterWaw]),
+    ('x', &[ArabicLetterSheen]),
+    ('z', &[ArabicLetterZain]),
+    ('A', &[ArabicLetterAlefWithMaddaAbove]),
+    ('C', &[ArabicLetterTheh]),
+    ('G', &[ArabicLetterGhain]),
+    ('H', &[ArabicLetterHah]),
+    ('J', &[ArabicLetterDad]),
+    ('K', &[ArabicLetterKhah]),
+    ('S', &[ArabicLetterSad]),
+    ('U', &[ArabicLetterYehWithHamzaAbove]),
+    ('V', &[ArabicLetterZah]),
+    ('Z', &[ArabicLetterThal]),
+];
+
+/// Represents a keyboard layout, that types Arabic characters from the keys of a Latin keyboard.
+///
+/// The keys are identified by the Latin characters they type when the Latin layout the keyboard
+/// ships with is active, such as `h` for the key that types an alef in the Arabic 101 layout:
+///
+/// ```
+/// use arabic_script::{ArabicLetterAlef, ArabicLetterKaf, KeyboardLayout};
+///
+/// assert_eq!(KeyboardLayout::Arabic101.characters('h'), Some(&[ArabicLetterAlef][..]));
+/// assert_eq!(KeyboardLayout::Arabic101.key(ArabicLetterKaf), Some(';'));
+/// assert_eq!(KeyboardLayout::Arabic102.key(ArabicLetterKaf), Some('m'));
+/// ```
+///
+/// The Persian and Urdu layouts type their keheh and Farsi yeh as the kaf and the yeh, while their
+/// keys for the letters outside the Arabic alphabet, such as پ or گ, are not mapped.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum KeyboardLayout {
+    /// The Arabic 101 layout of Windows, on a US QWERTY keyboard.
+    #[default]
+    Arabic101,
+
+    /// The Arabic 102 layout of Windows, with the same Arabic keys on a French AZERTY keyboard.
+    Arabic102,
+
+    /// The Arabic layout of macOS, which types Arabic-Indic digits.
+    ArabicMac,
+
+    /// The standard Persian layout (ISIRI 9147), which types Extended Arabic-Indic digits.
+    Persian,
+
+    /// The phonetic Urdu layout, where each key types the letter of its Latin sound.
+    Urdu,
+}
+
+impl KeyboardLayout {
+    /// Returns the characters typed by a key, or [`None`] if the key types no Arabic character.
+    ///
+    /// A key types several characters when it types a ligature, such as the lam-alef.
+    pub fn characters(&self, key: char) -> Option<&'static [ArabicCharacter]> {
+        self.keys()
+            .find(|(k, _)| *k == key)
+            .map(|(_, characters)| characters)
+    }
+
+    /// Returns the key that types a character, or [`None`] if the layout cannot type it.
+    pub fn key(&self, character: ArabicCharacter) -> Option<char> {
+        self.keys()
+            .find(|(_, characters)| characters == &[character])
+            .map(|(key, _)| key)
+    }
+
+    /// Returns the keys of the layout, each with the characters it types.
+    pub(crate) fn keys(&self) -> impl Iterator<Item = (char, &'static [ArabicCharacter])> {
+        let table: &'static [(char, &[ArabicCharacter])] = match self {
+            KeyboardLayout::Arabic101 | KeyboardLayout::Arabic102 | KeyboardLayout::ArabicMac => {
+                &ARABIC_PC
+            }
+            KeyboardLayout::Persian => &PERSIAN,
+            KeyboardLayout::Urdu => &URDU,
+        };
+        let digits: &'static [(char, &[ArabicCharacter])] = match self {
+            KeyboardLayout::ArabicMac => &ARABIC_MAC_DIGITS,
+            _ => &[],
+        };
+        let azerty = *self == KeyboardLayout::Arabic102;
+
+        table
+            .iter()
+            .chain(digits)
+            .filter_map(move |(key, characters)| {
+                let key = if azerty { azerty_key(*key)? } else { *key };
+                Some((key, *characters))
+            })
+    }
+}
+
+/// Returns the AZERTY key at the position of a QWERTY key, or [`None`] if there is none.
+fn azerty_key(key: char) -> Option<char> {
+    QWERTY
+        .iter()
+        .zip(AZERTY)
+        .find_map(|(qwerty, azerty)| {
+            let position = qwerty.chars().position(|k| k == key)?;
+            azerty.chars().nth(position)
+        })
+        .filter(|key| *key != '\u{0}')
+}
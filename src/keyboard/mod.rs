@@ -0,0 +1,23 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+//! The **Keyboard** module recovers text typed with the wrong keyboard layout active.
+
+pub use keyboard_converter::mistyping_score;
+pub use keyboard_converter::retype_as_arabic;
+pub use keyboard_converter::retype_as_latin;
+pub use keyboard_layout::KeyboardLayout;
+
+mod keyboard_converter;
+mod keyboard_layout;
@@ -21,11 +21,37 @@
 //! standardized Unicode name, providing easy access to all Arabic characters, with none of the
 //! hassle of manipulating primitive Rust characters or Unicode code points.
 
+pub use crate::abjad::abjad_word_value;
+pub use crate::abjad::to_abjad;
+pub use crate::abjad::AbjadSystem;
+pub use crate::arabizi::convert_arabizi;
+pub use crate::arabizi::convert_arabizi_word;
+pub use crate::arabizi::ArabiziCandidate;
+pub use crate::arabizi::ArabiziProfile;
+pub use crate::bidi::insert_directional_marks;
+pub use crate::bidi::isolate_fragment;
+pub use crate::bidi::BidiClass;
+pub use crate::bidi::BidiParagraph;
+pub use crate::bidi::BidiResolver;
+pub use crate::bidi::ParagraphDirection;
+pub use crate::collation::Collator;
+pub use crate::collation::LetterOrder;
 pub use crate::core::ArabicCharacter;
 pub use crate::core::ArabicCharacter::ArabicDamma;
 pub use crate::core::ArabicCharacter::ArabicDammatan;
+pub use crate::core::ArabicCharacter::ArabicDecimalSeparator;
 pub use crate::core::ArabicCharacter::ArabicFatha;
 pub use crate::core::ArabicCharacter::ArabicFathatan;
+pub use crate::core::ArabicCharacter::ArabicIndicDigitEight;
+pub use crate::core::ArabicCharacter::ArabicIndicDigitFive;
+pub use crate::core::ArabicCharacter::ArabicIndicDigitFour;
+pub use crate::core::ArabicCharacter::ArabicIndicDigitNine;
+pub use crate::core::ArabicCharacter::ArabicIndicDigitOne;
+pub use crate::core::ArabicCharacter::ArabicIndicDigitSeven;
+pub use crate::core::ArabicCharacter::ArabicIndicDigitSix;
+pub use crate::core::ArabicCharacter::ArabicIndicDigitThree;
+pub use crate::core::ArabicCharacter::ArabicIndicDigitTwo;
+pub use crate::core::ArabicCharacter::ArabicIndicDigitZero;
 pub use crate::core::ArabicCharacter::ArabicKasra;
 pub use crate::core::ArabicCharacter::ArabicKasratan;
 pub use crate::core::ArabicCharacter::ArabicLetterAin;
@@ -46,6 +72,7 @@ pub use crate::core::ArabicCharacter::ArabicLetterJeem;
 pub use crate::core::ArabicCharacter::ArabicLetterKaf;
 pub use crate::core::ArabicCharacter::ArabicLetterKhah;
 pub use crate::core::ArabicCharacter::ArabicLetterLam;
+pub use crate::core::ArabicCharacter::ArabicLetterMark;
 pub use crate::core::ArabicCharacter::ArabicLetterMeem;
 pub use crate::core::ArabicCharacter::ArabicLetterNoon;
 pub use crate::core::ArabicCharacter::ArabicLetterQaf;
@@ -64,9 +91,99 @@ pub use crate::core::ArabicCharacter::ArabicLetterYeh;
 pub use crate::core::ArabicCharacter::ArabicLetterYehWithHamzaAbove;
 pub use crate::core::ArabicCharacter::ArabicLetterZah;
 pub use crate::core::ArabicCharacter::ArabicLetterZain;
+pub use crate::core::ArabicCharacter::ArabicPercentSign;
 pub use crate::core::ArabicCharacter::ArabicShadda;
 pub use crate::core::ArabicCharacter::ArabicSukun;
 pub use crate::core::ArabicCharacter::ArabicTatweel;
+pub use crate::core::ArabicCharacter::ArabicThousandsSeparator;
+pub use crate::core::ArabicCharacter::ExtendedArabicIndicDigitEight;
+pub use crate::core::ArabicCharacter::ExtendedArabicIndicDigitFive;
+pub use crate::core::ArabicCharacter::ExtendedArabicIndicDigitFour;
+pub use crate::core::ArabicCharacter::ExtendedArabicIndicDigitNine;
+pub use crate::core::ArabicCharacter::ExtendedArabicIndicDigitOne;
+pub use crate::core::ArabicCharacter::ExtendedArabicIndicDigitSeven;
+pub use crate::core::ArabicCharacter::ExtendedArabicIndicDigitSix;
+pub use crate::core::ArabicCharacter::ExtendedArabicIndicDigitThree;
+pub use crate::core::ArabicCharacter::ExtendedArabicIndicDigitTwo;
+pub use crate::core::ArabicCharacter::ExtendedArabicIndicDigitZero;
 pub use crate::core::UnicodeCharacter;
+pub use crate::hamza::check_hamza;
+pub use crate::hamza::seat_hamza;
+pub use crate::hamza::HamzaIssue;
+pub use crate::keyboard::mistyping_score;
+pub use crate::keyboard::retype_as_arabic;
+pub use crate::keyboard::retype_as_latin;
+pub use crate::keyboard::KeyboardLayout;
+pub use crate::normalization::strip_tashkeel;
+pub use crate::normalization::strip_tashkeel_with_offsets;
+pub use crate::normalization::NormalizationRule;
+pub use crate::normalization::Normalizer;
+pub use crate::normalization::OffsetMap;
+pub use crate::normalization::SourceSpan;
+pub use crate::numbers::DigitSystem;
+pub use crate::numbers::NumberFormatter;
+pub use crate::numbers::NumberParseError;
+pub use crate::numbers::NumberParser;
+pub use crate::numbers::ParsedNumber;
+pub use crate::numbers::SignPosition;
+pub use crate::phonemization::phonemize;
+pub use crate::phonemization::phonemize_word;
+pub use crate::phonemization::to_ipa;
+pub use crate::phonemization::Phoneme;
+pub use crate::phonemization::PhonemeKind;
+pub use crate::phonology::ArticulationRegion;
+pub use crate::phonology::Makhraj;
+pub use crate::phonology::Sifat;
+pub use crate::phonology::Strength;
+pub use crate::romanization::romanize;
+pub use crate::romanization::RomanizationSystem;
+pub use crate::roots::extract_roots;
+pub use crate::roots::Pattern;
+pub use crate::roots::RootCandidate;
+pub use crate::segmentation::segment;
+pub use crate::segmentation::segment_word;
+pub use crate::segmentation::SegmentedWord;
+pub use crate::spelling::spell;
+pub use crate::spelling::spell_word;
+pub use crate::stemming::Stemmer;
+pub use crate::stemming::StemmingAlgorithm;
+pub use crate::tafqit::CountedNoun;
+pub use crate::tafqit::Currency;
+pub use crate::tafqit::Gender;
+pub use crate::tafqit::GrammaticalCase;
+pub use crate::tafqit::Tafqit;
+pub use crate::tajweed::annotate_tajweed;
+pub use crate::tajweed::annotate_tajweed_word;
+pub use crate::tajweed::TajweedHtmlRenderer;
+pub use crate::tajweed::TajweedRule;
+pub use crate::tajweed::TajweedScheme;
+pub use crate::tajweed::TajweedSpan;
+pub use crate::transliteration::from_buckwalter;
+pub use crate::transliteration::to_buckwalter;
+pub use crate::transliteration::BuckwalterVariant;
+pub use crate::transliteration::TransliterationError;
+pub use crate::transliteration::UnmappedSymbol;
+pub use crate::vocalization::validate_vocalization;
+pub use crate::vocalization::VocalizationIssue;
+pub use crate::vocalization::VocalizationIssueReason;
 
+mod abjad;
+mod arabizi;
+mod bidi;
+mod collation;
 mod core;
+mod hamza;
+mod keyboard;
+mod normalization;
+mod numbers;
+mod phonemization;
+mod phonology;
+mod romanization;
+mod roots;
+mod segmentation;
+mod spelling;
+mod stemming;
+mod tafqit;
+mod tajweed;
+mod transliteration;
+mod vocalization;
@@ -0,0 +1,28 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+//! The **Normalization** module folds the orthographic variants of Arabic text for searching.
+
+pub use normalization_rule::NormalizationRule;
+pub use normalizer::Normalizer;
+pub use offset_map::OffsetMap;
+pub use source_span::SourceSpan;
+pub use tashkeel_stripper::strip_tashkeel;
+pub use tashkeel_stripper::strip_tashkeel_with_offsets;
+
+mod normalization_rule;
+mod normalizer;
+mod offset_map;
+mod source_span;
+mod tashkeel_stripper;
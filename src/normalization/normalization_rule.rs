@@ -0,0 +1,37 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+/// Represents an orthographic rule applied by a [`Normalizer`][`crate::Normalizer`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NormalizationRule {
+    /// Folds the Arabic Letter Alef With Madda Above, the Arabic Letter Alef With Hamza Above,
+    /// and the Arabic Letter Alef With Hamza Below into the Arabic Letter Alef.
+    FoldAlef,
+
+    /// Folds the Arabic Letter Alef Maksura into the Arabic Letter Yeh.
+    FoldAlefMaksura,
+
+    /// Folds the Arabic Letter Teh Marbuta into the Arabic Letter Heh.
+    FoldTehMarbuta,
+
+    /// Folds the Arabic Letter Waw With Hamza Above into the Arabic Letter Waw, and the Arabic
+    /// Letter Yeh With Hamza Above into the Arabic Letter Yeh.
+    FoldHamza,
+
+    /// Removes every Arabic Tatweel.
+    RemoveTatweel,
+
+    /// Removes every diacritical mark (_tashkeel_).
+    RemoveTashkeel,
+}
@@ -0,0 +1,245 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicCharacter;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterAlefMaksura;
+use crate::ArabicLetterAlefWithHamzaAbove;
+use crate::ArabicLetterAlefWithHamzaBelow;
+use crate::ArabicLetterAlefWithMaddaAbove;
+use crate::ArabicLetterHeh;
+use crate::ArabicLetterTehMarbuta;
+use crate::ArabicLetterWaw;
+use crate::ArabicLetterWawWithHamzaAbove;
+use crate::ArabicLetterYeh;
+use crate::ArabicLetterYehWithHamzaAbove;
+use crate::ArabicTatweel;
+use crate::NormalizationRule;
+use crate::OffsetMap;
+use crate::SourceSpan;
+use crate::UnicodeCharacter;
+
+/// Folds the orthographic variants of Arabic text according to a set of rules, so that the text
+/// can be searched regardless of the way it was spelled.
+///
+/// A normalizer starts either empty or from one of its presets, and its rules can be toggled
+/// individually:
+///
+/// ```
+/// use arabic_script::{NormalizationRule, Normalizer};
+///
+/// let normalizer = Normalizer::light().with_rule(NormalizationRule::FoldTehMarbuta);
+///
+/// assert_eq!(normalizer.normalize("مَكْتَبَة"), "مكتبه");
+/// ```
+///
+/// Characters that do not belong to the Arabic script are left untouched.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Normalizer {
+    rules: Vec<NormalizationRule>,
+}
+
+impl Normalizer {
+    /// Returns a new normalizer that applies no rules.
+    pub fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Returns a new normalizer that folds the alef variants and removes the tatweel and the
+    /// diacritical marks.
+    pub fn light() -> Self {
+        Self::new()
+            .with_rule(NormalizationRule::FoldAlef)
+            .with_rule(NormalizationRule::RemoveTatweel)
+            .with_rule(NormalizationRule::RemoveTashkeel)
+    }
+
+    /// Returns a new normalizer that applies every rule.
+    pub fn aggressive() -> Self {
+        Self::light()
+            .with_rule(NormalizationRule::FoldAlefMaksura)
+            .with_rule(NormalizationRule::FoldTehMarbuta)
+            .with_rule(NormalizationRule::FoldHamza)
+    }
+
+    /// Returns the normalizer with the given rule enabled.
+    pub fn with_rule(mut self, rule: NormalizationRule) -> Self {
+        if !self.has_rule(rule) {
+            self.rules.push(rule);
+        }
+        self
+    }
+
+    /// Returns the normalizer with the given rule disabled.
+    pub fn without_rule(mut self, rule: NormalizationRule) -> Self {
+        self.rules.retain(|r| r != &rule);
+        self
+    }
+
+    /// Returns `true` if the given rule is enabled.
+    pub fn has_rule(&self, rule: NormalizationRule) -> bool {
+        self.rules.contains(&rule)
+    }
+
+    /// Returns the normalized form of a character, or [`None`] if the character is removed.
+    pub fn normalize_character(&self, character: ArabicCharacter) -> Option<ArabicCharacter> {
+        match character {
+            ArabicLetterAlefWithMaddaAbove
+            | ArabicLetterAlefWithHamzaAbove
+            | ArabicLetterAlefWithHamzaBelow
+                if self.has_rule(NormalizationRule::FoldAlef) =>
+            {
+                Some(ArabicLetterAlef)
+            }
+            ArabicLetterAlefMaksura if self.has_rule(NormalizationRule::FoldAlefMaksura) => {
+                Some(ArabicLetterYeh)
+            }
+            ArabicLetterTehMarbuta if self.has_rule(NormalizationRule::FoldTehMarbuta) => {
+                Some(ArabicLetterHeh)
+            }
+            ArabicLetterWawWithHamzaAbove if self.has_rule(NormalizationRule::FoldHamza) => {
+                Some(ArabicLetterWaw)
+            }
+            ArabicLetterYehWithHamzaAbove if self.has_rule(NormalizationRule::FoldHamza) => {
+                Some(ArabicLetterYeh)
+            }
+            ArabicTatweel if self.has_rule(NormalizationRule::RemoveTatweel) => None,
+            c if c.is_diacritic() && self.has_rule(NormalizationRule::RemoveTashkeel) => None,
+            c => Some(c),
+        }
+    }
+
+    /// Returns the normalized form of a text.
+    pub fn normalize(&self, text: &str) -> String {
+        text.chars()
+            .filter_map(|c| self.normalize_char(c))
+            .collect()
+    }
+
+    /// Returns the normalized form of a text, along with the map of its offsets back to the
+    /// source text.
+    ///
+    /// See [`OffsetMap`] for the way removed characters are attributed.
+    pub fn normalize_with_offsets(&self, text: &str) -> (String, OffsetMap) {
+        let mut normalized = String::with_capacity(text.len());
+        let mut offsets = OffsetMap::new();
+        let mut pending: Option<(usize, usize)> = None;
+
+        for (index, (byte, c)) in text.char_indices().enumerate() {
+            let (byte_end, char_end) = (byte + c.len_utf8(), index + 1);
+
+            match self.normalize_char(c) {
+                Some(n) => {
+                    let (byte_start, char_start) = pending.take().unwrap_or((byte, index));
+                    normalized.push(n);
+                    offsets.push(
+                        n,
+                        SourceSpan {
+                            bytes: byte_start..byte_end,
+                            chars: char_start..char_end,
+                        },
+                    );
+                }
+                None => {
+                    if !offsets.extend_last(byte_end, char_end) && pending.is_none() {
+                        pending = Some((byte, index));
+                    }
+                }
+            }
+        }
+
+        (normalized, offsets)
+    }
+
+    /// Returns the normalized form of a primitive character, or [`None`] if it is removed.
+    fn normalize_char(&self, c: char) -> Option<char> {
+        match ArabicCharacter::try_from(c) {
+            Ok(character) => self
+                .normalize_character(character)
+                .map(|n| n.scalar_value()),
+            Err(()) => Some(c),
+        }
+    }
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::NormalizationRule;
+    use crate::Normalizer;
+
+    #[test]
+    fn applies_no_rules_by_default() {
+        assert_eq!(Normalizer::new().normalize("أَحْمَـد"), "أَحْمَـد");
+    }
+
+    #[test]
+    fn applies_the_light_preset() {
+        let normalizer = Normalizer::light();
+
+        assert_eq!(normalizer.normalize("إِسْلَام"), "اسلام");
+        assert_eq!(normalizer.normalize("آمَـــنَ"), "امن");
+        assert_eq!(normalizer.normalize("مُسْتَشْفَى"), "مستشفى");
+    }
+
+    #[test]
+    fn applies_the_aggressive_preset() {
+        let normalizer = Normalizer::aggressive();
+
+        assert_eq!(normalizer.normalize("مُسْتَشْفَى"), "مستشفي");
+        assert_eq!(normalizer.normalize("مَدْرَسَة"), "مدرسه");
+        assert_eq!(normalizer.normalize("مَسْؤُول"), "مسوول");
+        assert_eq!(normalizer.normalize("رئيس"), "رييس");
+    }
+
+    #[test]
+    fn toggles_individual_rules() {
+        let normalizer = Normalizer::aggressive().without_rule(NormalizationRule::RemoveTashkeel);
+
+        assert!(!normalizer.has_rule(NormalizationRule::RemoveTashkeel));
+        assert_eq!(normalizer.normalize("أَحْمَد"), "اَحْمَد");
+    }
+
+    #[test]
+    fn maps_normalized_offsets_back_to_the_source() {
+        let source = "ـبِسْمِ اللَّهِ";
+        let (normalized, offsets) = Normalizer::light().normalize_with_offsets(source);
+
+        assert_eq!(normalized, "بسم الله");
+        assert_eq!(offsets.len(), normalized.chars().count());
+
+        let first = offsets.source_of_char(0).unwrap();
+        assert_eq!(first.chars, 0..3);
+        assert_eq!(&source[first.bytes.clone()], "ـبِ");
+
+        let hit = normalized.find("الله").unwrap();
+        let span = offsets.source_of_bytes(hit..normalized.len()).unwrap();
+        assert_eq!(&source[span.bytes], "اللَّهِ");
+        assert_eq!(span.chars, 8..15);
+
+        assert_eq!(offsets.source_of_byte(hit + 1), None);
+        assert_eq!(offsets.source_of_bytes(0..1), None);
+    }
+
+    #[test]
+    fn leaves_other_characters_untouched() {
+        assert_eq!(
+            Normalizer::aggressive().normalize("Qur'ān: قُرْآن"),
+            "Qur'ān: قران"
+        );
+    }
+}
@@ -0,0 +1,123 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use std::ops::Range;
+
+use crate::SourceSpan;
+
+/// Maps each position of a normalized text back to the span of the source text it came from.
+///
+/// Characters removed during normalization, such as diacritical marks, are attributed to the span
+/// of the character preceding them, so that a search hit found in the normalized text highlights
+/// the fully vocalized letters of the source:
+///
+/// ```
+/// use arabic_script::Normalizer;
+///
+/// let source = "قَالَ الْكِتَابُ";
+/// let (normalized, offsets) = Normalizer::light().normalize_with_offsets(source);
+///
+/// let hit = normalized.find("كتاب").unwrap();
+/// let span = offsets.source_of_bytes(hit..hit + "كتاب".len()).unwrap();
+///
+/// assert_eq!(&source[span.bytes], "كِتَابُ");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OffsetMap {
+    byte_offsets: Vec<usize>,
+    byte_length: usize,
+    spans: Vec<SourceSpan>,
+}
+
+impl OffsetMap {
+    /// Returns a new, empty offset map.
+    pub fn new() -> Self {
+        Self {
+            byte_offsets: Vec::new(),
+            byte_length: 0,
+            spans: Vec::new(),
+        }
+    }
+
+    /// Returns the number of characters of the normalized text.
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Returns `true` if the normalized text is empty.
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Returns the source span of the normalized character at the given character index.
+    pub fn source_of_char(&self, index: usize) -> Option<&SourceSpan> {
+        self.spans.get(index)
+    }
+
+    /// Returns the source span of the normalized character starting at the given byte offset.
+    pub fn source_of_byte(&self, offset: usize) -> Option<&SourceSpan> {
+        let index = self.byte_offsets.binary_search(&offset).ok()?;
+        self.spans.get(index)
+    }
+
+    /// Returns the source span covering the given range of normalized characters.
+    pub fn source_of_chars(&self, range: Range<usize>) -> Option<SourceSpan> {
+        if range.is_empty() {
+            return None;
+        }
+
+        let first = self.spans.get(range.start)?;
+        let last = self.spans.get(range.end - 1)?;
+
+        Some(SourceSpan {
+            bytes: first.bytes.start..last.bytes.end,
+            chars: first.chars.start..last.chars.end,
+        })
+    }
+
+    /// Returns the source span covering the given byte range of the normalized text.
+    ///
+    /// Both ends of the range must fall on character boundaries of the normalized text.
+    pub fn source_of_bytes(&self, range: Range<usize>) -> Option<SourceSpan> {
+        let start = self.byte_offsets.binary_search(&range.start).ok()?;
+        let end = match self.byte_offsets.binary_search(&range.end) {
+            Ok(end) => end,
+            Err(end) if range.end == self.byte_length => end,
+            Err(_) => return None,
+        };
+
+        self.source_of_chars(start..end)
+    }
+
+    /// Records the next character of the normalized text, produced from the given source span.
+    pub(crate) fn push(&mut self, character: char, span: SourceSpan) {
+        self.byte_offsets.push(self.byte_length);
+        self.byte_length += character.len_utf8();
+        self.spans.push(span);
+    }
+
+    /// Extends the source span of the last normalized character up to the given source positions.
+    ///
+    /// Returns `false` if no normalized character has been recorded yet.
+    pub(crate) fn extend_last(&mut self, byte_end: usize, char_end: usize) -> bool {
+        match self.spans.last_mut() {
+            Some(span) => {
+                span.bytes.end = byte_end;
+                span.chars.end = char_end;
+                true
+            }
+            None => false,
+        }
+    }
+}
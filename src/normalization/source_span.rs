@@ -0,0 +1,25 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use std::ops::Range;
+
+/// Represents the span of a source text from which a normalized character was produced.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SourceSpan {
+    /// The range of the span in bytes, suitable for slicing the source [`str`].
+    pub bytes: Range<usize>,
+
+    /// The range of the span in characters.
+    pub chars: Range<usize>,
+}
@@ -0,0 +1,37 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::NormalizationRule;
+use crate::Normalizer;
+use crate::OffsetMap;
+
+/// Returns the text with every diacritical mark (_tashkeel_) removed.
+///
+/// ```
+/// assert_eq!(arabic_script::strip_tashkeel("مُحَمَّدٌ"), "محمد");
+/// ```
+pub fn strip_tashkeel(text: &str) -> String {
+    stripper().normalize(text)
+}
+
+/// Returns the text with every diacritical mark (_tashkeel_) removed, along with the map of its
+/// offsets back to the source text.
+pub fn strip_tashkeel_with_offsets(text: &str) -> (String, OffsetMap) {
+    stripper().normalize_with_offsets(text)
+}
+
+/// Returns a normalizer that only removes the diacritical marks.
+fn stripper() -> Normalizer {
+    Normalizer::new().with_rule(NormalizationRule::RemoveTashkeel)
+}
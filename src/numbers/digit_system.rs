@@ -0,0 +1,124 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicCharacter;
+use crate::ArabicIndicDigitEight;
+use crate::ArabicIndicDigitFive;
+use crate::ArabicIndicDigitFour;
+use crate::ArabicIndicDigitNine;
+use crate::ArabicIndicDigitOne;
+use crate::ArabicIndicDigitSeven;
+use crate::ArabicIndicDigitSix;
+use crate::ArabicIndicDigitThree;
+use crate::ArabicIndicDigitTwo;
+use crate::ArabicIndicDigitZero;
+use crate::ExtendedArabicIndicDigitEight;
+use crate::ExtendedArabicIndicDigitFive;
+use crate::ExtendedArabicIndicDigitFour;
+use crate::ExtendedArabicIndicDigitNine;
+use crate::ExtendedArabicIndicDigitOne;
+use crate::ExtendedArabicIndicDigitSeven;
+use crate::ExtendedArabicIndicDigitSix;
+use crate::ExtendedArabicIndicDigitThree;
+use crate::ExtendedArabicIndicDigitTwo;
+use crate::ExtendedArabicIndicDigitZero;
+use crate::UnicodeCharacter;
+
+/// The Arabic-Indic digits, from zero to nine.
+const ARABIC_INDIC_DIGITS: [ArabicCharacter; 10] = [
+    ArabicIndicDigitZero,
+    ArabicIndicDigitOne,
+    ArabicIndicDigitTwo,
+    ArabicIndicDigitThree,
+    ArabicIndicDigitFour,
+    ArabicIndicDigitFive,
+    ArabicIndicDigitSix,
+    ArabicIndicDigitSeven,
+    ArabicIndicDigitEight,
+    ArabicIndicDigitNine,
+];
+
+/// The Extended Arabic-Indic digits, from zero to nine.
+const EXTENDED_ARABIC_INDIC_DIGITS: [ArabicCharacter; 10] = [
+    ExtendedArabicIndicDigitZero,
+    ExtendedArabicIndicDigitOne,
+    ExtendedArabicIndicDigitTwo,
+    ExtendedArabicIndicDigitThree,
+    ExtendedArabicIndicDigitFour,
+    ExtendedArabicIndicDigitFive,
+    ExtendedArabicIndicDigitSix,
+    ExtendedArabicIndicDigitSeven,
+    ExtendedArabicIndicDigitEight,
+    ExtendedArabicIndicDigitNine,
+];
+
+/// Represents a system of decimal digits used alongside the Arabic script.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DigitSystem {
+    /// The ASCII digits, as used in the Maghreb.
+    Western,
+
+    /// The Arabic-Indic digits, as used in most of the Arab world.
+    #[default]
+    ArabicIndic,
+
+    /// The Extended Arabic-Indic digits, as used in Iran, Afghanistan, and Pakistan.
+    ExtendedArabicIndic,
+}
+
+impl DigitSystem {
+    /// Returns the digit of the given value, from zero to nine.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is greater than nine.
+    pub fn digit(&self, value: u32) -> char {
+        assert!(value < 10, "a digit must be less than ten");
+
+        match self {
+            DigitSystem::Western => char::from(b'0' + value as u8),
+            DigitSystem::ArabicIndic => ARABIC_INDIC_DIGITS[value as usize].scalar_value(),
+            DigitSystem::ExtendedArabicIndic => {
+                EXTENDED_ARABIC_INDIC_DIGITS[value as usize].scalar_value()
+            }
+        }
+    }
+
+    /// Returns the value of a digit of the system, or [`None`] if the character is not one.
+    pub fn value(&self, digit: char) -> Option<u32> {
+        let digits = match self {
+            DigitSystem::Western => {
+                return digit.is_ascii_digit().then(|| digit as u32 - '0' as u32)
+            }
+            DigitSystem::ArabicIndic => &ARABIC_INDIC_DIGITS,
+            DigitSystem::ExtendedArabicIndic => &EXTENDED_ARABIC_INDIC_DIGITS,
+        };
+
+        digits
+            .iter()
+            .position(|d| d.scalar_value() == digit)
+            .map(|value| value as u32)
+    }
+
+    /// Returns the system a digit belongs to, or [`None`] if the character is not a digit.
+    pub fn of(digit: char) -> Option<Self> {
+        [
+            DigitSystem::Western,
+            DigitSystem::ArabicIndic,
+            DigitSystem::ExtendedArabicIndic,
+        ]
+        .into_iter()
+        .find(|system| system.value(digit).is_some())
+    }
+}
@@ -0,0 +1,30 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+//! The **Numbers** module formats and parses numbers with the digits and separators of the Arabic
+//! script.
+
+pub use digit_system::DigitSystem;
+pub use number_formatter::NumberFormatter;
+pub use number_parse_error::NumberParseError;
+pub use number_parser::NumberParser;
+pub use parsed_number::ParsedNumber;
+pub use sign_position::SignPosition;
+
+mod digit_system;
+mod number_formatter;
+mod number_parse_error;
+mod number_parser;
+mod parsed_number;
+mod sign_position;
@@ -0,0 +1,301 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicDecimalSeparator;
+use crate::ArabicThousandsSeparator;
+use crate::DigitSystem;
+use crate::SignPosition;
+use crate::UnicodeCharacter;
+
+/// The symbol of the CLDR for a value that is not a number.
+const NAN: &str = "ليس رقمًا";
+
+/// The symbol of the CLDR for infinity.
+const INFINITY: &str = "∞";
+
+/// Formats numbers with the digits, separators, and signs of a locale.
+///
+/// A formatter starts either from the defaults of the Arabic script or from the preset of a
+/// locale, and each of its symbols can be changed individually:
+///
+/// ```
+/// use arabic_script::{DigitSystem, NumberFormatter};
+///
+/// let formatter = NumberFormatter::for_locale("fa-IR").unwrap();
+/// assert_eq!(formatter.format_decimal(1234.5, 2), "۱٬۲۳۴٫۵۰");
+///
+/// let formatter = NumberFormatter::new().with_digits(DigitSystem::Western);
+/// assert_eq!(formatter.format_integer(1234567), "1٬234٬567");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NumberFormatter {
+    digits: DigitSystem,
+    decimal_separator: char,
+    grouping_separator: Option<char>,
+    minus_sign: &'static str,
+    percent_sign: &'static str,
+    sign_position: SignPosition,
+}
+
+impl NumberFormatter {
+    /// Returns a new formatter that uses the Arabic-Indic digits, the Arabic Decimal Separator,
+    /// the Arabic Thousands Separator, and the Arabic Percent Sign.
+    ///
+    /// The minus sign is preceded by an Arabic Letter Mark, so that it stays attached to the
+    /// number in right-to-left text.
+    pub fn new() -> Self {
+        Self {
+            digits: DigitSystem::ArabicIndic,
+            decimal_separator: ArabicDecimalSeparator.scalar_value(),
+            grouping_separator: Some(ArabicThousandsSeparator.scalar_value()),
+            minus_sign: "\u{061C}-",
+            percent_sign: "\u{066A}\u{061C}",
+            sign_position: SignPosition::Leading,
+        }
+    }
+
+    /// Returns the preset formatter of a locale, or [`None`] if the locale has no preset.
+    ///
+    /// The presets are available for the `ar-EG`, `fa-IR`, `ur-PK`, and `ar-MA` locales. The
+    /// latter uses the Western digits.
+    pub fn for_locale(locale: &str) -> Option<Self> {
+        let formatter = match locale.replace('_', "-").to_ascii_lowercase().as_str() {
+            "ar-eg" => Self::new(),
+            "fa-ir" => Self::new()
+                .with_digits(DigitSystem::ExtendedArabicIndic)
+                .with_minus_sign("\u{200E}\u{2212}")
+                .with_percent_sign("\u{066A}"),
+            "ur-pk" => Self::new()
+                .with_digits(DigitSystem::ExtendedArabicIndic)
+                .with_minus_sign("\u{200E}-")
+                .with_percent_sign("\u{066A}"),
+            "ar-ma" => Self::new()
+                .with_digits(DigitSystem::Western)
+                .with_decimal_separator(',')
+                .with_grouping_separator(Some('.'))
+                .with_minus_sign("\u{200E}-")
+                .with_percent_sign("\u{200E}%\u{200E}"),
+            _ => return None,
+        };
+
+        Some(formatter)
+    }
+
+    /// Returns the formatter with the given digits.
+    pub fn with_digits(mut self, digits: DigitSystem) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// Returns the formatter with the given separator between the integer and fractional parts.
+    pub fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Returns the formatter with the given separator between groups of thousands, or with no
+    /// grouping at all.
+    pub fn with_grouping_separator(mut self, separator: Option<char>) -> Self {
+        self.grouping_separator = separator;
+        self
+    }
+
+    /// Returns the formatter with the given minus sign, including any directional mark.
+    pub fn with_minus_sign(mut self, sign: &'static str) -> Self {
+        self.minus_sign = sign;
+        self
+    }
+
+    /// Returns the formatter with the given percent sign, including any directional mark.
+    pub fn with_percent_sign(mut self, sign: &'static str) -> Self {
+        self.percent_sign = sign;
+        self
+    }
+
+    /// Returns the formatter with the minus sign placed at the given side of the number.
+    pub fn with_sign_position(mut self, position: SignPosition) -> Self {
+        self.sign_position = position;
+        self
+    }
+
+    /// Formats an integer.
+    ///
+    /// ```
+    /// use arabic_script::{NumberFormatter, SignPosition};
+    ///
+    /// let formatter = NumberFormatter::new();
+    /// assert_eq!(formatter.format_integer(-1500), "\u{061C}-١٬٥٠٠");
+    ///
+    /// let formatter = formatter.with_minus_sign("-").with_sign_position(SignPosition::Trailing);
+    /// assert_eq!(formatter.format_integer(-1500), "١٬٥٠٠-");
+    /// ```
+    pub fn format_integer(&self, value: i64) -> String {
+        self.format(value < 0, &value.unsigned_abs().to_string(), None)
+    }
+
+    /// Formats a decimal number, rounded to the given number of fractional digits.
+    ///
+    /// A number that is not finite is formatted with the symbols of the CLDR: `ليس رقمًا` for NaN,
+    /// and `∞` with the minus sign of the formatter for the infinities.
+    pub fn format_decimal(&self, value: f64, fraction_digits: usize) -> String {
+        if value.is_nan() {
+            return NAN.to_string();
+        }
+        if value.is_infinite() {
+            return self.sign(value.is_sign_negative(), INFINITY);
+        }
+
+        let rendered = format!("{:.*}", fraction_digits, value.abs());
+        let (integer, fraction) = match rendered.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (rendered.as_str(), None),
+        };
+
+        self.format(value.is_sign_negative(), integer, fraction)
+    }
+
+    /// Formats a percentage, rounded to the given number of fractional digits.
+    ///
+    /// ```
+    /// use arabic_script::NumberFormatter;
+    ///
+    /// let formatter = NumberFormatter::for_locale("ur-PK").unwrap();
+    /// assert_eq!(formatter.format_percent(12.5, 1), "۱۲٫۵٪");
+    /// ```
+    pub fn format_percent(&self, value: f64, fraction_digits: usize) -> String {
+        if value.is_nan() {
+            return NAN.to_string();
+        }
+
+        let mut formatted = self.format_decimal(value, fraction_digits);
+        formatted.push_str(self.percent_sign);
+        formatted
+    }
+
+    /// Formats the ASCII digits of the integer and fractional parts of a number.
+    fn format(&self, negative: bool, integer: &str, fraction: Option<&str>) -> String {
+        let mut number = String::with_capacity(integer.len() * 3);
+
+        let offset = integer.len() % 3;
+        for (index, digit) in integer.chars().enumerate() {
+            if index > 0 && index % 3 == offset {
+                number.extend(self.grouping_separator);
+            }
+            number.push(self.digit(digit));
+        }
+
+        if let Some(fraction) = fraction {
+            number.push(self.decimal_separator);
+            number.extend(fraction.chars().map(|digit| self.digit(digit)));
+        }
+
+        let zero = integer
+            .chars()
+            .chain(fraction.unwrap_or_default().chars())
+            .all(|d| d == '0');
+        self.sign(negative && !zero, &number)
+    }
+
+    /// Places the minus sign of the formatter around a number, if it is negative.
+    fn sign(&self, negative: bool, number: &str) -> String {
+        if !negative {
+            return number.to_string();
+        }
+
+        match self.sign_position {
+            SignPosition::Leading => format!("{}{}", self.minus_sign, number),
+            SignPosition::Trailing => format!("{}{}", number, self.minus_sign),
+        }
+    }
+
+    /// Returns the digit of the formatter for an ASCII digit.
+    fn digit(&self, digit: char) -> char {
+        self.digits.digit(digit.to_digit(10).unwrap_or_default())
+    }
+}
+
+impl Default for NumberFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::DigitSystem;
+    use crate::NumberFormatter;
+    use crate::SignPosition;
+
+    #[test]
+    fn formats_integers() {
+        let formatter = NumberFormatter::new();
+
+        assert_eq!(formatter.format_integer(0), "٠");
+        assert_eq!(formatter.format_integer(999), "٩٩٩");
+        assert_eq!(formatter.format_integer(1000), "١٬٠٠٠");
+        assert_eq!(
+            formatter.format_integer(i64::MIN),
+            "\u{061C}-٩٬٢٢٣٬٣٧٢٬٠٣٦٬٨٥٤٬٧٧٥٬٨٠٨"
+        );
+    }
+
+    #[test]
+    fn formats_decimals() {
+        let formatter = NumberFormatter::new().with_grouping_separator(None);
+
+        assert_eq!(formatter.format_decimal(4.56789, 2), "٤٫٥٧");
+        assert_eq!(formatter.format_decimal(12345.5, 0), "١٢٣٤٦");
+        assert_eq!(formatter.format_decimal(-0.001, 2), "٠٫٠٠");
+        assert_eq!(formatter.format_decimal(-2.5, 1), "\u{061C}-٢٫٥");
+    }
+
+    #[test]
+    fn formats_values_that_are_not_finite() {
+        let formatter = NumberFormatter::new();
+        let trailing = NumberFormatter::for_locale("ar-MA")
+            .unwrap()
+            .with_sign_position(SignPosition::Trailing);
+
+        assert_eq!(formatter.format_decimal(f64::NAN, 2), "ليس رقمًا");
+        assert_eq!(formatter.format_decimal(f64::INFINITY, 2), "∞");
+        assert_eq!(formatter.format_decimal(f64::NEG_INFINITY, 2), "\u{061C}-∞");
+        assert_eq!(trailing.format_decimal(f64::NEG_INFINITY, 0), "∞\u{200E}-");
+        assert_eq!(formatter.format_percent(f64::NAN, 0), "ليس رقمًا");
+    }
+
+    #[test]
+    fn applies_locale_presets() {
+        let egypt = NumberFormatter::for_locale("ar-EG").unwrap();
+        let iran = NumberFormatter::for_locale("fa_IR").unwrap();
+        let pakistan = NumberFormatter::for_locale("ur-PK").unwrap();
+        let morocco = NumberFormatter::for_locale("ar-MA").unwrap();
+
+        assert_eq!(egypt.format_percent(45.0, 0), "٤٥٪\u{061C}");
+        assert_eq!(iran.format_integer(-42), "\u{200E}\u{2212}۴۲");
+        assert_eq!(pakistan.format_decimal(1234.25, 2), "۱٬۲۳۴٫۲۵");
+        assert_eq!(morocco.format_decimal(1234.25, 2), "1.234,25");
+        assert_eq!(NumberFormatter::for_locale("en-US"), None);
+    }
+
+    #[test]
+    fn maps_digit_systems() {
+        assert_eq!(DigitSystem::Western.digit(7), '7');
+        assert_eq!(DigitSystem::ArabicIndic.digit(7), '٧');
+        assert_eq!(DigitSystem::ExtendedArabicIndic.digit(7), '۷');
+    }
+}
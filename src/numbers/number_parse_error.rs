@@ -0,0 +1,75 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::DigitSystem;
+
+/// Represents the failure of the [`NumberParser`](crate::NumberParser) to read a number.
+///
+/// The indices are counted in characters, within the parsed text.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NumberParseError {
+    /// The text contains no digit.
+    Empty,
+
+    /// The text contains a character that is neither a digit, a separator, nor a sign, or one that
+    /// is out of place.
+    UnexpectedCharacter {
+        /// The index of the character.
+        index: usize,
+
+        /// The character itself.
+        character: char,
+    },
+
+    /// In strict mode, the text contains digits of more than one digit system.
+    MixedDigitSystems {
+        /// The index of the first digit that does not belong to the system of the first digit.
+        index: usize,
+
+        /// The system of the first digit of the text.
+        expected: DigitSystem,
+
+        /// The system of the offending digit.
+        found: DigitSystem,
+    },
+
+    /// The integer does not fit in an [`i64`].
+    OutOfRange,
+}
+
+impl Display for NumberParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NumberParseError::Empty => write!(f, "no digit to parse"),
+            NumberParseError::UnexpectedCharacter { index, character } => {
+                write!(f, "unexpected character {:?} at {}", character, index)
+            }
+            NumberParseError::MixedDigitSystems {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "digit at {} belongs to the {:?} system instead of the {:?} system",
+                index, found, expected
+            ),
+            NumberParseError::OutOfRange => write!(f, "integer out of range"),
+        }
+    }
+}
+
+impl Error for NumberParseError {}
@@ -0,0 +1,332 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicDecimalSeparator;
+use crate::ArabicThousandsSeparator;
+use crate::DigitSystem;
+use crate::NumberParseError;
+use crate::ParsedNumber;
+use crate::UnicodeCharacter;
+
+/// The directional marks that may surround a number or its sign.
+const DIRECTIONAL_MARKS: [char; 3] = ['\u{061C}', '\u{200E}', '\u{200F}'];
+
+/// The signs that may precede or follow a negative number.
+const MINUS_SIGNS: [char; 2] = ['-', '\u{2212}'];
+
+/// Parses numbers written with the Arabic-Indic, the Extended Arabic-Indic, or the ASCII digits.
+///
+/// The Arabic Decimal Separator and the full stop separate the integer part from the fractional
+/// part, and the Arabic Thousands Separator and the comma separate the groups of three digits of
+/// the integer part. A sign may precede or follow the number, and the directional marks are
+/// ignored:
+///
+/// ```
+/// use arabic_script::{NumberParser, ParsedNumber};
+///
+/// let parser = NumberParser::new();
+///
+/// assert_eq!(parser.parse("۱٬۲۳۴"), Ok(ParsedNumber::Integer(1234)));
+/// assert_eq!(parser.parse("\u{061C}-٣٫٥"), Ok(ParsedNumber::Decimal(-3.5)));
+/// ```
+///
+/// By default, the digits of a number may belong to different systems, as happens when a number
+/// is typed on a Persian keyboard and completed on an Arabic one. In strict mode, such a number is
+/// rejected:
+///
+/// ```
+/// use arabic_script::{DigitSystem, NumberParseError, NumberParser};
+///
+/// let parser = NumberParser::new().with_strict(true);
+///
+/// assert_eq!(
+///     parser.parse("۱٢"),
+///     Err(NumberParseError::MixedDigitSystems {
+///         index: 1,
+///         expected: DigitSystem::ExtendedArabicIndic,
+///         found: DigitSystem::ArabicIndic,
+///     })
+/// );
+/// ```
+///
+/// The locales that swap the full stop and the comma, such as `ar-MA`, have their own preset:
+///
+/// ```
+/// use arabic_script::{NumberParser, ParsedNumber};
+///
+/// let parser = NumberParser::for_locale("ar-MA").unwrap();
+///
+/// assert_eq!(parser.parse("1.234,50"), Ok(ParsedNumber::Decimal(1234.5)));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NumberParser {
+    strict: bool,
+    decimal_separator: char,
+    grouping_separator: char,
+}
+
+impl NumberParser {
+    /// Returns a new parser, that accepts digits of different systems within the same number, and
+    /// reads the full stop as the decimal separator and the comma as the grouping separator.
+    pub fn new() -> Self {
+        Self {
+            strict: false,
+            decimal_separator: '.',
+            grouping_separator: ',',
+        }
+    }
+
+    /// Returns the preset parser of a locale, or [`None`] if the locale has no preset.
+    ///
+    /// The presets are those of [`NumberFormatter::for_locale()`](crate::NumberFormatter).
+    pub fn for_locale(locale: &str) -> Option<Self> {
+        let parser = match locale.replace('_', "-").to_ascii_lowercase().as_str() {
+            "ar-eg" | "fa-ir" | "ur-pk" => Self::new(),
+            "ar-ma" => Self::new()
+                .with_decimal_separator(',')
+                .with_grouping_separator('.'),
+            _ => return None,
+        };
+
+        Some(parser)
+    }
+
+    /// Returns the parser, rejecting or accepting digits of different systems within a number.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Returns `true` if the parser rejects digits of different systems within a number.
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Returns the parser, reading the given character, besides the Arabic Decimal Separator, as
+    /// the separator between the integer and fractional parts.
+    pub fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Returns the parser, reading the given character, besides the Arabic Thousands Separator, as
+    /// the separator between groups of thousands.
+    pub fn with_grouping_separator(mut self, separator: char) -> Self {
+        self.grouping_separator = separator;
+        self
+    }
+
+    /// Parses a number, as an integer if it has no decimal separator, or as a decimal otherwise.
+    pub fn parse(&self, text: &str) -> Result<ParsedNumber, NumberParseError> {
+        let characters: Vec<(usize, char)> = text
+            .chars()
+            .enumerate()
+            .filter(|(_, c)| !DIRECTIONAL_MARKS.contains(c))
+            .skip_while(|(_, c)| c.is_whitespace())
+            .collect();
+        let end = characters
+            .iter()
+            .rposition(|(_, c)| !c.is_whitespace())
+            .map_or(0, |e| e + 1);
+        let characters = &characters[..end];
+        let last = end.saturating_sub(1);
+
+        let mut ascii = String::with_capacity(characters.len() + 1);
+        let mut negative = false;
+        let mut signed = false;
+        let mut system = None;
+        let mut separator = None;
+        let mut decimal = false;
+        let mut digits = false;
+        let mut group = 0;
+        let mut grouping = None;
+
+        for (position, &(index, character)) in characters.iter().enumerate() {
+            let unexpected = NumberParseError::UnexpectedCharacter { index, character };
+
+            if let Some(found) = DigitSystem::of(character) {
+                match system {
+                    Some(expected) if self.strict && expected != found => {
+                        return Err(NumberParseError::MixedDigitSystems {
+                            index,
+                            expected,
+                            found,
+                        });
+                    }
+                    Some(_) => {}
+                    None => system = Some(found),
+                }
+
+                ascii.extend(found.value(character).and_then(|v| char::from_digit(v, 10)));
+                separator = None;
+                digits = true;
+                group += 1;
+            } else if let Some(separator) = separator {
+                return Err(separator);
+            } else if self.is_decimal_separator(character) && digits && !decimal {
+                check_group(group, grouping)?;
+                ascii.push('.');
+                separator = Some(unexpected);
+                decimal = true;
+            } else if self.is_grouping_separator(character) && digits && !decimal {
+                if grouping.is_some() && group != 3 || group > 3 {
+                    return Err(unexpected);
+                }
+                separator = Some(unexpected);
+                grouping = Some(unexpected);
+                group = 0;
+            } else if is_sign(character) && !signed && (position == 0 || position == last) {
+                negative = MINUS_SIGNS.contains(&character);
+                signed = true;
+            } else {
+                return Err(unexpected);
+            }
+        }
+
+        if let Some(separator) = separator {
+            return Err(separator);
+        }
+        if !digits {
+            return Err(NumberParseError::Empty);
+        }
+        if !decimal {
+            check_group(group, grouping)?;
+        }
+        if negative {
+            ascii.insert(0, '-');
+        }
+
+        if decimal {
+            let value = ascii.parse().map_err(|_| NumberParseError::OutOfRange)?;
+            Ok(ParsedNumber::Decimal(value))
+        } else {
+            let value = ascii.parse().map_err(|_| NumberParseError::OutOfRange)?;
+            Ok(ParsedNumber::Integer(value))
+        }
+    }
+}
+
+impl NumberParser {
+    /// Returns `true` if the character separates the integer part of a number from its fraction.
+    fn is_decimal_separator(&self, character: char) -> bool {
+        character == self.decimal_separator || character == ArabicDecimalSeparator.scalar_value()
+    }
+
+    /// Returns `true` if the character separates the groups of thousands of a number.
+    fn is_grouping_separator(&self, character: char) -> bool {
+        character == self.grouping_separator || character == ArabicThousandsSeparator.scalar_value()
+    }
+}
+
+impl Default for NumberParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Checks that the last group of the integer part of a number has three digits, if the integer
+/// part is grouped, returning the error of the separator preceding the group otherwise.
+fn check_group(group: usize, grouping: Option<NumberParseError>) -> Result<(), NumberParseError> {
+    match grouping {
+        Some(separator) if group != 3 => Err(separator),
+        _ => Ok(()),
+    }
+}
+
+/// Returns `true` if the character is the sign of a number.
+fn is_sign(character: char) -> bool {
+    character == '+' || MINUS_SIGNS.contains(&character)
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::DigitSystem;
+    use crate::NumberFormatter;
+    use crate::NumberParseError;
+    use crate::NumberParser;
+    use crate::ParsedNumber;
+
+    #[test]
+    fn parses_each_digit_system() {
+        let parser = NumberParser::new();
+
+        assert_eq!(parser.parse("1,234.5"), Ok(ParsedNumber::Decimal(1234.5)));
+        assert_eq!(parser.parse("١٢٣٤"), Ok(ParsedNumber::Integer(1234)));
+        assert_eq!(parser.parse(" ۴۲ "), Ok(ParsedNumber::Integer(42)));
+        assert_eq!(parser.parse("+۰٫۲۵"), Ok(ParsedNumber::Decimal(0.25)));
+        assert_eq!(parser.parse("١٥-"), Ok(ParsedNumber::Integer(-15)));
+    }
+
+    #[test]
+    fn accepts_mixed_input_unless_strict() {
+        assert_eq!(
+            NumberParser::new().parse("۱2٣.5"),
+            Ok(ParsedNumber::Decimal(123.5))
+        );
+        assert_eq!(
+            NumberParser::new().with_strict(true).parse("۱۲3"),
+            Err(NumberParseError::MixedDigitSystems {
+                index: 2,
+                expected: DigitSystem::ExtendedArabicIndic,
+                found: DigitSystem::Western,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_numbers() {
+        let parser = NumberParser::new();
+        let unexpected =
+            |index, character| Err(NumberParseError::UnexpectedCharacter { index, character });
+
+        assert_eq!(parser.parse(""), Err(NumberParseError::Empty));
+        assert_eq!(parser.parse("-"), Err(NumberParseError::Empty));
+        assert_eq!(parser.parse("١٢أ"), unexpected(2, 'أ'));
+        assert_eq!(parser.parse("٫٥"), unexpected(0, '٫'));
+        assert_eq!(parser.parse("١٫٥٫٦"), unexpected(3, '٫'));
+        assert_eq!(parser.parse("١٬"), unexpected(1, '٬'));
+        assert_eq!(parser.parse("1,5"), unexpected(1, ','));
+        assert_eq!(parser.parse("1,23,456"), unexpected(4, ','));
+        assert_eq!(parser.parse("1234,567"), unexpected(4, ','));
+        assert_eq!(parser.parse("1,2345.5"), unexpected(1, ','));
+        assert_eq!(parser.parse("1.234,50"), unexpected(5, ','));
+        assert_eq!(parser.parse("١-٢"), unexpected(1, '-'));
+        assert_eq!(parser.parse("+١-"), unexpected(2, '-'));
+        assert_eq!(
+            parser.parse("٩٩٩٩٩٩٩٩٩٩٩٩٩٩٩٩٩٩٩٩"),
+            Err(NumberParseError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn reads_formatted_numbers() {
+        for locale in ["ar-EG", "fa-IR", "ur-PK", "ar-MA"] {
+            let formatter = NumberFormatter::for_locale(locale).unwrap();
+            let parser = NumberParser::for_locale(locale).unwrap();
+
+            assert_eq!(
+                parser.parse(&formatter.format_integer(-1234567)),
+                Ok(ParsedNumber::Integer(-1234567))
+            );
+            assert_eq!(
+                parser.parse(&formatter.format_decimal(1234.75, 2)),
+                Ok(ParsedNumber::Decimal(1234.75))
+            );
+        }
+    }
+}
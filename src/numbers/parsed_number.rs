@@ -0,0 +1,33 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+/// Represents a number read by the [`NumberParser`](crate::NumberParser).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ParsedNumber {
+    /// A number written without a decimal separator.
+    Integer(i64),
+
+    /// A number written with a decimal separator.
+    Decimal(f64),
+}
+
+impl ParsedNumber {
+    /// Returns the number as a floating-point number, whether it was written as an integer or not.
+    pub fn as_f64(&self) -> f64 {
+        match self {
+            ParsedNumber::Integer(value) => *value as f64,
+            ParsedNumber::Decimal(value) => *value,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+/// Represents the side of a number on which its sign is placed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SignPosition {
+    /// The sign precedes the number, in its logical order.
+    #[default]
+    Leading,
+
+    /// The sign follows the number, in its logical order, as in some right-to-left documents.
+    Trailing,
+}
@@ -0,0 +1,25 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+//! The **Phonemization** module transcribes vocalized Arabic text into its phonemes.
+
+pub use phoneme::Phoneme;
+pub use phoneme_kind::PhonemeKind;
+pub use phonemizer::phonemize;
+pub use phonemizer::phonemize_word;
+pub use phonemizer::to_ipa;
+
+mod phoneme;
+mod phoneme_kind;
+mod phonemizer;
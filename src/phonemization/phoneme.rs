@@ -0,0 +1,60 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use std::fmt::{Display, Formatter};
+
+use crate::PhonemeKind;
+
+/// Represents a phoneme, as transcribed in the International Phonetic Alphabet.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Phoneme {
+    /// The IPA symbol of the phoneme, without its length mark.
+    pub symbol: &'static str,
+
+    /// The kind of the phoneme.
+    pub kind: PhonemeKind,
+
+    /// Whether the phoneme is long.
+    pub long: bool,
+}
+
+impl Phoneme {
+    /// Returns a new consonant phoneme.
+    pub fn consonant(symbol: &'static str) -> Self {
+        Self {
+            symbol,
+            kind: PhonemeKind::Consonant,
+            long: false,
+        }
+    }
+
+    /// Returns a new vowel phoneme.
+    pub fn vowel(symbol: &'static str, long: bool) -> Self {
+        Self {
+            symbol,
+            kind: PhonemeKind::Vowel,
+            long,
+        }
+    }
+}
+
+impl Display for Phoneme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol)?;
+        if self.long {
+            write!(f, "ː")?;
+        }
+        Ok(())
+    }
+}
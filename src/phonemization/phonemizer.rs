@@ -0,0 +1,297 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::core::LetterCluster;
+use crate::core::TextSegment;
+use crate::ArabicCharacter;
+use crate::ArabicDamma;
+use crate::ArabicDammatan;
+use crate::ArabicFathatan;
+use crate::ArabicKasra;
+use crate::ArabicKasratan;
+use crate::ArabicLetterAin;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterAlefMaksura;
+use crate::ArabicLetterAlefWithMaddaAbove;
+use crate::ArabicLetterBeh;
+use crate::ArabicLetterDad;
+use crate::ArabicLetterDal;
+use crate::ArabicLetterFeh;
+use crate::ArabicLetterGhain;
+use crate::ArabicLetterHah;
+use crate::ArabicLetterHeh;
+use crate::ArabicLetterJeem;
+use crate::ArabicLetterKaf;
+use crate::ArabicLetterKhah;
+use crate::ArabicLetterLam;
+use crate::ArabicLetterMeem;
+use crate::ArabicLetterNoon;
+use crate::ArabicLetterQaf;
+use crate::ArabicLetterReh;
+use crate::ArabicLetterSad;
+use crate::ArabicLetterSeen;
+use crate::ArabicLetterSheen;
+use crate::ArabicLetterTah;
+use crate::ArabicLetterTeh;
+use crate::ArabicLetterTehMarbuta;
+use crate::ArabicLetterThal;
+use crate::ArabicLetterTheh;
+use crate::ArabicLetterWaw;
+use crate::ArabicLetterYeh;
+use crate::ArabicLetterZah;
+use crate::ArabicLetterZain;
+use crate::Phoneme;
+
+/// Transcribes a vocalized word into its phonemes.
+///
+/// A shadda doubles the consonant carrying it, a tanween is realized as its vowel followed by
+/// /n/, and the long vowels spelled with an alef, a waw, or a yeh are realized as long vowels. The
+/// lam of the definite article assimilates into the sun letter following it:
+///
+/// ```
+/// use arabic_script::{phonemize_word, ArabicCharacter};
+///
+/// let word: Vec<ArabicCharacter> = "الشَّمْسُ"
+///     .chars()
+///     .map(|c| ArabicCharacter::try_from(c).unwrap())
+///     .collect();
+/// let phonemes: String = phonemize_word(&word).iter().map(|p| p.to_string()).collect();
+///
+/// assert_eq!(phonemes, "ʔaʃʃamsu");
+/// ```
+///
+/// A vowelless Arabic Letter Teh Marbuta is read in its pausal form, and is not pronounced, nor is
+/// the alef written after the final waw of a plural.
+pub fn phonemize_word(word: &[ArabicCharacter]) -> Vec<Phoneme> {
+    let clusters = LetterCluster::read(word);
+    let mut phonemes = Vec::new();
+    let mut index = 0;
+
+    if LetterCluster::has_definite_article(&clusters) {
+        phonemes.push(Phoneme::consonant("ʔ"));
+        phonemes.push(Phoneme::vowel("a", false));
+
+        let sun = clusters[2].letter;
+        if sun.is_sun_letter() {
+            phonemes.extend(consonant(sun).map(Phoneme::consonant));
+        } else {
+            phonemes.push(Phoneme::consonant("l"));
+        }
+        index = 2;
+    }
+
+    let mut assimilated = index == 2 && clusters[2].letter.is_sun_letter();
+
+    while index < clusters.len() {
+        let cluster = clusters[index];
+        let previous = index.checked_sub(1).map(|i| clusters[i]);
+        let next = clusters.get(index + 1);
+        let initial = index == 0;
+        index += 1;
+
+        match cluster.letter {
+            ArabicLetterAlef
+                if next.is_none() && previous.is_some_and(|p| p.letter == ArabicLetterWaw) =>
+            {
+                continue;
+            }
+            ArabicLetterAlef | ArabicLetterAlefMaksura if !initial => {
+                phonemes.push(Phoneme::vowel("a", true));
+                continue;
+            }
+            ArabicLetterAlef | ArabicLetterAlefMaksura => {
+                if cluster.vowel.is_some_and(|v| v.is_short_vowel()) {
+                    phonemes.push(Phoneme::consonant("ʔ"));
+                }
+            }
+            ArabicLetterTehMarbuta if cluster.is_vowelless() => continue,
+            ArabicLetterAlefWithMaddaAbove => {
+                phonemes.push(Phoneme::consonant("ʔ"));
+                phonemes.push(Phoneme::vowel("a", true));
+                continue;
+            }
+            letter => {
+                if let Some(symbol) = consonant(letter) {
+                    phonemes.push(Phoneme::consonant(symbol));
+                    if cluster.shadda && !assimilated {
+                        phonemes.push(Phoneme::consonant(symbol));
+                    }
+                }
+            }
+        }
+        assimilated = false;
+
+        match cluster.vowel {
+            Some(vowel) if vowel.is_short_vowel() => {
+                let long =
+                    next.is_some_and(|n| !n.shadda && n.is_vowelless() && n.lengthens(vowel));
+
+                phonemes.push(Phoneme::vowel(short_vowel(vowel), long));
+                if long {
+                    index += 1;
+                }
+            }
+            Some(vowel) if vowel.is_tanween() => {
+                phonemes.push(Phoneme::vowel(short_vowel(vowel), false));
+                phonemes.push(Phoneme::consonant("n"));
+
+                let seated = next.is_some_and(|n| {
+                    matches!(n.letter, ArabicLetterAlef | ArabicLetterAlefMaksura)
+                });
+                if vowel == ArabicFathatan && seated {
+                    index += 1;
+                }
+            }
+            None if next.is_some_and(|n| n.letter == ArabicLetterAlef && n.vowel.is_none()) => {
+                phonemes.push(Phoneme::vowel("a", true));
+                index += 1;
+            }
+            _ => {}
+        }
+    }
+
+    phonemes
+}
+
+/// Transcribes each word of a vocalized text into its phonemes.
+///
+/// Characters that do not belong to the Arabic script only separate the words.
+pub fn phonemize(text: &str) -> Vec<Vec<Phoneme>> {
+    TextSegment::read(text)
+        .iter()
+        .filter_map(|segment| match segment {
+            TextSegment::Word { characters, .. } => Some(phonemize_word(characters)),
+            TextSegment::Other(_) => None,
+        })
+        .collect()
+}
+
+/// Renders the phonemes of a sequence of words as an IPA string, separating the words with spaces.
+///
+/// ```
+/// use arabic_script::{phonemize, to_ipa};
+///
+/// assert_eq!(to_ipa(&phonemize("كِتَابٌ جَمِيلٌ")), "kitaːbun d͡ʒamiːlun");
+/// ```
+pub fn to_ipa(words: &[Vec<Phoneme>]) -> String {
+    words
+        .iter()
+        .map(|word| word.iter().map(Phoneme::to_string).collect::<String>())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the IPA symbol of a consonant letter, or [`None`] if the character is not one.
+fn consonant(character: ArabicCharacter) -> Option<&'static str> {
+    let symbol = match character {
+        c if c.has_hamza() => "ʔ",
+        ArabicLetterBeh => "b",
+        ArabicLetterTeh | ArabicLetterTehMarbuta => "t",
+        ArabicLetterTheh => "θ",
+        ArabicLetterJeem => "d͡ʒ",
+        ArabicLetterHah => "ħ",
+        ArabicLetterKhah => "x",
+        ArabicLetterDal => "d",
+        ArabicLetterThal => "ð",
+        ArabicLetterReh => "r",
+        ArabicLetterZain => "z",
+        ArabicLetterSeen => "s",
+        ArabicLetterSheen => "ʃ",
+        ArabicLetterSad => "sˤ",
+        ArabicLetterDad => "dˤ",
+        ArabicLetterTah => "tˤ",
+        ArabicLetterZah => "ðˤ",
+        ArabicLetterAin => "ʕ",
+        ArabicLetterGhain => "ɣ",
+        ArabicLetterFeh => "f",
+        ArabicLetterQaf => "q",
+        ArabicLetterKaf => "k",
+        ArabicLetterLam => "l",
+        ArabicLetterMeem => "m",
+        ArabicLetterNoon => "n",
+        ArabicLetterHeh => "h",
+        ArabicLetterWaw => "w",
+        ArabicLetterYeh => "j",
+        _ => return None,
+    };
+
+    Some(symbol)
+}
+
+/// Returns the IPA symbol of the vowel of a short vowel or of a tanween.
+fn short_vowel(character: ArabicCharacter) -> &'static str {
+    match character {
+        ArabicDamma | ArabicDammatan => "u",
+        ArabicKasra | ArabicKasratan => "i",
+        _ => "a",
+    }
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::phonemize;
+    use crate::to_ipa;
+    use crate::Phoneme;
+    use crate::PhonemeKind;
+
+    fn ipa(text: &str) -> String {
+        to_ipa(&phonemize(text))
+    }
+
+    #[test]
+    fn maps_emphatic_consonants() {
+        assert_eq!(ipa("صَبَرَ"), "sˤabara");
+        assert_eq!(ipa("ضَرَبَ ظَهْرَ طِفْلٍ"), "dˤaraba ðˤahra tˤiflin");
+    }
+
+    #[test]
+    fn doubles_consonants_under_shadda() {
+        assert_eq!(ipa("مُحَمَّدٌ"), "muħammadun");
+    }
+
+    #[test]
+    fn realizes_long_vowels_and_tanween() {
+        assert_eq!(ipa("كِتَابًا"), "kitaːban");
+        assert_eq!(ipa("نُورٌ"), "nuːrun");
+        assert_eq!(ipa("كَبِيرَةٌ"), "kabiːratun");
+        assert_eq!(ipa("مَدْرَسَة"), "madrasa");
+        assert_eq!(ipa("مُوسَى"), "muːsaː");
+    }
+
+    #[test]
+    fn silences_the_alef_after_the_plural_waw() {
+        assert_eq!(ipa("كَتَبُوا"), "katabuː");
+        assert_eq!(ipa("قَالُوا"), "qaːluː");
+    }
+
+    #[test]
+    fn assimilates_the_definite_article() {
+        assert_eq!(ipa("النُّورُ"), "ʔannuːru");
+        assert_eq!(ipa("الْقَمَرُ"), "ʔalqamaru");
+    }
+
+    #[test]
+    fn structures_the_phonemes() {
+        let phonemes = &phonemize("بَابٌ")[0];
+
+        assert_eq!(phonemes[0], Phoneme::consonant("b"));
+        assert_eq!(phonemes[1].kind, PhonemeKind::Vowel);
+        assert!(phonemes[1].long);
+        assert_eq!(phonemes.len(), 5);
+    }
+}
@@ -0,0 +1,32 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+/// Represents one of the five regions of articulation (_al-makharij al-'amma_).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ArticulationRegion {
+    /// The empty space of the mouth and throat (الجوف), where the long vowels are produced.
+    Cavity,
+
+    /// The throat (الحلق).
+    Throat,
+
+    /// The tongue (اللسان).
+    Tongue,
+
+    /// The lips (الشفتان).
+    Lips,
+
+    /// The nasal cavity (الخيشوم), where the ghunnah is produced.
+    Nasal,
+}
@@ -0,0 +1,146 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::phonology::phoneme_letter;
+use crate::ArabicCharacter;
+use crate::ArabicLetterAin;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterBeh;
+use crate::ArabicLetterDad;
+use crate::ArabicLetterDal;
+use crate::ArabicLetterFeh;
+use crate::ArabicLetterGhain;
+use crate::ArabicLetterHah;
+use crate::ArabicLetterHamza;
+use crate::ArabicLetterHeh;
+use crate::ArabicLetterJeem;
+use crate::ArabicLetterKaf;
+use crate::ArabicLetterKhah;
+use crate::ArabicLetterLam;
+use crate::ArabicLetterMeem;
+use crate::ArabicLetterNoon;
+use crate::ArabicLetterQaf;
+use crate::ArabicLetterReh;
+use crate::ArabicLetterSad;
+use crate::ArabicLetterSeen;
+use crate::ArabicLetterSheen;
+use crate::ArabicLetterTah;
+use crate::ArabicLetterTeh;
+use crate::ArabicLetterThal;
+use crate::ArabicLetterTheh;
+use crate::ArabicLetterWaw;
+use crate::ArabicLetterYeh;
+use crate::ArabicLetterZah;
+use crate::ArabicLetterZain;
+use crate::ArticulationRegion;
+
+/// Represents one of the seventeen points of articulation (_makharij_) of the classical tajweed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Makhraj {
+    /// The empty space of the mouth and throat (الجوف), for the long vowels ا و ي.
+    Cavity,
+
+    /// The deepest part of the throat (أقصى الحلق), for ء and ه.
+    DeepestThroat,
+
+    /// The middle of the throat (وسط الحلق), for ع and ح.
+    MiddleThroat,
+
+    /// The nearest part of the throat (أدنى الحلق), for غ and خ.
+    NearestThroat,
+
+    /// The deepest part of the tongue against the soft palate (أقصى اللسان), for ق.
+    DeepestTongue,
+
+    /// The deep part of the tongue, slightly forward of the ق, for ك.
+    DeepTongue,
+
+    /// The middle of the tongue against the hard palate (وسط اللسان), for ج ش ي.
+    MiddleTongue,
+
+    /// The side of the tongue against the upper molars (حافة اللسان), for ض.
+    TongueSide,
+
+    /// The front of the side of the tongue against the upper gums, for ل.
+    TongueSideFront,
+
+    /// The tip of the tongue against the gums of the upper incisors, for ن.
+    TongueTip,
+
+    /// The tip of the tongue and its back against the gums of the upper incisors, for ر.
+    TongueTipBack,
+
+    /// The tip of the tongue against the roots of the upper incisors (النطعية), for ط د ت.
+    TongueTipRoots,
+
+    /// The tip of the tongue behind the lower incisors (الأسلية), for ص ز س.
+    TongueTipIncisors,
+
+    /// The tip of the tongue against the edges of the upper incisors (اللثوية), for ظ ذ ث.
+    TongueTipEdges,
+
+    /// The inside of the lower lip against the edges of the upper incisors, for ف.
+    LipAndIncisors,
+
+    /// Both lips (الشفتان), for ب م و.
+    BothLips,
+
+    /// The nasal cavity (الخيشوم), for the ghunnah.
+    Nasal,
+}
+
+impl Makhraj {
+    /// Returns the point of articulation of a letter, or [`None`] if the character is not a letter.
+    ///
+    /// The Arabic Letter Waw and the Arabic Letter Yeh are given their consonantal points; as long
+    /// vowels, they are articulated in the [`Makhraj::Cavity`]. The letters carrying a hamza are
+    /// articulated as the hamza, the Arabic Letter Teh Marbuta as a teh, and the Arabic Letter Alef
+    /// Maksura as an alef.
+    pub fn of(character: ArabicCharacter) -> Option<Self> {
+        let makhraj = match phoneme_letter(character)? {
+            ArabicLetterAlef => Makhraj::Cavity,
+            ArabicLetterHamza | ArabicLetterHeh => Makhraj::DeepestThroat,
+            ArabicLetterAin | ArabicLetterHah => Makhraj::MiddleThroat,
+            ArabicLetterGhain | ArabicLetterKhah => Makhraj::NearestThroat,
+            ArabicLetterQaf => Makhraj::DeepestTongue,
+            ArabicLetterKaf => Makhraj::DeepTongue,
+            ArabicLetterJeem | ArabicLetterSheen | ArabicLetterYeh => Makhraj::MiddleTongue,
+            ArabicLetterDad => Makhraj::TongueSide,
+            ArabicLetterLam => Makhraj::TongueSideFront,
+            ArabicLetterNoon => Makhraj::TongueTip,
+            ArabicLetterReh => Makhraj::TongueTipBack,
+            ArabicLetterTah | ArabicLetterDal | ArabicLetterTeh => Makhraj::TongueTipRoots,
+            ArabicLetterSad | ArabicLetterZain | ArabicLetterSeen => Makhraj::TongueTipIncisors,
+            ArabicLetterZah | ArabicLetterThal | ArabicLetterTheh => Makhraj::TongueTipEdges,
+            ArabicLetterFeh => Makhraj::LipAndIncisors,
+            ArabicLetterBeh | ArabicLetterMeem | ArabicLetterWaw => Makhraj::BothLips,
+            _ => return None,
+        };
+
+        Some(makhraj)
+    }
+
+    /// Returns the region the point of articulation belongs to.
+    pub fn region(&self) -> ArticulationRegion {
+        match self {
+            Makhraj::Cavity => ArticulationRegion::Cavity,
+            Makhraj::DeepestThroat | Makhraj::MiddleThroat | Makhraj::NearestThroat => {
+                ArticulationRegion::Throat
+            }
+            Makhraj::LipAndIncisors | Makhraj::BothLips => ArticulationRegion::Lips,
+            Makhraj::Nasal => ArticulationRegion::Nasal,
+            _ => ArticulationRegion::Tongue,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+//! The **Phonology** module describes how each letter is articulated, following the classical
+//! science of tajweed.
+
+pub use articulation_region::ArticulationRegion;
+pub use makhraj::Makhraj;
+pub use sifat::Sifat;
+pub use strength::Strength;
+
+pub(crate) use phoneme_letter::phoneme_letter;
+
+mod articulation_region;
+mod makhraj;
+mod phoneme_letter;
+mod sifat;
+mod strength;
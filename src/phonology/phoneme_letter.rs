@@ -0,0 +1,31 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicCharacter;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterAlefMaksura;
+use crate::ArabicLetterHamza;
+use crate::ArabicLetterTeh;
+use crate::ArabicLetterTehMarbuta;
+
+/// Returns the letter whose sound a letter has, or [`None`] if the character is not a letter.
+pub(crate) fn phoneme_letter(character: ArabicCharacter) -> Option<ArabicCharacter> {
+    match character {
+        c if c.has_hamza() => Some(ArabicLetterHamza),
+        ArabicLetterTehMarbuta => Some(ArabicLetterTeh),
+        ArabicLetterAlefMaksura => Some(ArabicLetterAlef),
+        c if c.is_letter() => Some(c),
+        _ => None,
+    }
+}
@@ -0,0 +1,180 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::phonology::phoneme_letter;
+use crate::ArabicCharacter;
+use crate::ArabicLetterAin;
+use crate::ArabicLetterBeh;
+use crate::ArabicLetterDad;
+use crate::ArabicLetterDal;
+use crate::ArabicLetterFeh;
+use crate::ArabicLetterGhain;
+use crate::ArabicLetterHah;
+use crate::ArabicLetterHamza;
+use crate::ArabicLetterHeh;
+use crate::ArabicLetterJeem;
+use crate::ArabicLetterKaf;
+use crate::ArabicLetterKhah;
+use crate::ArabicLetterLam;
+use crate::ArabicLetterMeem;
+use crate::ArabicLetterNoon;
+use crate::ArabicLetterQaf;
+use crate::ArabicLetterReh;
+use crate::ArabicLetterSad;
+use crate::ArabicLetterSeen;
+use crate::ArabicLetterSheen;
+use crate::ArabicLetterTah;
+use crate::ArabicLetterTeh;
+use crate::ArabicLetterTheh;
+use crate::ArabicLetterWaw;
+use crate::ArabicLetterYeh;
+use crate::ArabicLetterZah;
+use crate::ArabicLetterZain;
+use crate::Strength;
+
+/// Represents the characteristics (_sifat_) of a letter, as described by the classical tajweed.
+///
+/// The first five characteristics come in pairs of opposites, of which every letter has one. The
+/// others are only found in some letters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Sifat {
+    /// Whether the letter is voiced (الجهر), or whispered (الهمس) like the letters of فحثه شخص سكت.
+    pub voiced: bool,
+
+    /// How freely the sound of the letter flows.
+    pub strength: Strength,
+
+    /// Whether the back of the tongue rises when the letter is pronounced (الاستعلاء), making it
+    /// emphatic (مفخم), as for the letters of خص ضغط قظ.
+    pub emphatic: bool,
+
+    /// Whether the tongue adheres to the palate (الإطباق), as for ص ض ط ظ.
+    pub adhesive: bool,
+
+    /// Whether the letter is pronounced with ease from the tip of the tongue or the lips
+    /// (الإذلاق), as for the letters of فر من لب.
+    pub fluent: bool,
+
+    /// Whether the letter whistles (الصفير), as for ص ز س.
+    pub whistling: bool,
+
+    /// Whether the letter echoes when it carries a sukun (القلقلة), as for the letters of قطب جد.
+    pub qalqala: bool,
+
+    /// Whether the letter is soft (اللين), as the Arabic Letter Waw and the Arabic Letter Yeh
+    /// after a fatha.
+    pub soft: bool,
+
+    /// Whether the sound deviates from its point of articulation (الانحراف), as for ل and ر.
+    pub deviating: bool,
+
+    /// Whether the tongue trills (التكرير), as for ر.
+    pub trilled: bool,
+
+    /// Whether the air spreads in the mouth (التفشي), as for ش.
+    pub spreading: bool,
+
+    /// Whether the sound extends along the side of the tongue (الاستطالة), as for ض.
+    pub elongated: bool,
+
+    /// Whether the letter carries a nasal sound (الغنة), as for ن and م.
+    pub nasal: bool,
+}
+
+impl Sifat {
+    /// Returns the characteristics of a letter, or [`None`] if the character is not a letter.
+    ///
+    /// The letters carrying a hamza have the characteristics of the hamza, the Arabic Letter Teh
+    /// Marbuta those of a teh, and the Arabic Letter Alef Maksura those of an alef.
+    pub fn of(character: ArabicCharacter) -> Option<Self> {
+        let letter = phoneme_letter(character)?;
+        let any = |letters: &[ArabicCharacter]| letters.contains(&letter);
+
+        let strength = if any(&[
+            ArabicLetterHamza,
+            ArabicLetterJeem,
+            ArabicLetterDal,
+            ArabicLetterQaf,
+            ArabicLetterTah,
+            ArabicLetterBeh,
+            ArabicLetterKaf,
+            ArabicLetterTeh,
+        ]) {
+            Strength::Plosive
+        } else if any(&[
+            ArabicLetterLam,
+            ArabicLetterNoon,
+            ArabicLetterAin,
+            ArabicLetterMeem,
+            ArabicLetterReh,
+        ]) {
+            Strength::Intermediate
+        } else {
+            Strength::Fricative
+        };
+
+        Some(Sifat {
+            voiced: !any(&[
+                ArabicLetterFeh,
+                ArabicLetterHah,
+                ArabicLetterTheh,
+                ArabicLetterHeh,
+                ArabicLetterSheen,
+                ArabicLetterKhah,
+                ArabicLetterSad,
+                ArabicLetterSeen,
+                ArabicLetterKaf,
+                ArabicLetterTeh,
+            ]),
+            strength,
+            emphatic: any(&[
+                ArabicLetterKhah,
+                ArabicLetterSad,
+                ArabicLetterDad,
+                ArabicLetterGhain,
+                ArabicLetterTah,
+                ArabicLetterQaf,
+                ArabicLetterZah,
+            ]),
+            adhesive: any(&[
+                ArabicLetterSad,
+                ArabicLetterDad,
+                ArabicLetterTah,
+                ArabicLetterZah,
+            ]),
+            fluent: any(&[
+                ArabicLetterFeh,
+                ArabicLetterReh,
+                ArabicLetterMeem,
+                ArabicLetterNoon,
+                ArabicLetterLam,
+                ArabicLetterBeh,
+            ]),
+            whistling: any(&[ArabicLetterSad, ArabicLetterZain, ArabicLetterSeen]),
+            qalqala: any(&[
+                ArabicLetterQaf,
+                ArabicLetterTah,
+                ArabicLetterBeh,
+                ArabicLetterJeem,
+                ArabicLetterDal,
+            ]),
+            soft: any(&[ArabicLetterWaw, ArabicLetterYeh]),
+            deviating: any(&[ArabicLetterLam, ArabicLetterReh]),
+            trilled: letter == ArabicLetterReh,
+            spreading: letter == ArabicLetterSheen,
+            elongated: letter == ArabicLetterDad,
+            nasal: any(&[ArabicLetterNoon, ArabicLetterMeem]),
+        })
+    }
+}
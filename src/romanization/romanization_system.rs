@@ -0,0 +1,186 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicCharacter;
+use crate::ArabicDamma;
+use crate::ArabicDammatan;
+use crate::ArabicFatha;
+use crate::ArabicFathatan;
+use crate::ArabicKasra;
+use crate::ArabicKasratan;
+use crate::ArabicLetterAin;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterAlefMaksura;
+use crate::ArabicLetterBeh;
+use crate::ArabicLetterDad;
+use crate::ArabicLetterDal;
+use crate::ArabicLetterFeh;
+use crate::ArabicLetterGhain;
+use crate::ArabicLetterHah;
+use crate::ArabicLetterHeh;
+use crate::ArabicLetterJeem;
+use crate::ArabicLetterKaf;
+use crate::ArabicLetterKhah;
+use crate::ArabicLetterLam;
+use crate::ArabicLetterMeem;
+use crate::ArabicLetterNoon;
+use crate::ArabicLetterQaf;
+use crate::ArabicLetterReh;
+use crate::ArabicLetterSad;
+use crate::ArabicLetterSeen;
+use crate::ArabicLetterSheen;
+use crate::ArabicLetterTah;
+use crate::ArabicLetterTeh;
+use crate::ArabicLetterThal;
+use crate::ArabicLetterTheh;
+use crate::ArabicLetterWaw;
+use crate::ArabicLetterYeh;
+use crate::ArabicLetterZah;
+use crate::ArabicLetterZain;
+
+/// Represents a scholarly romanization standard for the Arabic script.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RomanizationSystem {
+    /// The ISO 233 standard, a strict transliteration that preserves every letter.
+    Iso233,
+
+    /// The DIN 31635 standard, common in German-language scholarship.
+    Din31635,
+
+    /// The ALA-LC standard of the American Library Association and the Library of Congress.
+    AlaLc,
+
+    /// The system of the Encyclopaedia of Islam, without its underlined digraphs.
+    EncyclopaediaOfIslam,
+}
+
+impl RomanizationSystem {
+    /// Returns the romanization of a consonant, or [`None`] if the character is not a consonant.
+    ///
+    /// The hamza, in all of its seats, is a consonant.
+    pub fn consonant(&self, character: ArabicCharacter) -> Option<&'static str> {
+        use RomanizationSystem::*;
+
+        let consonant = match (self, character) {
+            (_, c) if c.has_hamza() => match self {
+                AlaLc => "ʼ",
+                _ => "ʾ",
+            },
+            (_, ArabicLetterBeh) => "b",
+            (_, ArabicLetterTeh) => "t",
+            (Iso233 | Din31635, ArabicLetterTheh) => "ṯ",
+            (_, ArabicLetterTheh) => "th",
+            (Iso233 | Din31635, ArabicLetterJeem) => "ǧ",
+            (AlaLc, ArabicLetterJeem) => "j",
+            (EncyclopaediaOfIslam, ArabicLetterJeem) => "dj",
+            (_, ArabicLetterHah) => "ḥ",
+            (Iso233, ArabicLetterKhah) => "ẖ",
+            (Din31635, ArabicLetterKhah) => "ḫ",
+            (_, ArabicLetterKhah) => "kh",
+            (_, ArabicLetterDal) => "d",
+            (Iso233 | Din31635, ArabicLetterThal) => "ḏ",
+            (_, ArabicLetterThal) => "dh",
+            (_, ArabicLetterReh) => "r",
+            (_, ArabicLetterZain) => "z",
+            (_, ArabicLetterSeen) => "s",
+            (Iso233 | Din31635, ArabicLetterSheen) => "š",
+            (_, ArabicLetterSheen) => "sh",
+            (_, ArabicLetterSad) => "ṣ",
+            (_, ArabicLetterDad) => "ḍ",
+            (_, ArabicLetterTah) => "ṭ",
+            (_, ArabicLetterZah) => "ẓ",
+            (AlaLc, ArabicLetterAin) => "ʻ",
+            (_, ArabicLetterAin) => "ʿ",
+            (Iso233 | Din31635, ArabicLetterGhain) => "ġ",
+            (_, ArabicLetterGhain) => "gh",
+            (_, ArabicLetterFeh) => "f",
+            (EncyclopaediaOfIslam, ArabicLetterQaf) => "ḳ",
+            (_, ArabicLetterQaf) => "q",
+            (_, ArabicLetterKaf) => "k",
+            (_, ArabicLetterLam) => "l",
+            (_, ArabicLetterMeem) => "m",
+            (_, ArabicLetterNoon) => "n",
+            (_, ArabicLetterHeh) => "h",
+            (_, ArabicLetterWaw) => "w",
+            (_, ArabicLetterYeh) => "y",
+            _ => return None,
+        };
+
+        Some(consonant)
+    }
+
+    /// Returns the romanization of a short vowel or of a tanween's vowel, or [`None`] if the
+    /// character is neither.
+    pub fn short_vowel(&self, character: ArabicCharacter) -> Option<&'static str> {
+        match character {
+            ArabicFatha | ArabicFathatan => Some("a"),
+            ArabicDamma | ArabicDammatan => Some("u"),
+            ArabicKasra | ArabicKasratan => Some("i"),
+            _ => None,
+        }
+    }
+
+    /// Returns the romanization of the long vowel spelled with the given letter, or [`None`] if
+    /// the letter does not spell a long vowel.
+    pub fn long_vowel(&self, character: ArabicCharacter) -> Option<&'static str> {
+        use RomanizationSystem::*;
+
+        match (self, character) {
+            (_, ArabicLetterAlef) => Some("ā"),
+            (Iso233, ArabicLetterAlefMaksura) => Some("ỳ"),
+            (AlaLc, ArabicLetterAlefMaksura) => Some("á"),
+            (_, ArabicLetterAlefMaksura) => Some("ā"),
+            (_, ArabicLetterWaw) => Some("ū"),
+            (_, ArabicLetterYeh) => Some("ī"),
+            _ => None,
+        }
+    }
+
+    /// Returns the romanization of the Arabic Letter Teh Marbuta in the pausal form, as at the end
+    /// of a phrase.
+    pub fn pausal_teh_marbuta(&self) -> &'static str {
+        match self {
+            RomanizationSystem::Iso233 => "ẗ",
+            RomanizationSystem::AlaLc => "h",
+            _ => "",
+        }
+    }
+
+    /// Returns the romanization of the Arabic Letter Teh Marbuta when it is pronounced, as in the
+    /// construct state.
+    pub fn pronounced_teh_marbuta(&self) -> &'static str {
+        match self {
+            RomanizationSystem::Iso233 => "ẗ",
+            _ => "t",
+        }
+    }
+
+    /// Returns `true` if the system writes the hamza at the beginning of a word.
+    pub fn writes_initial_hamza(&self) -> bool {
+        matches!(self, RomanizationSystem::Iso233)
+    }
+
+    /// Returns the romanization of the definite article before the given letter.
+    ///
+    /// The article is written `al-`, except before a sun letter in DIN 31635, which assimilates its
+    /// lam to that letter, as in `aš-šams`.
+    pub fn definite_article(&self, next: ArabicCharacter) -> String {
+        match self.consonant(next) {
+            Some(consonant) if *self == RomanizationSystem::Din31635 && next.is_sun_letter() => {
+                format!("a{}-", consonant)
+            }
+            _ => "al-".to_string(),
+        }
+    }
+}
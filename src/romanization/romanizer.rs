@@ -0,0 +1,243 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::core::LetterCluster;
+use crate::core::TextSegment;
+use crate::ArabicFathatan;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterAlefMaksura;
+use crate::ArabicLetterAlefWithHamzaBelow;
+use crate::ArabicLetterAlefWithMaddaAbove;
+use crate::ArabicLetterTehMarbuta;
+use crate::ArabicLetterWaw;
+use crate::RomanizationSystem;
+
+/// Romanizes vocalized Arabic text according to the given system.
+///
+/// A shadda doubles the consonant carrying it, and the long vowels spelled with an alef, a waw, or
+/// a yeh are rendered as such. The definite article is written `al-`, even before the sun letters,
+/// whose shadda is then not doubled, except in DIN 31635, where it assimilates to them:
+///
+/// ```
+/// use arabic_script::{romanize, RomanizationSystem};
+///
+/// assert_eq!(romanize("الشَّمْسُ", RomanizationSystem::Din31635), "aš-šamsu");
+/// assert_eq!(romanize("الشَّمْسُ", RomanizationSystem::AlaLc), "al-shamsu");
+/// ```
+///
+/// The Arabic Letter Teh Marbuta is pronounced when it carries a vowel, or when it ends the first
+/// noun of a construct state (_idafa_), which is recognized by the definite article of the noun
+/// following it. Otherwise, it takes its pausal form:
+///
+/// ```
+/// use arabic_script::{romanize, RomanizationSystem};
+///
+/// let system = RomanizationSystem::AlaLc;
+///
+/// assert_eq!(romanize("مَدْرَسَة", system), "madrasah");
+/// assert_eq!(romanize("مَدْرَسَة الْبَنَات", system), "madrasat al-banāt");
+/// ```
+///
+/// Characters that do not belong to the Arabic script are left untouched.
+pub fn romanize(text: &str, system: RomanizationSystem) -> String {
+    let segments = TextSegment::read(text);
+    let mut romanized = String::with_capacity(text.len());
+
+    for (index, segment) in segments.iter().enumerate() {
+        match segment {
+            TextSegment::Other(other) => romanized.push_str(other),
+            TextSegment::Word { characters, .. } => {
+                let construct = match (segments.get(index + 1), segments.get(index + 2)) {
+                    (Some(TextSegment::Other(gap)), Some(TextSegment::Word { characters, .. })) => {
+                        gap.trim().is_empty()
+                            && LetterCluster::has_definite_article(&LetterCluster::read(characters))
+                    }
+                    _ => false,
+                };
+
+                romanize_word(
+                    &LetterCluster::read(characters),
+                    system,
+                    construct,
+                    &mut romanized,
+                );
+            }
+        }
+    }
+
+    romanized
+}
+
+/// Romanizes the letters of a single word.
+fn romanize_word(
+    clusters: &[LetterCluster],
+    system: RomanizationSystem,
+    construct: bool,
+    romanized: &mut String,
+) {
+    let article = LetterCluster::has_definite_article(clusters);
+    let mut index = 0;
+
+    if article {
+        romanized.push_str(&system.definite_article(clusters[2].letter));
+        index = 2;
+    }
+
+    while index < clusters.len() {
+        let cluster = clusters[index];
+        let previous = index.checked_sub(1).map(|i| clusters[i]);
+        let next = clusters.get(index + 1);
+        let initial = index == 0;
+        index += 1;
+
+        match cluster.letter {
+            ArabicLetterAlef
+                if next.is_none() && previous.is_some_and(|p| p.letter == ArabicLetterWaw) =>
+            {
+                continue;
+            }
+            ArabicLetterAlef | ArabicLetterAlefMaksura if !initial => {
+                romanized.push_str(system.long_vowel(cluster.letter).unwrap_or_default());
+                continue;
+            }
+            ArabicLetterAlef | ArabicLetterAlefMaksura => {}
+            ArabicLetterTehMarbuta if cluster.is_vowelless() && !(next.is_none() && construct) => {
+                romanized.push_str(system.pausal_teh_marbuta());
+                continue;
+            }
+            ArabicLetterTehMarbuta => romanized.push_str(system.pronounced_teh_marbuta()),
+            ArabicLetterAlefWithMaddaAbove => {
+                if !initial || system.writes_initial_hamza() {
+                    romanized.push_str(system.consonant(cluster.letter).unwrap_or_default());
+                }
+                romanized.push_str(system.long_vowel(ArabicLetterAlef).unwrap_or_default());
+                continue;
+            }
+            letter if letter.has_hamza() && initial && !system.writes_initial_hamza() => {}
+            letter => {
+                let consonant = system.consonant(letter).unwrap_or_default();
+                let assimilated = article && index == 3 && letter.is_sun_letter();
+
+                romanized.push_str(consonant);
+                if cluster.shadda && !assimilated {
+                    romanized.push_str(consonant);
+                }
+            }
+        }
+
+        match cluster.vowel {
+            Some(vowel) if vowel.is_short_vowel() => {
+                let long = next.filter(|n| !n.shadda && n.is_vowelless() && n.lengthens(vowel));
+
+                match long {
+                    Some(long) => {
+                        romanized.push_str(system.long_vowel(long.letter).unwrap_or_default());
+                        index += 1;
+                    }
+                    None => romanized.push_str(system.short_vowel(vowel).unwrap_or_default()),
+                }
+            }
+            Some(vowel) if vowel.is_tanween() => {
+                romanized.push_str(system.short_vowel(vowel).unwrap_or_default());
+                romanized.push('n');
+
+                let seated = next.is_some_and(|n| {
+                    matches!(n.letter, ArabicLetterAlef | ArabicLetterAlefMaksura)
+                });
+                if vowel == ArabicFathatan && seated {
+                    index += 1;
+                }
+            }
+            None if initial && cluster.letter.has_hamza() && !system.writes_initial_hamza() => {
+                match cluster.letter {
+                    ArabicLetterAlefWithHamzaBelow => romanized.push('i'),
+                    _ => romanized.push('a'),
+                }
+            }
+            None if next.is_some_and(|n| n.letter == ArabicLetterAlef && n.vowel.is_none()) => {
+                romanized.push_str(system.long_vowel(ArabicLetterAlef).unwrap_or_default());
+                index += 1;
+            }
+            _ => {}
+        }
+    }
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::romanize;
+    use crate::RomanizationSystem::*;
+
+    #[test]
+    fn romanizes_consonants_per_system() {
+        let text = "جَمِيلٌ خَاصٌّ";
+
+        assert_eq!(romanize(text, Iso233), "ǧamīlun ẖāṣṣun");
+        assert_eq!(romanize(text, Din31635), "ǧamīlun ḫāṣṣun");
+        assert_eq!(romanize(text, AlaLc), "jamīlun khāṣṣun");
+        assert_eq!(romanize(text, EncyclopaediaOfIslam), "djamīlun khāṣṣun");
+    }
+
+    #[test]
+    fn doubles_consonants_under_shadda() {
+        assert_eq!(romanize("مُحَمَّد", Din31635), "muḥammad");
+        assert_eq!(romanize("الْقُرْآن", EncyclopaediaOfIslam), "al-ḳurʾān");
+    }
+
+    #[test]
+    fn romanizes_the_definite_article() {
+        let sun = "الشَّمْسُ";
+        let moon = "الْقَمَرُ";
+
+        assert_eq!(romanize(sun, Iso233), "al-šamsu");
+        assert_eq!(romanize(moon, Iso233), "al-qamaru");
+        assert_eq!(romanize(sun, Din31635), "aš-šamsu");
+        assert_eq!(romanize(moon, Din31635), "al-qamaru");
+        assert_eq!(romanize(sun, AlaLc), "al-shamsu");
+        assert_eq!(romanize(moon, AlaLc), "al-qamaru");
+        assert_eq!(romanize(sun, EncyclopaediaOfIslam), "al-shamsu");
+        assert_eq!(romanize(moon, EncyclopaediaOfIslam), "al-ḳamaru");
+        assert_eq!(romanize("النُّور", Din31635), "an-nūr");
+    }
+
+    #[test]
+    fn romanizes_long_vowels() {
+        assert_eq!(romanize("كِتَابًا", Din31635), "kitāban");
+        assert_eq!(romanize("سُورَة", Din31635), "sūra");
+        assert_eq!(romanize("كَتَبُوا", Din31635), "katabū");
+        assert_eq!(romanize("مُوسَى", Iso233), "mūsỳ");
+        assert_eq!(romanize("مُوسَى", AlaLc), "mūsá");
+    }
+
+    #[test]
+    fn romanizes_the_hamza() {
+        assert_eq!(romanize("أَحْمَد", Iso233), "ʾaḥmad");
+        assert_eq!(romanize("أَحْمَد", Din31635), "aḥmad");
+        assert_eq!(romanize("إِسْلَام", AlaLc), "islām");
+        assert_eq!(romanize("سُؤَال", AlaLc), "suʼāl");
+    }
+
+    #[test]
+    fn romanizes_the_teh_marbuta() {
+        assert_eq!(romanize("مَدْرَسَة", Iso233), "madrasaẗ");
+        assert_eq!(romanize("مَدْرَسَة", Din31635), "madrasa");
+        assert_eq!(romanize("مَدْرَسَةٌ", Din31635), "madrasatun");
+        assert_eq!(romanize("دَار الْحِكْمَة", Din31635), "dār al-ḥikma");
+        assert_eq!(romanize("بَيْت الْحِكْمَة", AlaLc), "bayt al-ḥikmah");
+    }
+}
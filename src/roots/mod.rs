@@ -0,0 +1,27 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+//! The **Roots** module extracts the roots of Arabic words, and matches and forms words by their
+//! patterns.
+
+pub use pattern::Pattern;
+pub use root_candidate::RootCandidate;
+pub use root_extractor::extract_roots;
+
+pub(crate) use root_extractor::stem_cost;
+
+mod pattern;
+mod patterns;
+mod root_candidate;
+mod root_extractor;
@@ -0,0 +1,643 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use std::fmt::{Display, Formatter};
+
+use crate::seat_hamza;
+use crate::ArabicCharacter;
+use crate::ArabicTatweel;
+use crate::UnicodeCharacter;
+
+/// The shadda, which always comes first among the marks of a letter.
+const SHADDA: char = '\u{0651}';
+
+/// The sukun.
+const SUKUN: char = '\u{0652}';
+
+/// The fatha.
+const FATHA: char = '\u{064E}';
+
+/// The damma.
+const DAMMA: char = '\u{064F}';
+
+/// The kasra.
+const KASRA: char = '\u{0650}';
+
+/// Represents a morphological pattern, or wazn, written with the letters ف, ع, and ل standing for
+/// the letters of the root.
+///
+/// A second ل stands for the fourth letter of a quadriliteral root, as in فَعْلَلَة. A pattern may
+/// be vocalized, in which case the words it matches and generates are vocalized too:
+///
+/// ```
+/// use arabic_script::{ArabicCharacter, Pattern};
+///
+/// let read = |word: &str| -> Vec<ArabicCharacter> {
+///     word.chars().map(|c| ArabicCharacter::try_from(c).unwrap()).collect()
+/// };
+///
+/// assert_eq!(Pattern::MAFUUL.match_word(&read("مَكْتُوب")), Some(read("كتب")));
+/// assert_eq!(Pattern::FAAIL.apply(&read("قول")), Some("قَائِل".to_string()));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Pattern {
+    template: &'static str,
+}
+
+/// Represents a letter of a pattern, along with its marks.
+#[derive(Clone, Debug, PartialEq)]
+struct Unit {
+    /// The letter, which is a letter of the root when the unit is a slot.
+    letter: char,
+
+    /// The index of the letter of the root filling the unit, if the unit is a slot.
+    slot: Option<usize>,
+
+    /// The marks of the letter, with the shadda first.
+    marks: Vec<char>,
+}
+
+impl Unit {
+    /// Returns a new literal unit without marks.
+    fn literal(letter: char) -> Self {
+        Self {
+            letter,
+            slot: None,
+            marks: Vec::new(),
+        }
+    }
+
+    /// Returns the short vowel of the unit, if any.
+    fn vowel(&self) -> Option<char> {
+        self.marks
+            .iter()
+            .copied()
+            .find(|mark| matches!(*mark, FATHA | DAMMA | KASRA))
+    }
+
+    /// Returns `true` if the unit carries the given mark.
+    fn has(&self, mark: char) -> bool {
+        self.marks.contains(&mark)
+    }
+
+    /// Returns `true` if the unit is the given letter, added by the pattern and carrying no mark.
+    fn is_literal(&self, letter: char) -> bool {
+        self.slot.is_none() && self.letter == letter && self.marks.is_empty()
+    }
+}
+
+impl Pattern {
+    /// The pattern of the bare verb in the past tense, فَعَلَ.
+    pub const FAALA: Self = Self::new("فَعَلَ");
+
+    /// The pattern of the active participle of the bare verb, فَاعِل.
+    pub const FAAIL: Self = Self::new("فَاعِل");
+
+    /// The pattern of the passive participle of the bare verb, مَفْعُول.
+    pub const MAFUUL: Self = Self::new("مَفْعُول");
+
+    /// The pattern of the verbal noun of the second form, تَفْعِيل.
+    pub const TAFIIL: Self = Self::new("تَفْعِيل");
+
+    /// The pattern of the verb of the eighth form in the past tense, افْتَعَلَ.
+    pub const IFTAALA: Self = Self::new("افْتَعَلَ");
+
+    /// The pattern of the verbal noun of the tenth form, اسْتِفْعَال.
+    pub const ISTIFAAL: Self = Self::new("اسْتِفْعَال");
+
+    /// Returns a new pattern from its template, written with the letters ف, ع, and ل.
+    pub const fn new(template: &'static str) -> Self {
+        Self { template }
+    }
+
+    /// Returns the template of the pattern.
+    pub fn template(&self) -> &'static str {
+        self.template
+    }
+
+    /// Returns the number of letters of the roots the pattern applies to, from the last of the
+    /// letters ف, ع, and ل in the template.
+    pub fn root_length(&self) -> usize {
+        self.units()
+            .iter()
+            .filter_map(|unit| unit.slot)
+            .max()
+            .map_or(0, |slot| slot + 1)
+    }
+
+    /// Returns `true` if every letter of the root has a slot in the template, unlike in a template
+    /// such as فَل, that lacks the ع.
+    fn is_complete(&self) -> bool {
+        let units = self.units();
+        (0..self.root_length()).all(|slot| units.iter().any(|unit| unit.slot == Some(slot)))
+    }
+
+    /// Returns the root filling the pattern in a word, or [`None`] if the word does not fit the
+    /// pattern.
+    ///
+    /// This is the first of the roots returned by [`match_roots()`](Self::match_roots).
+    pub fn match_word(&self, word: &[ArabicCharacter]) -> Option<Vec<ArabicCharacter>> {
+        self.match_roots(word).into_iter().next()
+    }
+
+    /// Returns every root filling the pattern in a word, those forming the word with exactly its
+    /// marks first, then the weak ones.
+    ///
+    /// A root fills the pattern if [`apply()`](Self::apply) forms the word from it: the letters
+    /// must be the same, and the marks of the word, if any, must be among those of the word formed,
+    /// except for the case ending of the last letter. The changes of the eighth form and of the
+    /// weak letters are thus undone, and an alef, an alef maksura, or a hamza may stand for a waw,
+    /// a yeh, or a hamza of the root, as in قَائِل, read as قول, then as قيل and قءل.
+    pub fn match_roots(&self, word: &[ArabicCharacter]) -> Vec<Vec<ArabicCharacter>> {
+        let word = read_units(word.iter().map(|c| c.scalar_value()));
+        let mut roots = Vec::new();
+        if self.is_complete() && word.len().abs_diff(self.units().len()) <= 2 {
+            self.find_roots(&word, 0, &mut Vec::new(), &mut roots);
+        }
+
+        roots.sort_by_key(|(_, exact)| !exact);
+        roots.into_iter().map(|(root, _)| root).collect()
+    }
+
+    /// Completes a root with the letters of the word from the given index on, or with a dropped
+    /// weak letter, and adds each root that forms the word.
+    fn find_roots(
+        &self,
+        word: &[Unit],
+        from: usize,
+        root: &mut Vec<char>,
+        roots: &mut Vec<(Vec<ArabicCharacter>, bool)>,
+    ) {
+        if root.len() == self.root_length() {
+            let candidate: Vec<ArabicCharacter> = root
+                .iter()
+                .filter_map(|letter| ArabicCharacter::try_from(*letter).ok())
+                .collect();
+            if roots.iter().any(|(root, _)| *root == candidate) {
+                return;
+            }
+            if let Some(formed) = self.apply(&candidate) {
+                let formed = read_units(formed.chars());
+                if fits(word, &formed) {
+                    roots.push((candidate, formed == word));
+                }
+            }
+            return;
+        }
+
+        for index in from..word.len() {
+            for letter in root_letters(word[index].letter) {
+                root.push(letter);
+                self.find_roots(word, index + 1, root, roots);
+                root.pop();
+            }
+        }
+        for letter in ['و', 'ي'] {
+            root.push(letter);
+            self.find_roots(word, from, root, roots);
+            root.pop();
+        }
+    }
+
+    /// Applies a root to the pattern, returning the word it forms, or [`None`] if the root does
+    /// not have as many letters as the pattern has slots, or the template lacks a slot.
+    ///
+    /// The dental of the eighth form assimilates to an emphatic or a voiced first letter of the
+    /// root, as in اصْطَبَرَ and ازْدَهَرَ, and merges with a weak one, as in اتَّصَلَ. The weak
+    /// letters of a triliteral root change as they do in the language, as in قَالَ, مَقُول, رَمَى,
+    /// and يَعِدُ, and the hamza is seated by its vowels, as in سَائِل.
+    pub fn apply(&self, root: &[ArabicCharacter]) -> Option<String> {
+        if root.len() != self.root_length() || !self.is_complete() {
+            return None;
+        }
+
+        let mut units = self.units();
+        for unit in &mut units {
+            if let Some(slot) = unit.slot {
+                unit.letter = hamza(root[slot].scalar_value());
+            }
+        }
+
+        assimilate_eighth_form(&mut units);
+        if root.len() == 3 {
+            change_hollow(&mut units, self.template.starts_with('ا'));
+            change_defective(&mut units);
+            change_assimilated(&mut units);
+        }
+
+        let word: Vec<ArabicCharacter> = units
+            .iter()
+            .flat_map(|unit| std::iter::once(unit.letter).chain(unit.marks.iter().copied()))
+            .filter_map(|c| ArabicCharacter::try_from(c).ok())
+            .collect();
+
+        Some(seat_hamza(&word).iter().map(|c| c.scalar_value()).collect())
+    }
+
+    /// Returns the units of the template.
+    fn units(&self) -> Vec<Unit> {
+        let mut units = read_units(self.template.chars());
+        let mut slots = 0;
+
+        for unit in &mut units {
+            let slot = match unit.letter {
+                'ف' => 0,
+                'ع' => 1,
+                'ل' if slots > 2 => 3,
+                'ل' => 2,
+                _ => continue,
+            };
+            unit.slot = Some(slot);
+            slots = slots.max(slot + 1);
+        }
+
+        units
+    }
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.template)
+    }
+}
+
+/// Reads characters into units, attaching the marks to the letter before them and skipping the
+/// tatweel.
+fn read_units(characters: impl Iterator<Item = char>) -> Vec<Unit> {
+    let mut units: Vec<Unit> = Vec::new();
+
+    for c in characters.filter(|c| *c != ArabicTatweel.scalar_value()) {
+        let mark = ArabicCharacter::try_from(c).is_ok_and(|c| c.is_diacritic());
+        match units.last_mut() {
+            Some(unit) if mark && c == SHADDA => unit.marks.insert(0, c),
+            Some(unit) if mark => unit.marks.push(c),
+            None if mark => {}
+            _ => units.push(Unit::literal(c)),
+        }
+    }
+
+    units
+}
+
+/// Returns `true` if a word fits a word formed by a pattern.
+fn fits(word: &[Unit], formed: &[Unit]) -> bool {
+    if formed.len() != word.len() {
+        return false;
+    }
+
+    formed
+        .iter()
+        .zip(word)
+        .enumerate()
+        .all(|(index, (expected, found))| {
+            let last = index == word.len() - 1;
+            let mut marks = found.marks.iter().filter(|mark| !last || **mark == SHADDA);
+            let letters = if index == 0 {
+                bare_alef(expected.letter) == bare_alef(found.letter)
+            } else {
+                expected.letter == found.letter
+            };
+
+            letters && marks.all(|mark| expected.has(*mark))
+        })
+}
+
+/// Returns the letters of the root a letter of a word may stand for, the weak ones first.
+fn root_letters(letter: char) -> Vec<char> {
+    match letter {
+        'ا' | 'أ' | 'إ' | 'ؤ' | 'ئ' | 'ء' => vec!['و', 'ي', 'ء'],
+        'ى' | 'ي' => vec!['ي', 'و'],
+        'و' => vec!['و', 'ي'],
+        'ة' => Vec::new(),
+        _ => vec![letter],
+    }
+}
+
+/// Returns the hamza for any of its seated forms, or the letter itself otherwise.
+fn hamza(letter: char) -> char {
+    match letter {
+        'أ' | 'إ' | 'ؤ' | 'ئ' => 'ء',
+        _ => letter,
+    }
+}
+
+/// Returns the bare alef for an alef carrying a hamza, or the letter itself otherwise.
+fn bare_alef(letter: char) -> char {
+    match letter {
+        'أ' | 'إ' => 'ا',
+        _ => letter,
+    }
+}
+
+/// Returns `true` if the letter is a waw or a yeh.
+fn is_weak(letter: char) -> bool {
+    matches!(letter, 'و' | 'ي')
+}
+
+/// Returns the index of the unit filled by a letter of the root.
+fn find_slot(units: &[Unit], slot: usize) -> Option<usize> {
+    units.iter().position(|unit| unit.slot == Some(slot))
+}
+
+/// Assimilates the teh of the eighth form to the first letter of the root.
+fn assimilate_eighth_form(units: &mut Vec<Unit>) {
+    let Some(index) = find_slot(units, 0) else {
+        return;
+    };
+    if !units[index].has(SUKUN) || units.get(index + 1).is_none_or(|next| next.slot.is_some()) {
+        return;
+    }
+    if units[index + 1].letter != 'ت' {
+        return;
+    }
+
+    let merged = match units[index].letter {
+        'ص' | 'ض' | 'ظ' => {
+            units[index + 1].letter = 'ط';
+            return;
+        }
+        'ز' => {
+            units[index + 1].letter = 'د';
+            return;
+        }
+        'ط' => 'ط',
+        'د' | 'ذ' => 'د',
+        'ت' | 'و' | 'ي' => 'ت',
+        _ => return,
+    };
+
+    let next = units.remove(index + 1);
+    units[index].letter = merged;
+    units[index].marks = std::iter::once(SHADDA).chain(next.marks).collect();
+}
+
+/// Changes the weak middle letter of a root, as in قَالَ, قَائِل, مَقُول, and اسْتِقَامَة.
+///
+/// The verbal nouns of the fourth and tenth forms, which start with an alef, make up for the
+/// dropped letter with a teh marbuta.
+fn change_hollow(units: &mut Vec<Unit>, compensated: bool) {
+    let (Some(index), Some(last)) = (find_slot(units, 1), find_slot(units, 2)) else {
+        return;
+    };
+    let unit = &units[index];
+    if index == 0 || !is_weak(unit.letter) || unit.has(SHADDA) || is_weak(units[last].letter) {
+        return;
+    }
+    let Some(vowel) = unit.vowel() else {
+        return;
+    };
+
+    let previous = &units[index - 1];
+    let next = units.get(index + 1);
+    let long_vowel_follows = next
+        .is_some_and(|next| next.is_literal('ا') || next.is_literal('و') || next.is_literal('ي'));
+
+    if previous.is_literal('ا') && vowel == KASRA {
+        units[index].letter = 'ئ';
+    } else if vowel == DAMMA && next.is_some_and(|next| next.is_literal('و')) {
+        if units[index].letter == 'و' {
+            units[index - 1].marks = vec![DAMMA];
+            units.remove(index);
+        } else {
+            units[index - 1].marks = vec![KASRA];
+            units[index].marks.clear();
+            units.remove(index + 1);
+        }
+    } else if previous.vowel() == Some(FATHA) && !long_vowel_follows {
+        units[index].letter = 'ا';
+        units[index].marks.clear();
+    } else if previous.has(SUKUN) && (!long_vowel_follows || compensated) {
+        units[index - 1].marks = vec![vowel];
+        units[index].letter = match vowel {
+            FATHA => 'ا',
+            DAMMA => 'و',
+            _ => 'ي',
+        };
+        units[index].marks.clear();
+
+        let alef_follows = units.get(index + 1).is_some_and(|u| u.is_literal('ا'));
+        if units[index].letter == 'ا' && alef_follows && compensated {
+            units.remove(index + 1);
+            if let Some(last) = units.last_mut() {
+                last.marks = vec![FATHA];
+            }
+            units.push(Unit::literal('ة'));
+        }
+    }
+}
+
+/// Changes the weak last letter of a root, as in رَمَى, دَعَا, رَامِي, مَدْعُوّ, and اسْتِدْعَاء.
+fn change_defective(units: &mut Vec<Unit>) {
+    let Some(index) = find_slot(units, 2) else {
+        return;
+    };
+    let letter = units[index].letter;
+    if index < 2 || !is_weak(letter) || units[index].has(SHADDA) {
+        return;
+    }
+
+    let is_last = index == units.len() - 1;
+    let previous = &units[index - 1];
+    let previous_vowel = previous.vowel();
+
+    if previous.is_literal('و') && units[index - 2].vowel() == Some(DAMMA) {
+        if letter == 'و' {
+            units.remove(index);
+            units[index - 1].marks = vec![SHADDA];
+        } else {
+            units[index - 2].marks = vec![KASRA];
+            units.remove(index - 1);
+            units[index - 1].marks = vec![SHADDA];
+        }
+    } else if previous.is_literal('ا') && is_last {
+        units[index].letter = 'ء';
+    } else if is_last {
+        let unit = &mut units[index];
+        match previous_vowel {
+            Some(FATHA) if unit.vowel().is_none_or(|vowel| vowel == FATHA) => {
+                unit.letter = if letter == 'و' && index == 2 {
+                    'ا'
+                } else {
+                    'ى'
+                };
+                unit.marks.clear();
+            }
+            Some(KASRA) => {
+                unit.letter = 'ي';
+                unit.marks.retain(|mark| *mark == FATHA);
+            }
+            Some(DAMMA) if letter == 'و' => unit.marks.retain(|mark| *mark == FATHA),
+            _ => {}
+        }
+    }
+}
+
+/// Changes the weak first letter of a root, as in يَعِدُ, مِيزَان, and مُوقِن.
+fn change_assimilated(units: &mut Vec<Unit>) {
+    let Some(index) = find_slot(units, 0) else {
+        return;
+    };
+    let letter = units[index].letter;
+    if index == 0 || !is_weak(letter) || !units[index].has(SUKUN) {
+        return;
+    }
+
+    let previous = units[index - 1].vowel();
+    let dropped = letter == 'و'
+        && previous == Some(FATHA)
+        && units[index - 1].slot.is_none()
+        && units.get(index + 1).and_then(Unit::vowel) == Some(KASRA);
+
+    if dropped {
+        units.remove(index);
+    } else if letter == 'و' && previous == Some(KASRA) {
+        units[index].letter = 'ي';
+        units[index].marks.clear();
+    } else if letter == 'ي' && previous == Some(DAMMA) {
+        units[index].letter = 'و';
+        units[index].marks.clear();
+    }
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::ArabicCharacter;
+    use crate::Pattern;
+
+    fn read(word: &str) -> Vec<ArabicCharacter> {
+        word.chars()
+            .map(|c| ArabicCharacter::try_from(c).unwrap())
+            .collect()
+    }
+
+    fn apply(pattern: Pattern, root: &str) -> String {
+        pattern.apply(&read(root)).unwrap()
+    }
+
+    #[test]
+    fn matches_words() {
+        assert_eq!(Pattern::FAAIL.match_word(&read("كَاتِبٌ")), Some(read("كتب")));
+        assert_eq!(
+            Pattern::TAFIIL.match_word(&read("تَدْرِيس")),
+            Some(read("درس"))
+        );
+        assert_eq!(
+            Pattern::ISTIFAAL.match_word(&read("استخدام")),
+            Some(read("خدم"))
+        );
+        assert_eq!(
+            Pattern::FAAIL.match_roots(&read("سَائِل")),
+            [read("سول"), read("سيل"), read("سءل")]
+        );
+        assert_eq!(Pattern::FAAIL.match_word(&read("كَاتَب")), None);
+        assert_eq!(Pattern::MAFUUL.match_word(&read("مكتبة")), None);
+        assert_eq!(
+            Pattern::new("فَعْلَلَة").match_word(&read("دَحْرَجَة")),
+            Some(read("دحرج"))
+        );
+    }
+
+    #[test]
+    fn matches_the_words_it_forms() {
+        let cases = [
+            (Pattern::IFTAALA, "صبر"),
+            (Pattern::IFTAALA, "زهر"),
+            (Pattern::IFTAALA, "طلع"),
+            (Pattern::IFTAALA, "وصل"),
+            (Pattern::IFTAALA, "رمي"),
+            (Pattern::MAFUUL, "قول"),
+            (Pattern::MAFUUL, "رمي"),
+            (Pattern::MAFUUL, "سءل"),
+            (Pattern::FAAIL, "قول"),
+            (Pattern::FAAIL, "رمي"),
+            (Pattern::FAALA, "قول"),
+            (Pattern::FAALA, "رمي"),
+            (Pattern::FAALA, "دعو"),
+            (Pattern::FAALA, "قرء"),
+            (Pattern::ISTIFAAL, "قوم"),
+            (Pattern::ISTIFAAL, "دعو"),
+            (Pattern::new("يَفْعِلُ"), "وعد"),
+            (Pattern::new("مِفْعَال"), "وزن"),
+            (Pattern::new("مُفْعِل"), "يقن"),
+        ];
+
+        for (pattern, root) in cases {
+            let word = read(&apply(pattern, root));
+            assert_eq!(pattern.match_word(&word), Some(read(root)), "{}", root);
+        }
+    }
+
+    #[test]
+    fn applies_sound_roots() {
+        assert_eq!(apply(Pattern::FAAIL, "كتب"), "كَاتِب");
+        assert_eq!(apply(Pattern::MAFUUL, "كتب"), "مَكْتُوب");
+        assert_eq!(apply(Pattern::TAFIIL, "درس"), "تَدْرِيس");
+        assert_eq!(apply(Pattern::ISTIFAAL, "خدم"), "اسْتِخْدَام");
+        assert_eq!(Pattern::FAALA.apply(&read("دحرج")), None);
+    }
+
+    #[test]
+    fn assimilates_the_eighth_form() {
+        assert_eq!(apply(Pattern::IFTAALA, "صبر"), "اصْطَبَرَ");
+        assert_eq!(apply(Pattern::IFTAALA, "ضرب"), "اضْطَرَبَ");
+        assert_eq!(apply(Pattern::IFTAALA, "طلع"), "اطَّلَعَ");
+        assert_eq!(apply(Pattern::IFTAALA, "زهر"), "ازْدَهَرَ");
+        assert_eq!(apply(Pattern::IFTAALA, "دعو"), "ادَّعَى");
+        assert_eq!(apply(Pattern::IFTAALA, "وصل"), "اتَّصَلَ");
+        assert_eq!(apply(Pattern::IFTAALA, "جمع"), "اجْتَمَعَ");
+    }
+
+    #[test]
+    fn rejects_incomplete_templates() {
+        let pattern = Pattern::new("فَل");
+
+        assert_eq!(pattern.root_length(), 3);
+        assert_eq!(pattern.apply(&read("كتب")), None);
+        assert_eq!(pattern.apply(&read("كب")), None);
+        assert_eq!(pattern.match_word(&read("كَب")), None);
+    }
+
+    #[test]
+    fn changes_weak_roots() {
+        assert_eq!(apply(Pattern::FAALA, "قول"), "قَالَ");
+        assert_eq!(apply(Pattern::FAAIL, "قول"), "قَائِل");
+        assert_eq!(apply(Pattern::MAFUUL, "قول"), "مَقُول");
+        assert_eq!(apply(Pattern::MAFUUL, "بيع"), "مَبِيع");
+        assert_eq!(apply(Pattern::ISTIFAAL, "قوم"), "اسْتِقَامَة");
+        assert_eq!(apply(Pattern::new("يَفْعُلُ"), "قول"), "يَقُولُ");
+        assert_eq!(apply(Pattern::FAALA, "رمي"), "رَمَى");
+        assert_eq!(apply(Pattern::FAALA, "دعو"), "دَعَا");
+        assert_eq!(apply(Pattern::FAAIL, "رمي"), "رَامِي");
+        assert_eq!(apply(Pattern::MAFUUL, "دعو"), "مَدْعُوّ");
+        assert_eq!(apply(Pattern::MAFUUL, "رمي"), "مَرْمِيّ");
+        assert_eq!(apply(Pattern::ISTIFAAL, "دعو"), "اسْتِدْعَاء");
+        assert_eq!(apply(Pattern::new("يَفْعِلُ"), "وعد"), "يَعِدُ");
+        assert_eq!(apply(Pattern::new("مِفْعَال"), "وزن"), "مِيزَان");
+        assert_eq!(apply(Pattern::new("مُفْعِل"), "يقن"), "مُوقِن");
+        assert_eq!(apply(Pattern::new("افَلْعَ"), "قول"), "اقَلَا");
+    }
+
+    #[test]
+    fn seats_the_hamza() {
+        assert_eq!(apply(Pattern::FAALA, "سءل"), "سَأَلَ");
+        assert_eq!(apply(Pattern::FAAIL, "سءل"), "سَائِل");
+        assert_eq!(apply(Pattern::MAFUUL, "سءل"), "مَسْؤُول");
+        assert_eq!(apply(Pattern::FAALA, "قرء"), "قَرَأَ");
+        assert_eq!(apply(Pattern::FAALA, "ءكل"), "أَكَلَ");
+    }
+}
@@ -0,0 +1,100 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::Pattern;
+
+/// The derivational patterns of the stems, written with the letters ف, ع, and ل standing for the
+/// letters of the root.
+///
+/// A second ل stands for the fourth letter of a quadriliteral root. A pattern lacking one of the
+/// three letters reads a stem whose weak or doubled letter was dropped, such as يعد or قل.
+pub(crate) const PATTERNS: [Pattern; 63] = [
+    // The bare and augmented verbs.
+    Pattern::new("فعل"),
+    Pattern::new("افعل"),
+    Pattern::new("تفعل"),
+    Pattern::new("تفاعل"),
+    Pattern::new("انفعل"),
+    Pattern::new("افتعل"),
+    Pattern::new("استفعل"),
+    // The imperfect verbs.
+    Pattern::new("يفعل"),
+    Pattern::new("نفعل"),
+    Pattern::new("يفاعل"),
+    Pattern::new("يتفعل"),
+    Pattern::new("يتفاعل"),
+    Pattern::new("ينفعل"),
+    Pattern::new("يفتعل"),
+    Pattern::new("تفتعل"),
+    Pattern::new("يستفعل"),
+    Pattern::new("تستفعل"),
+    // The verbal nouns.
+    Pattern::new("فعال"),
+    Pattern::new("فعول"),
+    Pattern::new("فعلة"),
+    Pattern::new("فعالة"),
+    Pattern::new("فعولة"),
+    Pattern::new("فعلان"),
+    Pattern::new("تفعيل"),
+    Pattern::new("تفعلة"),
+    Pattern::new("مفاعلة"),
+    Pattern::new("افعال"),
+    Pattern::new("انفعال"),
+    Pattern::new("افتعال"),
+    Pattern::new("استفعال"),
+    // The participles and the nouns of place and instrument.
+    Pattern::new("فاعل"),
+    Pattern::new("فاعلة"),
+    Pattern::new("مفعول"),
+    Pattern::new("مفعولة"),
+    Pattern::new("مفعل"),
+    Pattern::new("مفعلة"),
+    Pattern::new("مفعال"),
+    Pattern::new("مفاعل"),
+    Pattern::new("متفعل"),
+    Pattern::new("متفاعل"),
+    Pattern::new("منفعل"),
+    Pattern::new("مفتعل"),
+    Pattern::new("مستفعل"),
+    Pattern::new("مستفعلة"),
+    // The adjectives and the broken plurals.
+    Pattern::new("فعيل"),
+    Pattern::new("فعيلة"),
+    Pattern::new("فعلاء"),
+    Pattern::new("فعلى"),
+    Pattern::new("افعلة"),
+    Pattern::new("فواعل"),
+    Pattern::new("فعائل"),
+    Pattern::new("مفاعيل"),
+    // The quadriliteral stems.
+    Pattern::new("فعلل"),
+    Pattern::new("فعللة"),
+    Pattern::new("تفعلل"),
+    Pattern::new("يفعلل"),
+    Pattern::new("مفعلل"),
+    Pattern::new("متفعلل"),
+    // The stems that dropped a weak or doubled letter.
+    Pattern::new("فع"),
+    Pattern::new("فل"),
+    Pattern::new("عل"),
+    Pattern::new("يعل"),
+    Pattern::new("تعل"),
+];
+
+/// Returns `true` if the pattern is that of an imperfect verb, which neither a preposition nor the
+/// definite article may precede.
+pub(crate) fn is_imperfect(pattern: &Pattern) -> bool {
+    let template = pattern.template();
+    template.starts_with(['ي', 'ن']) || matches!(template, "تفتعل" | "تستفعل" | "تعل")
+}
@@ -0,0 +1,44 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use std::fmt::{Display, Formatter};
+
+use crate::ArabicCharacter;
+use crate::Pattern;
+
+/// Represents a candidate root of a word, along with the derivational pattern it was read with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RootCandidate {
+    /// The letters of the root: three for a triliteral root, or four for a quadriliteral one.
+    pub letters: Vec<ArabicCharacter>,
+
+    /// The pattern matched by the stem of the word.
+    pub pattern: Pattern,
+
+    /// The cost of the candidate root, where lower costs denote likelier roots.
+    pub cost: u32,
+}
+
+impl Display for RootCandidate {
+    /// Writes the letters of the root separated by spaces, as in ك ت ب.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, letter) in self.letters.iter().enumerate() {
+            if index > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", letter)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,291 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::roots::patterns::is_imperfect;
+use crate::roots::patterns::PATTERNS;
+use crate::ArabicCharacter;
+use crate::Pattern;
+use crate::RootCandidate;
+use crate::UnicodeCharacter;
+
+/// The proclitics stripped from a word before its stem is matched against the patterns.
+const PREFIXES: [&str; 21] = [
+    "", "و", "ف", "ب", "ك", "ل", "س", "ال", "لل", "وال", "فال", "بال", "كال", "ولل", "فلل", "وب",
+    "فب", "ول", "فل", "وس", "فس",
+];
+
+/// The inflectional suffixes and enclitic pronouns stripped from a word before its stem is matched
+/// against the patterns.
+const SUFFIXES: [&str; 26] = [
+    "", "ة", "ه", "ها", "هم", "هما", "هن", "ك", "كم", "كما", "كن", "ي", "نا", "ات", "ان", "ين",
+    "ون", "وا", "ت", "تم", "تن", "ته", "تها", "تهم", "اته", "اتها",
+];
+
+/// The inflectional suffixes a stem may end with, once its clitics are stripped.
+const INFLECTIONS: [&str; 9] = ["", "ة", "ات", "ان", "ين", "ون", "وا", "ت", "تم"];
+
+/// Extracts the candidate roots of a word, ranked from the most to the least likely.
+///
+/// The affixes of the word are stripped, and its stem is matched against the standard derivational
+/// patterns. The hamza is a letter of the root whatever its seat, and a weak letter that turned
+/// into an alef or was dropped is restored as a waw or a yeh:
+///
+/// ```
+/// use arabic_script::{extract_roots, ArabicCharacter};
+///
+/// let read = |word: &str| -> Vec<ArabicCharacter> {
+///     word.chars().map(|c| ArabicCharacter::try_from(c).unwrap()).collect()
+/// };
+///
+/// assert_eq!(extract_roots(&read("مكتبة"))[0].to_string(), "ك ت ب");
+/// assert_eq!(extract_roots(&read("قال"))[0].to_string(), "ق و ل");
+/// assert_eq!(extract_roots(&read("سأل"))[0].to_string(), "س ء ل");
+/// ```
+///
+/// The diacritical marks and the tatweel are ignored.
+pub fn extract_roots(word: &[ArabicCharacter]) -> Vec<RootCandidate> {
+    let letters = normalize(word);
+    let mut candidates: Vec<RootCandidate> = Vec::new();
+
+    for prefix in PREFIXES {
+        let prefix: Vec<char> = prefix.chars().collect();
+        if !letters.starts_with(&prefix) {
+            continue;
+        }
+
+        for suffix in SUFFIXES {
+            let suffix: Vec<char> = suffix.chars().collect();
+            let stem = &letters[prefix.len()..];
+            if stem.len() < suffix.len() + 2 || !stem.ends_with(&suffix) {
+                continue;
+            }
+
+            let stem = &stem[..stem.len() - suffix.len()];
+            let cost = u32::from(!prefix.is_empty()) + u32::from(!suffix.is_empty());
+
+            for pattern in PATTERNS {
+                for (root, root_cost) in match_pattern(stem, pattern) {
+                    add_candidate(&mut candidates, root, pattern, cost + root_cost);
+                }
+            }
+        }
+    }
+
+    candidates.sort_by_key(|candidate| candidate.cost);
+    candidates
+}
+
+/// Returns the cost of the likeliest root of a stem stripped of its clitics, or [`None`] if it
+/// matches no pattern.
+///
+/// The stem may still end with an inflectional suffix, which adds to the cost. A nominal stem,
+/// which follows a preposition or the definite article, is not matched against the imperfect
+/// verbs.
+pub(crate) fn stem_cost(stem: &[ArabicCharacter], nominal: bool) -> Option<u32> {
+    let letters = normalize(stem);
+
+    INFLECTIONS
+        .iter()
+        .filter_map(|suffix| {
+            let suffix: Vec<char> = suffix.chars().collect();
+            if letters.len() < suffix.len() + 2 || !letters.ends_with(&suffix) {
+                return None;
+            }
+
+            let stem = &letters[..letters.len() - suffix.len()];
+            let cost = u32::from(!suffix.is_empty());
+            PATTERNS
+                .iter()
+                .filter(|pattern| !nominal || !is_imperfect(pattern))
+                .flat_map(|pattern| match_pattern(stem, *pattern))
+                .map(|(_, root_cost)| cost + root_cost)
+                .min()
+        })
+        .min()
+}
+
+/// Returns the letters of a word, with the hamza on its own whatever its seat, and the alef with
+/// madda spelled out as a hamza followed by an alef.
+fn normalize(word: &[ArabicCharacter]) -> Vec<char> {
+    let mut letters = Vec::with_capacity(word.len() + 1);
+
+    for character in word.iter().filter(|character| character.is_letter()) {
+        match character.scalar_value() {
+            'آ' => letters.extend(['ء', 'ا']),
+            'أ' | 'إ' | 'ؤ' | 'ئ' => letters.push('ء'),
+            c => letters.push(c),
+        }
+    }
+
+    letters
+}
+
+/// Matches a stem against a pattern, returning the roots it may be read with, each with its cost.
+///
+/// A pattern made of the letters of the root alone is costlier than one with added letters, and a
+/// quadriliteral root is costlier than a triliteral one.
+fn match_pattern(stem: &[char], pattern: Pattern) -> Vec<(Vec<char>, u32)> {
+    let pattern: Vec<char> = pattern.template().chars().collect();
+    if pattern.len() != stem.len() {
+        return Vec::new();
+    }
+
+    let mut slots = [None; 4];
+    let mut literals = 0;
+
+    for (&p, &c) in pattern.iter().zip(stem) {
+        let slot = match p {
+            'ف' => 0,
+            'ع' => 1,
+            'ل' if slots[2].is_some() => 3,
+            'ل' => 2,
+            _ if p == c || (p == 'ا' && c == 'ء') => {
+                literals += 1;
+                continue;
+            }
+            _ => return Vec::new(),
+        };
+        slots[slot] = Some(c);
+    }
+
+    let quadriliteral = slots[3].is_some();
+    let length = if quadriliteral { 4 } else { 3 };
+    let cost = 2 * u32::from(literals == 0) + 2 * u32::from(quadriliteral);
+
+    let mut roots = vec![(Vec::with_capacity(length), cost)];
+    for index in 0..length {
+        let readings = readings(&slots, index, quadriliteral);
+
+        roots = roots
+            .into_iter()
+            .flat_map(|(root, cost)| {
+                readings.iter().map(move |&(letter, extra)| {
+                    let mut root = root.clone();
+                    root.push(letter);
+                    (root, cost + extra)
+                })
+            })
+            .collect();
+    }
+
+    roots
+}
+
+/// Returns the letters a slot of a pattern may be read as, each with its cost.
+fn readings(slots: &[Option<char>; 4], index: usize, quadriliteral: bool) -> Vec<(char, u32)> {
+    let last = index == if quadriliteral { 3 } else { 2 };
+
+    match (slots[index], index) {
+        (None, 0) => vec![('و', 1)],
+        (None, 1) => vec![('و', 1), ('ي', 2)],
+        (None, _) => {
+            let doubled = slots[1].filter(|c| !matches!(c, 'ا' | 'ى' | 'ة'));
+            doubled
+                .map(|c| (c, 1))
+                .into_iter()
+                .chain([('ي', 2), ('و', 3)])
+                .collect()
+        }
+        (Some('ا'), 0) => vec![('ء', 0)],
+        (Some('ا'), _) if !quadriliteral => vec![('و', 1), ('ي', 2)],
+        (Some('ى'), _) if last => vec![('ي', 0), ('و', 1)],
+        (Some('ا' | 'ى' | 'ة'), _) => Vec::new(),
+        (Some(c), _) => vec![(c, 0)],
+    }
+}
+
+/// Adds a candidate root, or lowers the cost of the same root if it was already found.
+fn add_candidate(
+    candidates: &mut Vec<RootCandidate>,
+    root: Vec<char>,
+    pattern: Pattern,
+    cost: u32,
+) {
+    let letters: Vec<ArabicCharacter> = root
+        .into_iter()
+        .filter_map(|c| ArabicCharacter::try_from(c).ok())
+        .collect();
+
+    match candidates.iter_mut().find(|c| c.letters == letters) {
+        Some(candidate) if candidate.cost > cost => {
+            candidate.pattern = pattern;
+            candidate.cost = cost;
+        }
+        Some(_) => {}
+        None => candidates.push(RootCandidate {
+            letters,
+            pattern,
+            cost,
+        }),
+    }
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::extract_roots;
+    use crate::ArabicCharacter;
+    use crate::Pattern;
+    use crate::RootCandidate;
+
+    fn best_candidate(word: &str) -> RootCandidate {
+        let word: Vec<ArabicCharacter> = word
+            .chars()
+            .map(|c| ArabicCharacter::try_from(c).unwrap())
+            .collect();
+
+        extract_roots(&word).remove(0)
+    }
+
+    fn best_root(word: &str) -> String {
+        best_candidate(word).to_string()
+    }
+
+    #[test]
+    fn reports_the_matched_pattern() {
+        let candidate = best_candidate("مكتبة");
+
+        assert_eq!(candidate.pattern, Pattern::new("مفعلة"));
+        assert_eq!(candidate.cost, 0);
+    }
+
+    #[test]
+    fn extracts_sound_roots() {
+        assert_eq!(best_root("مكتبة"), "ك ت ب");
+        assert_eq!(best_root("المكتبات"), "ك ت ب");
+        assert_eq!(best_root("يكتبون"), "ك ت ب");
+        assert_eq!(best_root("كتابهم"), "ك ت ب");
+        assert_eq!(best_root("اسْتِخْدَام"), "خ د م");
+        assert_eq!(best_root("دَحْرَجَة"), "د ح ر ج");
+    }
+
+    #[test]
+    fn restores_weak_letters() {
+        assert_eq!(best_root("قال"), "ق و ل");
+        assert_eq!(best_root("يعد"), "و ع د");
+        assert_eq!(best_root("دعا"), "د ع و");
+        assert_eq!(best_root("رمى"), "ر م ي");
+        assert_eq!(best_root("مدّ"), "م د د");
+    }
+
+    #[test]
+    fn reads_the_hamza_on_any_seat() {
+        assert_eq!(best_root("سؤال"), "س ء ل");
+        assert_eq!(best_root("آمن"), "ء م ن");
+        assert_eq!(best_root("أكرم"), "ك ر م");
+    }
+}
@@ -0,0 +1,288 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::core::LetterCluster;
+use crate::core::TextSegment;
+use crate::roots::stem_cost;
+use crate::ArabicCharacter;
+use crate::ArabicLetterKaf;
+use crate::ArabicLetterLam;
+use crate::ArabicLetterTeh;
+use crate::ArabicLetterTehMarbuta;
+use crate::SegmentedWord;
+use crate::UnicodeCharacter;
+
+/// The cost of a stem that matches no pattern.
+const UNKNOWN_STEM_COST: u32 = 8;
+
+/// The extra cost of the preposition ك, a letter that begins many stems.
+const KAF_COST: u32 = 3;
+
+/// The conjunctions.
+const CONJUNCTIONS: [Option<char>; 3] = [None, Some('و'), Some('ف')];
+
+/// The proclitics that may follow a conjunction: the prepositions and the future marker.
+const PROCLITICS: [Option<char>; 5] = [None, Some('ب'), Some('ك'), Some('ل'), Some('س')];
+
+/// The pronoun suffixes.
+const PRONOUNS: [&str; 13] = [
+    "", "ه", "ها", "هم", "هما", "هن", "ك", "كم", "كما", "كن", "ي", "ني", "نا",
+];
+
+/// Represents the proclitics a word may begin with.
+struct Proclitics {
+    conjunction: Option<char>,
+    proclitic: Option<char>,
+    definite: bool,
+
+    /// The index of the first letter following the proclitics.
+    length: usize,
+}
+
+/// Splits a word into its conjunction, preposition or future marker, definite article, stem, and
+/// pronoun suffix.
+///
+/// Of the possible splits, the one whose stem best fits the derivational patterns is kept, each
+/// clitic adding to its cost, so that words like كتاب and كتبهم keep their initial kaf. The lam of
+/// the article is read after the preposition lam, as in للمدرسة, and the teh marbuta of the stem
+/// is restored where it was written as a teh before a suffix:
+///
+/// ```
+/// use arabic_script::{segment_word, ArabicCharacter};
+///
+/// let word: Vec<ArabicCharacter> = "بمدرستهم"
+///     .chars()
+///     .map(|c| ArabicCharacter::try_from(c).unwrap())
+///     .collect();
+///
+/// assert_eq!(segment_word(&word).to_string(), "ب+ مدرسة +هم");
+/// ```
+///
+/// The marks of a vocalized word stay on its stem and pronoun suffix.
+pub fn segment_word(word: &[ArabicCharacter]) -> SegmentedWord {
+    let clusters = LetterCluster::read(word);
+    let letters: Vec<char> = clusters.iter().map(|c| c.letter.scalar_value()).collect();
+
+    let mut best = SegmentedWord {
+        conjunction: None,
+        preposition: None,
+        future: false,
+        definite: false,
+        stem: word.to_vec(),
+        pronoun: Vec::new(),
+    };
+    let mut best_cost = stem_cost(word, false).unwrap_or(UNKNOWN_STEM_COST);
+
+    for proclitics in read_proclitics(&letters) {
+        for pronoun in PRONOUNS {
+            let pronoun: Vec<char> = pronoun.chars().collect();
+            let Some(end) = letters.len().checked_sub(pronoun.len()) else {
+                continue;
+            };
+            if end < proclitics.length || !letters.ends_with(&pronoun) {
+                continue;
+            }
+
+            let start = proclitics.length;
+            let future = proclitics.proclitic == Some('س');
+            let mut candidate = SegmentedWord {
+                conjunction: proclitics.conjunction.and_then(|c| c.try_into().ok()),
+                preposition: proclitics
+                    .proclitic
+                    .filter(|_| !future)
+                    .and_then(|c| c.try_into().ok()),
+                future,
+                definite: proclitics.definite,
+                stem: if end > start {
+                    word[clusters[start].start..clusters[end - 1].end].to_vec()
+                } else {
+                    Vec::new()
+                },
+                pronoun: if pronoun.is_empty() {
+                    Vec::new()
+                } else {
+                    word[clusters[end].start..].to_vec()
+                },
+            };
+
+            if let Some(cost) = cost(&mut candidate) {
+                if cost < best_cost {
+                    best = candidate;
+                    best_cost = cost;
+                }
+            }
+        }
+    }
+
+    best
+}
+
+/// Splits each word of a text into its clitics and stem.
+///
+/// Characters that do not belong to the Arabic script only separate the words.
+pub fn segment(text: &str) -> Vec<SegmentedWord> {
+    TextSegment::read(text)
+        .iter()
+        .filter_map(|segment| match segment {
+            TextSegment::Word { characters, .. } => Some(segment_word(characters)),
+            TextSegment::Other(_) => None,
+        })
+        .collect()
+}
+
+/// Returns the possible proclitics of the letters of a word, including none at all.
+fn read_proclitics(letters: &[char]) -> Vec<Proclitics> {
+    let mut readings = Vec::new();
+
+    for conjunction in CONJUNCTIONS {
+        for proclitic in PROCLITICS {
+            for definite in [false, true] {
+                let article = match (definite, proclitic) {
+                    (false, _) => "",
+                    (true, Some('س')) => continue,
+                    (true, Some('ل')) => "ل",
+                    (true, _) => "ال",
+                };
+
+                let prefix: Vec<char> = conjunction
+                    .into_iter()
+                    .chain(proclitic)
+                    .chain(article.chars())
+                    .collect();
+                if letters.starts_with(&prefix) {
+                    readings.push(Proclitics {
+                        conjunction,
+                        proclitic,
+                        definite,
+                        length: prefix.len(),
+                    });
+                }
+            }
+        }
+    }
+
+    readings
+}
+
+/// Returns the cost of a split of a word, or [`None`] if its stem cannot stand.
+///
+/// The teh the stem ends with before a pronoun suffix is turned into a teh marbuta unless the stem
+/// fits the patterns better as written.
+fn cost(candidate: &mut SegmentedWord) -> Option<u32> {
+    let clitics = [
+        candidate.conjunction.is_some(),
+        candidate.preposition.is_some() || candidate.future,
+        candidate.definite,
+        !candidate.pronoun.is_empty(),
+    ]
+    .into_iter()
+    .filter(|clitic| *clitic)
+    .count() as u32;
+
+    let letters: Vec<usize> = (0..candidate.stem.len())
+        .filter(|index| candidate.stem[*index].is_letter())
+        .collect();
+
+    if letters.is_empty() {
+        let preposition = candidate.preposition.is_some_and(|p| p != ArabicLetterKaf);
+        let particle = preposition && !candidate.pronoun.is_empty();
+        return (particle && !candidate.definite).then_some(1);
+    }
+    if letters.len() < 2 {
+        return None;
+    }
+
+    let first = candidate.stem[letters[0]].scalar_value();
+    if candidate.future && !matches!(first, 'ي' | 'ت' | 'ن' | 'أ') {
+        return None;
+    }
+
+    let nominal = candidate.definite || candidate.preposition.is_some_and(|p| p != ArabicLetterLam);
+    let mut cost = stem_cost(&candidate.stem, nominal).unwrap_or(UNKNOWN_STEM_COST);
+
+    let last = letters[letters.len() - 1];
+    if !candidate.pronoun.is_empty() && candidate.stem[last] == ArabicLetterTeh {
+        let mut restored = candidate.stem.clone();
+        restored[last] = ArabicLetterTehMarbuta;
+
+        let restored_cost = stem_cost(&restored, nominal).unwrap_or(UNKNOWN_STEM_COST);
+        if restored_cost <= cost {
+            candidate.stem = restored;
+            cost = restored_cost;
+        }
+    }
+
+    if candidate.preposition == Some(ArabicLetterKaf) {
+        cost += KAF_COST;
+    }
+
+    Some(cost + clitics)
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::segment;
+
+    fn tokens(word: &str) -> Vec<String> {
+        segment(word).remove(0).tokens()
+    }
+
+    #[test]
+    fn splits_the_proclitics() {
+        assert_eq!(tokens("والكتاب"), ["و+", "الكتاب"]);
+        assert_eq!(tokens("وللمدرسة"), ["و+", "ل+", "المدرسة"]);
+        assert_eq!(tokens("فبالقلم"), ["ف+", "ب+", "القلم"]);
+        assert_eq!(tokens("وسيكتبونها"), ["و+", "سيكتبون", "+ها"]);
+        assert!(segment("وسيكتبونها")[0].future);
+    }
+
+    #[test]
+    fn keeps_the_letters_of_the_stem() {
+        assert_eq!(tokens("كتاب"), ["كتاب"]);
+        assert_eq!(tokens("ولد"), ["ولد"]);
+        assert_eq!(tokens("سلام"), ["سلام"]);
+        assert_eq!(tokens("بيتهم"), ["بيت", "+هم"]);
+        assert_eq!(tokens("كتبهم"), ["كتب", "+هم"]);
+        assert_eq!(tokens("كالبيت"), ["ك+", "البيت"]);
+    }
+
+    #[test]
+    fn restores_the_teh_marbuta() {
+        assert_eq!(tokens("بمدرستهم"), ["ب+", "مدرسة", "+هم"]);
+        assert_eq!(tokens("سيارتي"), ["سيارة", "+ي"]);
+        assert_eq!(tokens("مكتبتنا"), ["مكتبة", "+نا"]);
+    }
+
+    #[test]
+    fn splits_prepositions_with_pronouns() {
+        let word = segment("لهم").remove(0);
+
+        assert!(word.stem.is_empty());
+        assert_eq!(word.tokens(), ["ل+", "+هم"]);
+        assert_eq!(tokens("بها"), ["ب+", "+ها"]);
+        assert_eq!(tokens("كهم"), ["كهم"]);
+    }
+
+    #[test]
+    fn keeps_the_marks_on_the_stem() {
+        let word = segment("وَبِالْقَلَمِ").remove(0);
+
+        assert!(word.definite);
+        assert_eq!(word.to_string(), "و+ ب+ القَلَمِ");
+    }
+}
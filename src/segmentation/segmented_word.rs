@@ -0,0 +1,82 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use std::fmt::{Display, Formatter};
+
+use crate::ArabicCharacter;
+
+/// Represents a word split into its proclitics, its stem, and its pronoun suffix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SegmentedWord {
+    /// The conjunction و or ف, if any.
+    pub conjunction: Option<ArabicCharacter>,
+
+    /// The preposition ب, ك, or ل, if any.
+    pub preposition: Option<ArabicCharacter>,
+
+    /// Whether the word begins with the future marker س.
+    pub future: bool,
+
+    /// Whether the stem takes the definite article.
+    pub definite: bool,
+
+    /// The stem, without the definite article, and with the teh marbuta it ends with restored.
+    pub stem: Vec<ArabicCharacter>,
+
+    /// The pronoun suffix, or nothing if the word has none.
+    pub pronoun: Vec<ArabicCharacter>,
+}
+
+impl SegmentedWord {
+    /// Returns the tokens of the word in the style of the Penn Arabic Treebank, where each clitic
+    /// is split from the stem and marked with a plus sign on the side it is attached to, and the
+    /// definite article and the future marker stay on the stem.
+    ///
+    /// ```
+    /// use arabic_script::segment;
+    ///
+    /// assert_eq!(segment("وللمدرسة")[0].tokens(), ["و+", "ل+", "المدرسة"]);
+    /// assert_eq!(segment("سيارتهم")[0].tokens(), ["سيارة", "+هم"]);
+    /// ```
+    pub fn tokens(&self) -> Vec<String> {
+        let mut tokens = Vec::with_capacity(5);
+
+        for clitic in [self.conjunction, self.preposition].into_iter().flatten() {
+            tokens.push(format!("{}+", clitic));
+        }
+        let mut stem = match (self.future, self.definite) {
+            (true, _) => "س".to_string(),
+            (false, true) => "ال".to_string(),
+            (false, false) => String::new(),
+        };
+        stem.extend(self.stem.iter().map(ToString::to_string));
+        if !stem.is_empty() {
+            tokens.push(stem);
+        }
+
+        if !self.pronoun.is_empty() {
+            let pronoun: String = self.pronoun.iter().map(ToString::to_string).collect();
+            tokens.push(format!("+{}", pronoun));
+        }
+
+        tokens
+    }
+}
+
+impl Display for SegmentedWord {
+    /// Writes the tokens of the word separated by spaces.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tokens().join(" "))
+    }
+}
@@ -0,0 +1,87 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::core::TextSegment;
+use crate::ArabicCharacter;
+use crate::ArabicTatweel;
+
+/// Spells a word out, returning the Arabic name of each of its characters in order.
+///
+/// The diacritical marks are named after the letter carrying them, and the tatweel, which only
+/// stretches the word, is not named:
+///
+/// ```
+/// use arabic_script::{spell_word, ArabicCharacter};
+///
+/// let word: Vec<ArabicCharacter> = "بـَاب"
+///     .chars()
+///     .map(|c| ArabicCharacter::try_from(c).unwrap())
+///     .collect();
+///
+/// assert_eq!(spell_word(&word), ["باء", "فتحة", "ألف", "باء"]);
+/// ```
+pub fn spell_word(word: &[ArabicCharacter]) -> Vec<&'static str> {
+    word.iter()
+        .filter(|character| **character != ArabicTatweel)
+        .map(ArabicCharacter::arabic_name)
+        .collect()
+}
+
+/// Spells each word of a text out, returning the Arabic names of its characters.
+///
+/// Characters that do not belong to the Arabic script only separate the words.
+pub fn spell(text: &str) -> Vec<Vec<&'static str>> {
+    TextSegment::read(text)
+        .iter()
+        .filter_map(|segment| match segment {
+            TextSegment::Word { characters, .. } => Some(spell_word(characters)),
+            TextSegment::Other(_) => None,
+        })
+        .collect()
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::spell;
+
+    #[test]
+    fn spells_each_word() {
+        assert_eq!(
+            spell("سُؤَال، مَدْرَسَة"),
+            [
+                vec!["سين", "ضمة", "همزة على الواو", "فتحة", "ألف", "لام"],
+                vec![
+                    "ميم",
+                    "فتحة",
+                    "دال",
+                    "سكون",
+                    "راء",
+                    "فتحة",
+                    "سين",
+                    "فتحة",
+                    "تاء مربوطة"
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_the_tatweel() {
+        assert_eq!(spell("كـــتب"), [vec!["كاف", "تاء", "باء"]]);
+    }
+}
@@ -0,0 +1,29 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+/// Returns the length of the first of the prefixes the word starts with, if any.
+pub(crate) fn prefix_length(word: &[char], prefixes: &[&str]) -> Option<usize> {
+    prefixes.iter().find_map(|prefix| {
+        let prefix: Vec<char> = prefix.chars().collect();
+        word.starts_with(&prefix).then_some(prefix.len())
+    })
+}
+
+/// Returns the length of the first of the suffixes the word ends with, if any.
+pub(crate) fn suffix_length(word: &[char], suffixes: &[&str]) -> Option<usize> {
+    suffixes.iter().find_map(|suffix| {
+        let suffix: Vec<char> = suffix.chars().collect();
+        word.ends_with(&suffix).then_some(suffix.len())
+    })
+}
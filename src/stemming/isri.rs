@@ -0,0 +1,213 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::stemming::affixes::prefix_length;
+use crate::stemming::affixes::suffix_length;
+
+/// The prefixes of three letters, stripped from words of six letters or more.
+const PREFIXES_3: [&str; 4] = ["كال", "بال", "ولل", "وال"];
+
+/// The prefixes of two letters, stripped from words of five letters or more.
+const PREFIXES_2: [&str; 2] = ["ال", "لل"];
+
+/// The prefixes of one letter.
+const PREFIXES_1: [&str; 9] = ["ل", "ب", "ف", "س", "و", "ي", "ت", "ن", "ا"];
+
+/// The suffixes of three letters, stripped from words of six letters or more.
+const SUFFIXES_3: [&str; 5] = ["تمل", "همل", "تان", "تين", "كمل"];
+
+/// The suffixes of two letters, stripped from words of five letters or more.
+const SUFFIXES_2: [&str; 16] = [
+    "ون", "ات", "ان", "ين", "تن", "كم", "هن", "نا", "يا", "ها", "تم", "كن", "ني", "وا", "ما", "هم",
+];
+
+/// The suffixes of one letter.
+const SUFFIXES_1: [&str; 7] = ["ة", "ه", "ي", "ك", "ت", "ا", "ن"];
+
+/// Stems a normalized word with the ISRI algorithm.
+pub(crate) fn isri(word: Vec<char>) -> Vec<char> {
+    let mut word = strip_suffixes(strip_prefixes(word));
+
+    if word.len() >= 4 && word[0] == 'و' && word[1] == 'و' {
+        word.remove(0);
+    }
+
+    match word.len() {
+        4 => pattern_4(word),
+        5 => reduce_5(pattern_5(word)),
+        6 => reduce_6(pattern_6(word)),
+        7 => {
+            let word = strip_suffix_1(word);
+            let word = if word.len() == 7 {
+                strip_prefix_1(word)
+            } else {
+                word
+            };
+            if word.len() == 6 {
+                reduce_6(pattern_6(word))
+            } else {
+                word
+            }
+        }
+        _ => word,
+    }
+}
+
+/// Strips a prefix of three letters, or failing that of two letters, by the length of the word.
+fn strip_prefixes(mut word: Vec<char>) -> Vec<char> {
+    let length = match word.len() {
+        6.. if prefix_length(&word, &PREFIXES_3).is_some() => 3,
+        5.. if prefix_length(&word, &PREFIXES_2).is_some() => 2,
+        _ => 0,
+    };
+
+    word.drain(..length);
+    word
+}
+
+/// Strips a suffix of three letters, or failing that of two letters, by the length of the word.
+fn strip_suffixes(mut word: Vec<char>) -> Vec<char> {
+    let length = match word.len() {
+        6.. if suffix_length(&word, &SUFFIXES_3).is_some() => 3,
+        5.. if suffix_length(&word, &SUFFIXES_2).is_some() => 2,
+        _ => 0,
+    };
+
+    word.truncate(word.len() - length);
+    word
+}
+
+/// Strips a prefix of one letter.
+fn strip_prefix_1(mut word: Vec<char>) -> Vec<char> {
+    if prefix_length(&word, &PREFIXES_1).is_some() {
+        word.remove(0);
+    }
+    word
+}
+
+/// Strips a suffix of one letter.
+fn strip_suffix_1(mut word: Vec<char>) -> Vec<char> {
+    if suffix_length(&word, &SUFFIXES_1).is_some() {
+        word.pop();
+    }
+    word
+}
+
+/// Picks the letters at the given indices of a word.
+fn pick(word: &[char], indices: &[usize]) -> Vec<char> {
+    indices.iter().map(|index| word[*index]).collect()
+}
+
+/// Reduces a word of four letters by its pattern.
+fn pattern_4(word: Vec<char>) -> Vec<char> {
+    match word[..] {
+        ['م', ..] => pick(&word, &[1, 2, 3]),
+        [_, 'ا', ..] => pick(&word, &[0, 2, 3]),
+        [_, _, 'ا' | 'و' | 'ي', _] => pick(&word, &[0, 1, 3]),
+        [_, _, _, 'ة' | 'ه'] => pick(&word, &[0, 1, 2]),
+        _ => {
+            let word = strip_suffix_1(word);
+            if word.len() == 4 {
+                strip_prefix_1(word)
+            } else {
+                word
+            }
+        }
+    }
+}
+
+/// Reduces a word of five letters by its pattern, to three letters if it has one.
+fn pattern_5(word: Vec<char>) -> Vec<char> {
+    match word[..] {
+        ['ا', _, 'ا' | 'ت', _, _] => pick(&word, &[1, 3, 4]),
+        ['م', _, _, 'ا' | 'ي' | 'و', _] => pick(&word, &[1, 2, 4]),
+        ['ا' | 'ت' | 'م', _, _, _, 'ة'] => pick(&word, &[1, 2, 3]),
+        ['م' | 'ي' | 'ت', _, 'ت', _, _] => pick(&word, &[1, 3, 4]),
+        ['م' | 'ت', _, 'ا', _, _] => pick(&word, &[1, 3, 4]),
+        [_, _, 'ا' | 'و', _, 'ة'] => pick(&word, &[0, 1, 3]),
+        ['ا' | 'م', 'ن', _, _, _] => pick(&word, &[2, 3, 4]),
+        ['ا', _, _, 'ا', _] => pick(&word, &[1, 2, 4]),
+        [_, _, _, 'ا', 'ن'] => pick(&word, &[0, 1, 2]),
+        ['ت', _, _, 'ي', _] => pick(&word, &[1, 2, 4]),
+        [_, 'ا', _, 'و', _] => pick(&word, &[0, 2, 4]),
+        [_, 'و', 'ا', _, _] => pick(&word, &[0, 3, 4]),
+        [_, _, 'ا', 'ئ', _] => pick(&word, &[0, 1, 4]),
+        [_, 'ا', _, _, 'ة'] => pick(&word, &[0, 2, 3]),
+        [_, _, 'ا', _, 'ي'] => pick(&word, &[0, 1, 3]),
+        _ => {
+            let word = strip_suffix_1(word);
+            if word.len() == 5 {
+                strip_prefix_1(word)
+            } else {
+                word
+            }
+        }
+    }
+}
+
+/// Reduces a word of five letters with four root letters by its pattern.
+fn pattern_5_quadriliteral(word: Vec<char>) -> Vec<char> {
+    match word[..] {
+        ['ا' | 'ت' | 'م', ..] => pick(&word, &[1, 2, 3, 4]),
+        [_, _, _, _, 'ة'] => pick(&word, &[0, 1, 2, 3]),
+        [_, _, 'ا', _, _] => pick(&word, &[0, 1, 3, 4]),
+        _ => word,
+    }
+}
+
+/// Reduces a word of six letters by its pattern, to three letters if it has one.
+fn pattern_6(word: Vec<char>) -> Vec<char> {
+    match word[..] {
+        ['ا' | 'م', 'س', 'ت', ..] => pick(&word, &[3, 4, 5]),
+        ['م', _, _, 'ا', _, 'ة'] => pick(&word, &[1, 2, 4]),
+        ['ا', _, 'ت', _, 'ا', _] => pick(&word, &[1, 3, 5]),
+        ['ا', _, a, 'و', b, _] if a == b => pick(&word, &[1, 4, 5]),
+        ['ت', _, 'ا', _, 'ي', _] => pick(&word, &[1, 3, 5]),
+        _ => {
+            let word = strip_suffix_1(word);
+            if word.len() == 6 {
+                strip_prefix_1(word)
+            } else {
+                word
+            }
+        }
+    }
+}
+
+/// Reduces a word of six letters with four root letters by its pattern.
+fn pattern_6_quadriliteral(word: Vec<char>) -> Vec<char> {
+    match word[..] {
+        ['ا', _, _, _, 'ا', _] => pick(&word, &[1, 2, 3, 5]),
+        ['م', 'ت', ..] => pick(&word, &[2, 3, 4, 5]),
+        _ => word,
+    }
+}
+
+/// Reduces further a word left with four or five letters after its pattern of five letters.
+fn reduce_5(word: Vec<char>) -> Vec<char> {
+    match word.len() {
+        4 => pattern_4(word),
+        5 => pattern_5_quadriliteral(word),
+        _ => word,
+    }
+}
+
+/// Reduces further a word left with five or six letters after its pattern of six letters.
+fn reduce_6(word: Vec<char>) -> Vec<char> {
+    match word.len() {
+        5 => reduce_5(pattern_5(word)),
+        6 => pattern_6_quadriliteral(word),
+        _ => word,
+    }
+}
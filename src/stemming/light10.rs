@@ -0,0 +1,53 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::stemming::affixes::prefix_length;
+use crate::stemming::affixes::suffix_length;
+
+/// The definite articles, alone or preceded by a particle.
+const ARTICLES: [&str; 6] = ["وال", "بال", "كال", "فال", "لل", "ال"];
+
+/// The suffixes, in the order they are stripped.
+const SUFFIXES: [&str; 8] = ["ها", "ان", "ات", "ون", "ين", "يه", "ه", "ي"];
+
+/// Stems a normalized word with the Light10 algorithm.
+///
+/// A final alef maksura is read as a yeh, and a final teh marbuta as a heh.
+pub(crate) fn light10(mut word: Vec<char>) -> Vec<char> {
+    match word.last_mut() {
+        Some(last @ 'ى') => *last = 'ي',
+        Some(last @ 'ة') => *last = 'ه',
+        _ => {}
+    }
+
+    if word.len() > 3 && word[0] == 'و' {
+        word.remove(0);
+    }
+
+    if let Some(length) = prefix_length(&word, &ARTICLES) {
+        if word.len() - length >= 2 {
+            word.drain(..length);
+        }
+    }
+
+    for suffix in SUFFIXES {
+        if let Some(length) = suffix_length(&word, &[suffix]) {
+            if word.len() - length >= 2 {
+                word.truncate(word.len() - length);
+            }
+        }
+    }
+
+    word
+}
@@ -0,0 +1,134 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::core::TextSegment;
+use crate::stemming::isri::isri;
+use crate::stemming::light10::light10;
+use crate::ArabicCharacter;
+use crate::ArabicTatweel;
+use crate::StemmingAlgorithm;
+use crate::UnicodeCharacter;
+
+/// Strips the prefixes and suffixes of Arabic words, with the Light10 or the ISRI algorithm.
+///
+/// Before it is stemmed, a word is stripped of its diacritical marks and its tatweel, and the
+/// forms of the alef carrying a hamza or a madda are reduced to the bare alef:
+///
+/// ```
+/// use arabic_script::{Stemmer, StemmingAlgorithm};
+///
+/// let stemmer = Stemmer::new();
+/// assert_eq!(stemmer.stem_text("والمكتبات"), "مكتب");
+///
+/// let stemmer = stemmer.with_algorithm(StemmingAlgorithm::Isri);
+/// assert_eq!(stemmer.stem_text("المكتبة"), "كتب");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Stemmer {
+    algorithm: StemmingAlgorithm,
+}
+
+impl Stemmer {
+    /// Returns a new stemmer, that uses the Light10 algorithm.
+    pub fn new() -> Self {
+        Self {
+            algorithm: StemmingAlgorithm::Light10,
+        }
+    }
+
+    /// Returns the stemmer, using the given algorithm.
+    pub fn with_algorithm(mut self, algorithm: StemmingAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Returns the algorithm of the stemmer.
+    pub fn algorithm(&self) -> StemmingAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns the stem of a word.
+    pub fn stem(&self, word: &[ArabicCharacter]) -> Vec<ArabicCharacter> {
+        let normalized: Vec<char> = word
+            .iter()
+            .filter(|character| !character.is_diacritic() && **character != ArabicTatweel)
+            .map(|character| match character.scalar_value() {
+                'آ' | 'أ' | 'إ' => 'ا',
+                c => c,
+            })
+            .collect();
+
+        let stem = match self.algorithm {
+            StemmingAlgorithm::Light10 => light10(normalized),
+            StemmingAlgorithm::Isri => isri(normalized),
+        };
+
+        stem.into_iter()
+            .filter_map(|c| ArabicCharacter::try_from(c).ok())
+            .collect()
+    }
+
+    /// Returns a text with each of its words replaced by its stem.
+    ///
+    /// Characters that do not belong to the Arabic script are left untouched.
+    pub fn stem_text(&self, text: &str) -> String {
+        let mut stemmed = String::with_capacity(text.len());
+
+        for segment in TextSegment::read(text) {
+            match segment {
+                TextSegment::Word { characters, .. } => {
+                    stemmed.extend(self.stem(&characters).iter().map(|c| c.scalar_value()))
+                }
+                TextSegment::Other(other) => stemmed.push_str(&other),
+            }
+        }
+
+        stemmed
+    }
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::Stemmer;
+    use crate::StemmingAlgorithm;
+
+    #[test]
+    fn stems_with_light10() {
+        let stemmer = Stemmer::new();
+
+        assert_eq!(stemmer.stem_text("والمكتبات"), "مكتب");
+        assert_eq!(stemmer.stem_text("بالمدرسة"), "مدرس");
+        assert_eq!(stemmer.stem_text("وَالْكِتَابَانِ"), "كتاب");
+        assert_eq!(stemmer.stem_text("ولد"), "ولد");
+    }
+
+    #[test]
+    fn stems_with_isri() {
+        let stemmer = Stemmer::new().with_algorithm(StemmingAlgorithm::Isri);
+
+        assert_eq!(stemmer.stem_text("كتابهم"), "كتب");
+        assert_eq!(stemmer.stem_text("المكتبة"), "كتب");
+        assert_eq!(stemmer.stem_text("يكتبون"), "كتب");
+        assert_eq!(stemmer.stem_text("استخدام"), "خدم");
+    }
+
+    #[test]
+    fn preserves_the_other_characters() {
+        assert_eq!(Stemmer::new().stem_text("(المدرسة), 2023"), "(مدرس), 2023");
+    }
+}
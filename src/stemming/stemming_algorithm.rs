@@ -0,0 +1,26 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+/// Represents an algorithm of light stemming.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum StemmingAlgorithm {
+    /// The Light10 algorithm of Larkey, Ballesteros, and Connell, which strips the conjunction و,
+    /// the definite articles, and a list of suffixes.
+    #[default]
+    Light10,
+
+    /// The ISRI algorithm of Taghva, Elkhoury, and Coombs, which strips the affixes by the length
+    /// of the word, then reduces the remaining word by its pattern.
+    Isri,
+}
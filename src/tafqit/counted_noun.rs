@@ -0,0 +1,52 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::Gender;
+
+/// Represents a noun counted by a number, such as a currency unit.
+///
+/// The singular and the plural are given vocalized, without their case ending. The dual is derived
+/// from the singular.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CountedNoun {
+    /// The gender of the noun.
+    pub gender: Gender,
+
+    /// The singular of the noun, such as رِيَال.
+    pub singular: &'static str,
+
+    /// The plural of the noun, such as رِيَالَات.
+    pub plural: &'static str,
+
+    /// Whether the plural is a diptote, such as دَرَاهِم, which takes no tanween.
+    pub diptote_plural: bool,
+}
+
+impl CountedNoun {
+    /// Returns a new noun, whose plural takes a tanween.
+    pub const fn new(gender: Gender, singular: &'static str, plural: &'static str) -> Self {
+        Self {
+            gender,
+            singular,
+            plural,
+            diptote_plural: false,
+        }
+    }
+
+    /// Returns the noun, with a plural that is a diptote.
+    pub const fn with_diptote_plural(mut self) -> Self {
+        self.diptote_plural = true;
+        self
+    }
+}
@@ -0,0 +1,59 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::CountedNoun;
+use crate::Gender;
+
+/// Represents a currency, with its unit and its subunit.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Currency {
+    /// The unit of the currency, such as the riyal.
+    pub unit: CountedNoun,
+
+    /// The subunit of the currency, such as the halala.
+    pub subunit: CountedNoun,
+
+    /// The number of subunits in a unit.
+    pub subunits: u64,
+}
+
+impl Currency {
+    /// The Saudi riyal, divided into one hundred halalas.
+    pub const SAUDI_RIYAL: Currency = Currency {
+        unit: CountedNoun::new(Gender::Masculine, "رِيَال", "رِيَالَات"),
+        subunit: CountedNoun::new(Gender::Feminine, "هَلَلَة", "هَلَلَات"),
+        subunits: 100,
+    };
+
+    /// The Emirati dirham, divided into one hundred fils.
+    pub const EMIRATI_DIRHAM: Currency = Currency {
+        unit: CountedNoun::new(Gender::Masculine, "دِرْهَم", "دَرَاهِم").with_diptote_plural(),
+        subunit: CountedNoun::new(Gender::Masculine, "فِلْس", "فُلُوس"),
+        subunits: 100,
+    };
+
+    /// The Kuwaiti dinar, divided into one thousand fils.
+    pub const KUWAITI_DINAR: Currency = Currency {
+        unit: CountedNoun::new(Gender::Masculine, "دِينَار", "دَنَانِير").with_diptote_plural(),
+        subunit: CountedNoun::new(Gender::Masculine, "فِلْس", "فُلُوس"),
+        subunits: 1000,
+    };
+
+    /// The Egyptian pound, divided into one hundred piastres.
+    pub const EGYPTIAN_POUND: Currency = Currency {
+        unit: CountedNoun::new(Gender::Masculine, "جُنَيْه", "جُنَيْهَات"),
+        subunit: CountedNoun::new(Gender::Masculine, "قِرْش", "قُرُوش"),
+        subunits: 100,
+    };
+}
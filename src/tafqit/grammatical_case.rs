@@ -0,0 +1,27 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+/// Represents the grammatical case of a phrase.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum GrammaticalCase {
+    /// The nominative case (الرفع), as of a subject.
+    #[default]
+    Nominative,
+
+    /// The accusative case (النصب), as of an object.
+    Accusative,
+
+    /// The genitive case (الجر), as after a preposition.
+    Genitive,
+}
@@ -0,0 +1,28 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+//! The **Tafqit** module writes numbers out in Arabic words.
+
+pub use counted_noun::CountedNoun;
+pub use currency::Currency;
+pub use gender::Gender;
+pub use grammatical_case::GrammaticalCase;
+pub use tafqit_generator::Tafqit;
+
+mod counted_noun;
+mod currency;
+mod gender;
+mod grammatical_case;
+mod tafqit_generator;
+mod word;
@@ -0,0 +1,565 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::strip_tashkeel;
+use crate::tafqit::word::Declension;
+use crate::tafqit::word::Word;
+use crate::CountedNoun;
+use crate::Currency;
+use crate::Gender;
+use crate::GrammaticalCase;
+
+/// The units from three to ten, as counting feminine nouns.
+const FEMININE_UNITS: [&str; 8] = ["ثَلَاث", "أَرْبَع", "خَمْس", "سِتّ", "سَبْع", "ثَمَانِي", "تِسْع", "عَشْر"];
+
+/// The units from three to ten, as counting masculine nouns.
+const MASCULINE_UNITS: [&str; 8] = [
+    "ثَلَاثَة",
+    "أَرْبَعَة",
+    "خَمْسَة",
+    "سِتَّة",
+    "سَبْعَة",
+    "ثَمَانِيَة",
+    "تِسْعَة",
+    "عَشَرَة",
+];
+
+/// The tens from twenty to ninety, without their plural ending.
+const TENS: [&str; 8] = ["عِشْر", "ثَلَاث", "أَرْبَع", "خَمْس", "سِتّ", "سَبْع", "ثَمَان", "تِسْع"];
+
+/// The ordinals from two to ten, describing masculine nouns.
+const MASCULINE_ORDINALS: [&str; 9] = [
+    "ثَانِي",
+    "ثَالِث",
+    "رَابِع",
+    "خَامِس",
+    "سَادِس",
+    "سَابِع",
+    "ثَامِن",
+    "تَاسِع",
+    "عَاشِر",
+];
+
+/// The ordinals from two to ten, describing feminine nouns.
+const FEMININE_ORDINALS: [&str; 9] = [
+    "ثَانِيَة",
+    "ثَالِثَة",
+    "رَابِعَة",
+    "خَامِسَة",
+    "سَادِسَة",
+    "سَابِعَة",
+    "ثَامِنَة",
+    "تَاسِعَة",
+    "عَاشِرَة",
+];
+
+/// The fractions from the half to the tenth.
+const FRACTIONS: [&str; 9] = [
+    "نِصْف",
+    "ثُلُث",
+    "رُبُع",
+    "خُمُس",
+    "سُدُس",
+    "سُبُع",
+    "ثُمُن",
+    "تُسُع",
+    "عُشُر",
+];
+
+/// The scales of the groups of three digits, from the thousand to the quintillion.
+const SCALES: [CountedNoun; 6] = [
+    CountedNoun::new(Gender::Masculine, "أَلْف", "آلَاف"),
+    CountedNoun::new(Gender::Masculine, "مِلْيُون", "مَلَايِين").with_diptote_plural(),
+    CountedNoun::new(Gender::Masculine, "مِلْيَار", "مِلْيَارَات"),
+    CountedNoun::new(Gender::Masculine, "تِرِلْيُون", "تِرِلْيُونَات"),
+    CountedNoun::new(Gender::Masculine, "كُوَادْرِلْيُون", "كُوَادْرِلْيُونَات"),
+    CountedNoun::new(Gender::Masculine, "كْوِينْتِلْيُون", "كْوِينْتِلْيُونَات"),
+];
+
+/// Writes numbers out in Arabic words (التفقيط).
+///
+/// The words agree with the gender of what they count, with the units from three to ten taking the
+/// opposite gender, and the counted noun follows the number in the case and number it governs:
+///
+/// ```
+/// use arabic_script::{Currency, Tafqit};
+///
+/// let tafqit = Tafqit::new();
+/// assert_eq!(
+///     tafqit.count(3250, &Currency::SAUDI_RIYAL.unit),
+///     "ثَلَاثَةُ آلَافٍ وَمِئَتَانِ وَخَمْسُونَ رِيَالًا"
+/// );
+///
+/// let tafqit = tafqit.with_vocalization(false);
+/// assert_eq!(
+///     tafqit.amount(10, 50, &Currency::SAUDI_RIYAL),
+///     "عشرة ريالات وخمسون هللة"
+/// );
+/// ```
+///
+/// When a number larger than a hundred ends with one or two, the counted noun follows it in the
+/// accusative singular, as it does after the numbers from eleven to ninety-nine.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Tafqit {
+    case: GrammaticalCase,
+    vocalized: bool,
+}
+
+impl Tafqit {
+    /// Returns a new generator, that writes vocalized numbers in the nominative case.
+    pub fn new() -> Self {
+        Self {
+            case: GrammaticalCase::Nominative,
+            vocalized: true,
+        }
+    }
+
+    /// Returns the generator, writing numbers in the given grammatical case.
+    pub fn with_case(mut self, case: GrammaticalCase) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Returns the generator, writing vocalized or unvocalized numbers.
+    pub fn with_vocalization(mut self, vocalized: bool) -> Self {
+        self.vocalized = vocalized;
+        self
+    }
+
+    /// Writes a number out, as counting things of the given gender.
+    ///
+    /// ```
+    /// use arabic_script::{Gender, Tafqit};
+    ///
+    /// let tafqit = Tafqit::new().with_vocalization(false);
+    ///
+    /// assert_eq!(tafqit.cardinal(13, Gender::Masculine), "ثلاثة عشر");
+    /// assert_eq!(tafqit.cardinal(13, Gender::Feminine), "ثلاث عشرة");
+    /// ```
+    pub fn cardinal(&self, number: u64, gender: Gender) -> String {
+        if number == 0 {
+            return self.write(vec![vec![Word::new(
+                "صِفْر",
+                Declension::Triptote,
+                self.case,
+            )]]);
+        }
+
+        self.write(self.number(number, gender))
+    }
+
+    /// Writes a number out, followed by the noun it counts.
+    ///
+    /// A single thing is written as the noun followed by the adjective وَاحِد, and a pair of things
+    /// as the dual of the noun.
+    pub fn count(&self, number: u64, noun: &CountedNoun) -> String {
+        self.write(self.counted(number, noun, true))
+    }
+
+    /// Writes an amount of a currency out, with its units and its subunits.
+    ///
+    /// The subunits that make up whole units are carried over to the units, unless the units would
+    /// overflow, in which case the subunits are written as given.
+    pub fn amount(&self, units: u64, subunits: u64, currency: &Currency) -> String {
+        let carried = subunits
+            .checked_div(currency.subunits)
+            .and_then(|carry| units.checked_add(carry));
+        let (units, subunits) = match (carried, subunits.checked_rem(currency.subunits)) {
+            (Some(units), Some(subunits)) => (units, subunits),
+            _ => (units, subunits),
+        };
+
+        let mut phrase = Vec::new();
+        if units > 0 || subunits == 0 {
+            phrase.extend(self.counted(units, &currency.unit, true));
+        }
+        if subunits > 0 {
+            phrase.extend(self.counted(subunits, &currency.subunit, true));
+        }
+
+        self.write(phrase)
+    }
+
+    /// Writes an ordinal number out, from the first to the ninety-ninth, or returns [`None`] if the
+    /// number is out of this range.
+    ///
+    /// ```
+    /// use arabic_script::{Gender, Tafqit};
+    ///
+    /// let tafqit = Tafqit::new();
+    ///
+    /// assert_eq!(tafqit.ordinal(1, Gender::Feminine, true).unwrap(), "الْأُولَى");
+    /// assert_eq!(tafqit.ordinal(11, Gender::Masculine, true).unwrap(), "الْحَادِيَ عَشَرَ");
+    /// assert_eq!(tafqit.ordinal(20, Gender::Masculine, true).unwrap(), "الْعِشْرُونَ");
+    /// ```
+    pub fn ordinal(&self, number: u64, gender: Gender, definite: bool) -> Option<String> {
+        let article = |word: Word| if definite { word.definite() } else { word };
+
+        let phrase = match number {
+            1..=10 => vec![vec![article(self.ordinal_unit(number, gender, false))]],
+            11..=19 => {
+                let mut unit = self.ordinal_unit(number - 10, gender, true).construct();
+                unit.case = GrammaticalCase::Accusative;
+                let ten = match gender {
+                    Gender::Masculine => "عَشَرَ",
+                    Gender::Feminine => "عَشْرَةَ",
+                };
+                vec![vec![
+                    article(unit),
+                    Word::new(ten, Declension::Indeclinable, self.case),
+                ]]
+            }
+            20..=99 => {
+                let tens = TENS[number as usize / 10 - 2];
+                let tens = article(Word::new(tens, Declension::SoundMasculinePlural, self.case));
+                match number % 10 {
+                    0 => vec![vec![tens]],
+                    unit => vec![
+                        vec![article(self.ordinal_unit(unit, gender, true))],
+                        vec![tens],
+                    ],
+                }
+            }
+            _ => return None,
+        };
+
+        Some(self.write(phrase))
+    }
+
+    /// Writes a fraction out, from the half to the tenth, or returns [`None`] if the denominator
+    /// is out of this range.
+    ///
+    /// ```
+    /// use arabic_script::Tafqit;
+    ///
+    /// let tafqit = Tafqit::new().with_vocalization(false);
+    ///
+    /// assert_eq!(tafqit.fraction(4, false).unwrap(), "ربع");
+    /// assert_eq!(tafqit.fraction(3, true).unwrap(), "الثلث");
+    /// ```
+    pub fn fraction(&self, denominator: u64, definite: bool) -> Option<String> {
+        let fraction = FRACTIONS.get((denominator as usize).checked_sub(2)?)?;
+        let mut word = Word::new(fraction, Declension::Triptote, self.case);
+        word.definite = definite;
+
+        Some(self.write(vec![vec![word]]))
+    }
+
+    /// Returns the conjuncts of a positive number, as counting things of the given gender.
+    fn number(&self, number: u64, gender: Gender) -> Vec<Vec<Word>> {
+        let mut groups = Vec::new();
+        let mut rest = number;
+        while rest > 0 {
+            groups.push(rest % 1000);
+            rest /= 1000;
+        }
+
+        let mut phrase = Vec::new();
+        for (scale, group) in groups.into_iter().enumerate().rev() {
+            match scale {
+                _ if group == 0 => {}
+                0 => phrase.extend(self.below_thousand(group, gender)),
+                _ => phrase.extend(self.counted(group, &SCALES[scale - 1], false)),
+            }
+        }
+
+        phrase
+    }
+
+    /// Returns the conjuncts of a number followed by the noun it counts.
+    fn counted(&self, number: u64, noun: &CountedNoun, explicit_one: bool) -> Vec<Vec<Word>> {
+        let singular = |case| Word::new(noun.singular, Declension::Triptote, case);
+
+        let mut phrase = match number {
+            0 => vec![vec![Word::new("صِفْر", Declension::Triptote, self.case)]],
+            1 if explicit_one => {
+                let one = match noun.gender {
+                    Gender::Masculine => "وَاحِد",
+                    Gender::Feminine => "وَاحِدَة",
+                };
+                let one = Word::new(one, Declension::Triptote, self.case);
+                return vec![vec![singular(self.case), one]];
+            }
+            1 => return vec![vec![singular(self.case)]],
+            2 => return vec![vec![Word::new(noun.singular, Declension::Dual, self.case)]],
+            _ => self.number(number, noun.gender),
+        };
+
+        let noun = match number % 100 {
+            0 => singular(GrammaticalCase::Genitive),
+            3..=10 if noun.diptote_plural => {
+                Word::new(noun.plural, Declension::Diptote, GrammaticalCase::Genitive)
+            }
+            3..=10 => Word::new(noun.plural, Declension::Triptote, GrammaticalCase::Genitive),
+            _ => singular(GrammaticalCase::Accusative),
+        };
+
+        if let Some(words) = phrase.last_mut() {
+            if noun.case == GrammaticalCase::Genitive {
+                if let Some(word) = words.last_mut() {
+                    word.construct = true;
+                }
+            }
+            words.push(noun);
+        }
+
+        phrase
+    }
+
+    /// Returns the conjuncts of a number from one to nine hundred ninety-nine.
+    fn below_thousand(&self, number: u64, gender: Gender) -> Vec<Vec<Word>> {
+        let hundreds = match number / 100 {
+            0 => None,
+            1 => Some(vec![Word::new("مِئَة", Declension::Triptote, self.case)]),
+            2 => Some(vec![Word::new("مِئَة", Declension::Dual, self.case)]),
+            h => {
+                let mut unit = self.unit(h, Gender::Feminine).construct();
+                unit.joined = true;
+                Some(vec![
+                    unit,
+                    Word::new("مِئَة", Declension::Triptote, GrammaticalCase::Genitive),
+                ])
+            }
+        };
+
+        hundreds
+            .into_iter()
+            .chain(self.below_hundred(number % 100, gender))
+            .collect()
+    }
+
+    /// Returns the conjuncts of a number below one hundred, or none for zero.
+    fn below_hundred(&self, number: u64, gender: Gender) -> Vec<Vec<Word>> {
+        let ten = match gender {
+            Gender::Masculine => "عَشَرَ",
+            Gender::Feminine => "عَشْرَةَ",
+        };
+        let ten = Word::new(ten, Declension::Indeclinable, self.case);
+
+        match number {
+            0 => vec![],
+            1..=10 => vec![vec![self.unit(number, gender)]],
+            11 => {
+                let one = match gender {
+                    Gender::Masculine => "أَحَدَ",
+                    Gender::Feminine => "إِحْدَى",
+                };
+                vec![vec![
+                    Word::new(one, Declension::Indeclinable, self.case),
+                    ten,
+                ]]
+            }
+            12 => vec![vec![self.unit(2, gender).construct(), ten]],
+            13..=19 => {
+                let mut unit = self.unit(number - 10, gender).construct();
+                unit.case = GrammaticalCase::Accusative;
+                vec![vec![unit, ten]]
+            }
+            _ => {
+                let tens = TENS[number as usize / 10 - 2];
+                let tens = Word::new(tens, Declension::SoundMasculinePlural, self.case);
+                match number % 10 {
+                    0 => vec![vec![tens]],
+                    1 if gender == Gender::Feminine => {
+                        vec![
+                            vec![Word::new("إِحْدَى", Declension::Indeclinable, self.case)],
+                            vec![tens],
+                        ]
+                    }
+                    unit => vec![vec![self.unit(unit, gender)], vec![tens]],
+                }
+            }
+        }
+    }
+
+    /// Returns a unit from one to ten, as counting things of the given gender.
+    fn unit(&self, number: u64, gender: Gender) -> Word {
+        let (stem, declension) = match (number, gender) {
+            (1, Gender::Masculine) => ("وَاحِد", Declension::Triptote),
+            (1, Gender::Feminine) => ("وَاحِدَة", Declension::Triptote),
+            (2, Gender::Masculine) => ("اثْن", Declension::Dual),
+            (2, Gender::Feminine) => ("اثْنَت", Declension::Dual),
+            (8, Gender::Feminine) => ("ثَمَانِي", Declension::Defective),
+            (_, Gender::Masculine) => (MASCULINE_UNITS[number as usize - 3], Declension::Triptote),
+            (_, Gender::Feminine) => (FEMININE_UNITS[number as usize - 3], Declension::Triptote),
+        };
+
+        Word::new(stem, declension, self.case)
+    }
+
+    /// Returns an ordinal from one to ten, describing things of the given gender.
+    ///
+    /// Within the compound ordinals, the first is written حَادٍ rather than أَوَّل.
+    fn ordinal_unit(&self, number: u64, gender: Gender, compound: bool) -> Word {
+        let (stem, declension) = match (number, gender, compound) {
+            (1, Gender::Masculine, false) => ("أَوَّل", Declension::Diptote),
+            (1, Gender::Feminine, false) => ("أُولَى", Declension::Indeclinable),
+            (1, Gender::Masculine, true) => ("حَادِي", Declension::Defective),
+            (1, Gender::Feminine, true) => ("حَادِيَة", Declension::Triptote),
+            (2, Gender::Masculine, _) => ("ثَانِي", Declension::Defective),
+            (_, Gender::Masculine, _) => (
+                MASCULINE_ORDINALS[number as usize - 2],
+                Declension::Triptote,
+            ),
+            (_, Gender::Feminine, _) => {
+                (FEMININE_ORDINALS[number as usize - 2], Declension::Triptote)
+            }
+        };
+
+        Word::new(stem, declension, self.case)
+    }
+
+    /// Inflects the words of a phrase, joining its conjuncts with وَ.
+    fn write(&self, phrase: Vec<Vec<Word>>) -> String {
+        let mut text = String::new();
+
+        for (index, words) in phrase.iter().enumerate() {
+            if index > 0 {
+                text.push_str(" وَ");
+            }
+            for (position, word) in words.iter().enumerate() {
+                if position > 0 && !words[position - 1].joined {
+                    text.push(' ');
+                }
+                text.push_str(&word.inflect());
+            }
+        }
+
+        if self.vocalized {
+            text
+        } else {
+            strip_tashkeel(&text)
+        }
+    }
+}
+
+impl Default for Tafqit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::CountedNoun;
+    use crate::Currency;
+    use crate::Gender;
+    use crate::GrammaticalCase;
+    use crate::Tafqit;
+
+    const LIRA: CountedNoun = CountedNoun::new(Gender::Feminine, "لَيْرَة", "لَيْرَات");
+
+    #[test]
+    fn reverses_the_gender_of_units() {
+        let tafqit = Tafqit::new();
+
+        assert_eq!(tafqit.cardinal(3, Gender::Masculine), "ثَلَاثَةٌ");
+        assert_eq!(tafqit.cardinal(3, Gender::Feminine), "ثَلَاثٌ");
+        assert_eq!(tafqit.count(3, &LIRA), "ثَلَاثُ لَيْرَاتٍ");
+        assert_eq!(tafqit.count(8, &LIRA), "ثَمَانِي لَيْرَاتٍ");
+        assert_eq!(tafqit.count(10, &Currency::SAUDI_RIYAL.unit), "عَشَرَةُ رِيَالَاتٍ");
+        assert_eq!(tafqit.count(18, &LIRA), "ثَمَانِيَ عَشْرَةَ لَيْرَةً");
+    }
+
+    #[test]
+    fn writes_ones_and_twos() {
+        let riyal = Currency::SAUDI_RIYAL.unit;
+        let genitive = Tafqit::new().with_case(GrammaticalCase::Genitive);
+
+        assert_eq!(Tafqit::new().count(1, &riyal), "رِيَالٌ وَاحِدٌ");
+        assert_eq!(Tafqit::new().count(2, &LIRA), "لَيْرَتَانِ");
+        assert_eq!(genitive.count(2, &riyal), "رِيَالَيْنِ");
+        assert_eq!(genitive.count(12, &riyal), "اثْنَيْ عَشَرَ رِيَالًا");
+        assert_eq!(Tafqit::new().count(21, &LIRA), "إِحْدَى وَعِشْرُونَ لَيْرَةً");
+        assert_eq!(Tafqit::new().count(22, &riyal), "اثْنَانِ وَعِشْرُونَ رِيَالًا");
+    }
+
+    #[test]
+    fn governs_the_counted_noun() {
+        let tafqit = Tafqit::new();
+        let dirham = Currency::EMIRATI_DIRHAM.unit;
+
+        assert_eq!(tafqit.count(5, &dirham), "خَمْسَةُ دَرَاهِمَ");
+        assert_eq!(tafqit.count(100, &dirham), "مِئَةُ دِرْهَمٍ");
+        assert_eq!(tafqit.count(300, &dirham), "ثَلَاثُمِئَةِ دِرْهَمٍ");
+        assert_eq!(tafqit.count(2000, &dirham), "أَلْفَا دِرْهَمٍ");
+        assert_eq!(tafqit.count(11000, &dirham), "أَحَدَ عَشَرَ أَلْفَ دِرْهَمٍ");
+        assert_eq!(tafqit.count(3_000_000, &dirham), "ثَلَاثَةُ مَلَايِينِ دِرْهَمٍ");
+        assert_eq!(tafqit.count(0, &dirham), "صِفْرُ دِرْهَمٍ");
+    }
+
+    #[test]
+    fn inflects_the_whole_phrase() {
+        let tafqit = Tafqit::new().with_case(GrammaticalCase::Accusative);
+
+        assert_eq!(
+            tafqit.count(3250, &Currency::SAUDI_RIYAL.unit),
+            "ثَلَاثَةَ آلَافٍ وَمِئَتَيْنِ وَخَمْسِينَ رِيَالًا"
+        );
+    }
+
+    #[test]
+    fn writes_large_numbers_and_amounts() {
+        let tafqit = Tafqit::new().with_vocalization(false);
+
+        assert_eq!(
+            tafqit.cardinal(2_000_003_000, Gender::Masculine),
+            "ملياران وثلاثة آلاف"
+        );
+        assert_eq!(
+            tafqit.cardinal(u64::MAX, Gender::Masculine),
+            "ثمانية عشر كوينتليونا وأربعمئة وستة وأربعون كوادرليونا وسبعمئة وأربعة وأربعون \
+             ترليونا وثلاثة وسبعون مليارا وسبعمئة وتسعة ملايين وخمسمئة وواحد وخمسون ألفا \
+             وستمئة وخمسة عشر"
+        );
+        assert_eq!(
+            tafqit.amount(1, 1250, &Currency::KUWAITI_DINAR),
+            "ديناران ومئتان وخمسون فلسا"
+        );
+        assert_eq!(tafqit.amount(0, 5, &Currency::EGYPTIAN_POUND), "خمسة قروش");
+        assert!(tafqit
+            .amount(u64::MAX, 1000, &Currency::SAUDI_RIYAL)
+            .ends_with("وخمسة عشر ريالا وألف هللة"));
+    }
+
+    #[test]
+    fn writes_ordinals() {
+        let tafqit = Tafqit::new();
+        let ordinal = |number, gender, definite| tafqit.ordinal(number, gender, definite).unwrap();
+
+        assert_eq!(ordinal(1, Gender::Masculine, true), "الْأَوَّلُ");
+        assert_eq!(ordinal(2, Gender::Masculine, false), "ثَانٍ");
+        assert_eq!(ordinal(3, Gender::Feminine, true), "الثَّالِثَةُ");
+        assert_eq!(ordinal(12, Gender::Feminine, true), "الثَّانِيَةَ عَشْرَةَ");
+        assert_eq!(ordinal(21, Gender::Masculine, true), "الْحَادِي وَالْعِشْرُونَ");
+        assert_eq!(ordinal(21, Gender::Masculine, false), "حَادٍ وَعِشْرُونَ");
+        assert_eq!(ordinal(35, Gender::Feminine, true), "الْخَامِسَةُ وَالثَّلَاثُونَ");
+        assert_eq!(tafqit.ordinal(0, Gender::Masculine, true), None);
+        assert_eq!(tafqit.ordinal(100, Gender::Masculine, true), None);
+    }
+
+    #[test]
+    fn writes_fractions() {
+        let tafqit = Tafqit::new().with_case(GrammaticalCase::Genitive);
+
+        assert_eq!(tafqit.fraction(2, true).unwrap(), "النِّصْفِ");
+        assert_eq!(tafqit.fraction(10, false).unwrap(), "عُشُرٍ");
+        assert_eq!(tafqit.fraction(1, false), None);
+        assert_eq!(tafqit.fraction(11, false), None);
+    }
+}
@@ -0,0 +1,151 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicCharacter;
+use crate::GrammaticalCase;
+
+/// Represents the way a word takes the endings of the grammatical cases.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Declension {
+    /// A noun taking the three vowels, and a tanween when indefinite.
+    Triptote,
+
+    /// A noun taking no tanween and no kasra when indefinite, such as مَلَايِين.
+    Diptote,
+
+    /// A noun ending with a yeh, that drops it before a kasratan, such as ثَمَانِي.
+    Defective,
+
+    /// A noun in the dual, such as مِئَتَانِ.
+    Dual,
+
+    /// A noun in the sound masculine plural, such as عِشْرُونَ.
+    SoundMasculinePlural,
+
+    /// A word whose ending never changes, such as the compound numbers from eleven to nineteen.
+    Indeclinable,
+}
+
+/// Represents a word of a number phrase, before it is inflected.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Word {
+    /// The vocalized word, without its case ending.
+    pub(crate) stem: &'static str,
+
+    /// The way the word takes the endings of the grammatical cases.
+    pub(crate) declension: Declension,
+
+    /// The case of the word.
+    pub(crate) case: GrammaticalCase,
+
+    /// Whether the word is followed by a noun in the genitive it governs, and takes no tanween.
+    pub(crate) construct: bool,
+
+    /// Whether the word takes the definite article, and no tanween.
+    pub(crate) definite: bool,
+
+    /// Whether the word is written attached to the following one, as in ثَلَاثُمِئَةٍ.
+    pub(crate) joined: bool,
+}
+
+impl Word {
+    /// Returns a new indefinite word, that is neither in the construct state nor joined to the next
+    /// one.
+    pub(crate) fn new(stem: &'static str, declension: Declension, case: GrammaticalCase) -> Self {
+        Self {
+            stem,
+            declension,
+            case,
+            construct: false,
+            definite: false,
+            joined: false,
+        }
+    }
+
+    /// Returns the word in the construct state.
+    pub(crate) fn construct(mut self) -> Self {
+        self.construct = true;
+        self
+    }
+
+    /// Returns the word with the definite article.
+    pub(crate) fn definite(mut self) -> Self {
+        self.definite = true;
+        self
+    }
+
+    /// Returns the vocalized word, with its case ending.
+    pub(crate) fn inflect(&self) -> String {
+        let mut word = self.stem.to_string();
+        let case = self.case as usize;
+
+        let bare = self.construct || self.definite;
+
+        let ending = match (self.declension, bare) {
+            (Declension::Triptote | Declension::Diptote, true) => {
+                ["\u{064F}", "\u{064E}", "\u{0650}"][case]
+            }
+            (Declension::Triptote, false) if word.ends_with('ة') => {
+                ["\u{064C}", "\u{064B}", "\u{064D}"][case]
+            }
+            (Declension::Triptote, false) => ["\u{064C}", "\u{064B}ا", "\u{064D}"][case],
+            (Declension::Diptote, false) => ["\u{064F}", "\u{064E}", "\u{064E}"][case],
+            (Declension::Defective, true) => ["", "\u{064E}", ""][case],
+            (Declension::Defective, false) if self.case == GrammaticalCase::Accusative => {
+                "\u{064B}ا"
+            }
+            (Declension::Defective, false) => {
+                word.pop();
+                word = word.trim_end_matches('\u{0650}').to_string();
+                "\u{064D}"
+            }
+            (Declension::Dual, _) => {
+                if let Some(stem) = word.strip_suffix('ة') {
+                    word = format!("{}ت", stem);
+                }
+                if self.construct {
+                    ["\u{064E}ا", "\u{064E}يْ", "\u{064E}يْ"][case]
+                } else {
+                    ["\u{064E}انِ", "\u{064E}يْنِ", "\u{064E}يْنِ"][case]
+                }
+            }
+            (Declension::SoundMasculinePlural, _) if self.construct => {
+                ["\u{064F}و", "\u{0650}ي", "\u{0650}ي"][case]
+            }
+            (Declension::SoundMasculinePlural, _) => {
+                ["\u{064F}ونَ", "\u{0650}ينَ", "\u{0650}ينَ"][case]
+            }
+            (Declension::Indeclinable, _) => "",
+        };
+
+        word.push_str(ending);
+
+        if self.definite {
+            word = with_definite_article(&word);
+        }
+        word
+    }
+}
+
+/// Prefixes a vocalized word with the definite article, assimilating its lam into a sun letter.
+fn with_definite_article(word: &str) -> String {
+    let mut characters = word.chars();
+    let first = characters.next().unwrap_or_default();
+
+    if ArabicCharacter::try_from(first).is_ok_and(|c| c.is_sun_letter()) {
+        format!("ال{}\u{0651}{}", first, characters.as_str())
+    } else {
+        format!("الْ{}", word)
+    }
+}
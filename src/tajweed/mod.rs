@@ -0,0 +1,29 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+//! The **Tajweed** module annotates vocalized text with the rules of Quranic recitation, and
+//! renders the annotations.
+
+pub use tajweed_annotator::annotate_tajweed;
+pub use tajweed_annotator::annotate_tajweed_word;
+pub use tajweed_html_renderer::TajweedHtmlRenderer;
+pub use tajweed_rule::TajweedRule;
+pub use tajweed_scheme::TajweedScheme;
+pub use tajweed_span::TajweedSpan;
+
+mod tajweed_annotator;
+mod tajweed_html_renderer;
+mod tajweed_rule;
+mod tajweed_scheme;
+mod tajweed_span;
@@ -0,0 +1,293 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::core::LetterCluster;
+use crate::core::TextSegment;
+use crate::ArabicCharacter;
+use crate::ArabicDamma;
+use crate::ArabicFatha;
+use crate::ArabicFathatan;
+use crate::ArabicKasra;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterAlefMaksura;
+use crate::ArabicLetterBeh;
+use crate::ArabicLetterLam;
+use crate::ArabicLetterMeem;
+use crate::ArabicLetterNoon;
+use crate::ArabicLetterReh;
+use crate::ArabicLetterWaw;
+use crate::ArabicLetterYeh;
+use crate::ArabicSukun;
+use crate::ArticulationRegion;
+use crate::TajweedRule;
+use crate::TajweedSpan;
+
+/// Annotates a vocalized word with the rules of tajweed applying to it.
+///
+/// The ranges of the spans are indices within the word. The rules spanning two words, such as
+/// the idgham or the separated madd, can only be found by [`annotate_tajweed`].
+pub fn annotate_tajweed_word(word: &[ArabicCharacter]) -> Vec<TajweedSpan> {
+    annotate(&[(0, word)])
+}
+
+/// Annotates a vocalized text with the rules of tajweed applying to it, in the order of the spans.
+///
+/// The ranges of the spans are indices of characters within the text, and the text is read as if
+/// the recitation paused on its last letter:
+///
+/// ```
+/// use arabic_script::{annotate_tajweed, TajweedRule};
+///
+/// let spans = annotate_tajweed("مِنْ بَعْدِ");
+///
+/// assert_eq!(spans[0].rule, TajweedRule::Iqlab);
+/// assert_eq!(spans[0].range, 2..7);
+/// assert_eq!(spans[1].rule, TajweedRule::Qalqala);
+/// assert_eq!(spans[1].range, 9..11);
+/// ```
+pub fn annotate_tajweed(text: &str) -> Vec<TajweedSpan> {
+    let segments = TextSegment::read(text);
+    let words: Vec<(usize, &[ArabicCharacter])> = segments
+        .iter()
+        .filter_map(|segment| match segment {
+            TextSegment::Word { start, characters } => Some((*start, characters.as_slice())),
+            TextSegment::Other(_) => None,
+        })
+        .collect();
+
+    annotate(&words)
+}
+
+/// Annotates a sequence of words, each given with the index of its first character.
+fn annotate(words: &[(usize, &[ArabicCharacter])]) -> Vec<TajweedSpan> {
+    let clusters: Vec<(usize, LetterCluster)> = words
+        .iter()
+        .enumerate()
+        .flat_map(|(word, (start, characters))| {
+            LetterCluster::read(characters)
+                .into_iter()
+                .map(move |mut cluster| {
+                    cluster.start += start;
+                    cluster.end += start;
+                    (word, cluster)
+                })
+        })
+        .collect();
+
+    let mut spans = Vec::new();
+    for (index, &(word, cluster)) in clusters.iter().enumerate() {
+        let previous = index
+            .checked_sub(1)
+            .map(|i| clusters[i])
+            .filter(|(w, _)| *w == word)
+            .map(|(_, c)| c);
+        let next = clusters.get(index + 1).copied();
+        let pause = next.is_none();
+
+        let rules = [
+            nunation(&clusters, index),
+            meem(cluster, next.map(|(_, c)| c)),
+            ghunnah(cluster),
+            qalqala(cluster, pause),
+            previous.and_then(|previous| madd(previous, cluster, word, next)),
+        ];
+        spans.extend(rules.into_iter().flatten());
+    }
+
+    spans.sort_by_key(|span| (span.range.start, span.range.end));
+    spans
+}
+
+/// Returns the span of the rule applying to a noon sakinah or a tanween, if the cluster is one.
+fn nunation(clusters: &[(usize, LetterCluster)], index: usize) -> Option<TajweedSpan> {
+    let (word, cluster) = clusters[index];
+    let tanween = cluster.vowel.is_some_and(|v| v.is_tanween());
+    let sakinah = cluster.letter == ArabicLetterNoon && cluster.is_vowelless() && !cluster.shadda;
+    if !tanween && !sakinah {
+        return None;
+    }
+
+    // The alef following a fathatan only seats it, and is not pronounced.
+    let mut following = index + 1;
+    let seated = clusters.get(following).is_some_and(|(_, c)| {
+        matches!(c.letter, ArabicLetterAlef | ArabicLetterAlefMaksura) && c.vowel.is_none()
+    });
+    if cluster.has_vowel(ArabicFathatan) && seated {
+        following += 1;
+    }
+
+    let (next_word, next) = *clusters.get(following)?;
+    let letter = next.letter;
+    let rule = match letter {
+        _ if letter.makhraj().map(|m| m.region()) == Some(ArticulationRegion::Throat) => {
+            TajweedRule::Izhar
+        }
+        ArabicLetterYeh | ArabicLetterNoon | ArabicLetterMeem | ArabicLetterWaw => {
+            if next_word == word {
+                TajweedRule::Izhar
+            } else {
+                TajweedRule::IdghamWithGhunnah
+            }
+        }
+        ArabicLetterLam | ArabicLetterReh => TajweedRule::IdghamWithoutGhunnah,
+        ArabicLetterBeh => TajweedRule::Iqlab,
+        ArabicLetterAlef | ArabicLetterAlefMaksura => return None,
+        _ => TajweedRule::Ikhfa,
+    };
+
+    Some(TajweedSpan {
+        rule,
+        range: cluster.start..next.end,
+    })
+}
+
+/// Returns the span of the rule applying to a meem sakinah, if the cluster is one.
+fn meem(cluster: LetterCluster, next: Option<LetterCluster>) -> Option<TajweedSpan> {
+    if cluster.letter != ArabicLetterMeem || !cluster.is_vowelless() || cluster.shadda {
+        return None;
+    }
+
+    let next = next?;
+    let rule = match next.letter {
+        ArabicLetterMeem => TajweedRule::IdghamShafawi,
+        ArabicLetterBeh => TajweedRule::IkhfaShafawi,
+        _ => TajweedRule::IzharShafawi,
+    };
+
+    Some(TajweedSpan {
+        rule,
+        range: cluster.start..next.end,
+    })
+}
+
+/// Returns the span of the ghunnah, if the cluster is a noon or a meem carrying a shadda.
+fn ghunnah(cluster: LetterCluster) -> Option<TajweedSpan> {
+    let nasal = matches!(cluster.letter, ArabicLetterNoon | ArabicLetterMeem);
+
+    (nasal && cluster.shadda).then_some(TajweedSpan {
+        rule: TajweedRule::Ghunnah,
+        range: cluster.start..cluster.end,
+    })
+}
+
+/// Returns the span of the qalqala, if the cluster is a letter of قطب جد carrying a sukun, or one
+/// on which the recitation pauses.
+fn qalqala(cluster: LetterCluster, pause: bool) -> Option<TajweedSpan> {
+    let echoes = cluster.letter.sifat().is_some_and(|s| s.qalqala);
+    let still = pause || (cluster.has_vowel(ArabicSukun) && !cluster.shadda);
+
+    (echoes && still).then_some(TajweedSpan {
+        rule: TajweedRule::Qalqala,
+        range: cluster.start..cluster.end,
+    })
+}
+
+/// Returns the span of the madd, if the cluster is a long vowel lengthening the vowel of the
+/// cluster preceding it within its word.
+fn madd(
+    previous: LetterCluster,
+    cluster: LetterCluster,
+    word: usize,
+    next: Option<(usize, LetterCluster)>,
+) -> Option<TajweedSpan> {
+    let lengthens = match cluster.letter {
+        ArabicLetterAlef | ArabicLetterAlefMaksura => previous.has_vowel(ArabicFatha),
+        ArabicLetterWaw => previous.has_vowel(ArabicDamma),
+        ArabicLetterYeh => previous.has_vowel(ArabicKasra),
+        _ => false,
+    };
+    if !lengthens || !cluster.is_vowelless() || cluster.shadda {
+        return None;
+    }
+
+    let rule = match next {
+        Some((w, next)) if w == word && next.letter.has_hamza() => TajweedRule::ConnectedMadd,
+        Some((w, next)) if w == word && (next.shadda || next.has_vowel(ArabicSukun)) => {
+            TajweedRule::NecessaryMadd
+        }
+        Some((_, next)) if next.letter.has_hamza() => TajweedRule::SeparatedMadd,
+        _ => TajweedRule::NaturalMadd,
+    };
+
+    Some(TajweedSpan {
+        rule,
+        range: previous.start..cluster.end,
+    })
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::annotate_tajweed;
+    use crate::annotate_tajweed_word;
+    use crate::ArabicCharacter;
+    use crate::TajweedRule;
+    use crate::TajweedRule::*;
+
+    fn rules(text: &str) -> Vec<TajweedRule> {
+        annotate_tajweed(text)
+            .into_iter()
+            .map(|span| span.rule)
+            .collect()
+    }
+
+    #[test]
+    fn applies_the_rules_of_nunation() {
+        assert_eq!(rules("مِنْ هَادٍ"), [Izhar, NaturalMadd, Qalqala]);
+        assert_eq!(rules("مَنْ يَقُولُ"), [IdghamWithGhunnah, NaturalMadd]);
+        assert_eq!(rules("هُدًى لِلْمُتَّقِينَ")[0], IdghamWithoutGhunnah);
+        assert_eq!(rules("سَمِيعٌ بَصِيرٌ")[1], Iqlab);
+        assert_eq!(rules("أَنْتُمْ")[0], Ikhfa);
+        assert_eq!(rules("الدُّنْيَا")[0], Izhar);
+        assert_eq!(rules("عَلِيمًا حَكِيمًا")[..2], [NaturalMadd, Izhar]);
+    }
+
+    #[test]
+    fn applies_the_rules_of_meem_sakinah() {
+        assert_eq!(rules("لَهُمْ مَا"), [IdghamShafawi, NaturalMadd]);
+        assert_eq!(rules("هُمْ بِهِ"), [IkhfaShafawi]);
+        assert_eq!(rules("لَكُمْ دِينُكُمْ")[0], IzharShafawi);
+    }
+
+    #[test]
+    fn finds_qalqala_and_ghunnah() {
+        assert_eq!(rules("يَقْطَعُونَ")[0], Qalqala);
+        assert_eq!(rules("إِنَّ"), [Ghunnah]);
+        assert_eq!(rules("ثُمَّ")[0], Ghunnah);
+        assert_eq!(rules("الْفَلَقِ"), [Qalqala]);
+    }
+
+    #[test]
+    fn classifies_the_madd() {
+        assert_eq!(rules("جَاءَ"), [ConnectedMadd]);
+        assert_eq!(rules("يَا أَيُّهَا")[0], SeparatedMadd);
+        assert_eq!(rules("الضَّالِّينَ")[..2], [NecessaryMadd, NaturalMadd]);
+        assert_eq!(rules("قَالُوا"), [NaturalMadd, NaturalMadd]);
+    }
+
+    #[test]
+    fn reports_the_ranges_within_a_word() {
+        let word: Vec<ArabicCharacter> = "يَنْصُرُ"
+            .chars()
+            .map(|c| ArabicCharacter::try_from(c).unwrap())
+            .collect();
+        let spans = annotate_tajweed_word(&word);
+
+        assert_eq!(spans[0].rule, Ikhfa);
+        assert_eq!(spans[0].range, 2..6);
+    }
+}
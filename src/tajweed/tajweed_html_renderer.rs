@@ -0,0 +1,282 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicCharacter;
+use crate::ArabicTatweel;
+use crate::TajweedRule;
+use crate::TajweedScheme;
+use crate::TajweedSpan;
+
+/// Renders text annotated with the rules of tajweed as HTML.
+///
+/// The text is wrapped in a right-to-left block, and each run of characters to which a rule
+/// applies in a `<span>` carrying the class, and by default the colour, of the rule:
+///
+/// ```
+/// use arabic_script::{annotate_tajweed, TajweedHtmlRenderer};
+///
+/// let text = "إِنَّ";
+/// let html = TajweedHtmlRenderer::new().render(text, &annotate_tajweed(text));
+///
+/// assert_eq!(
+///     html,
+///     "<div dir=\"rtl\" lang=\"ar\">إِ<span class=\"tajweed-ghunnah\" \
+///      style=\"color: #FF7E1E\">نَّ</span></div>"
+/// );
+/// ```
+///
+/// A span never separates a letter from its diacritical marks: spans starting or ending within a
+/// letter are widened to include it whole. Where spans overlap, the characters they share are
+/// rendered with the shortest of them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TajweedHtmlRenderer {
+    scheme: TajweedScheme,
+    lang: &'static str,
+    inline_styles: bool,
+}
+
+impl TajweedHtmlRenderer {
+    /// Returns a new renderer, that colours the spans inline with the default scheme, and marks the
+    /// text as Arabic.
+    pub fn new() -> Self {
+        Self {
+            scheme: TajweedScheme::mushaf(),
+            lang: "ar",
+            inline_styles: true,
+        }
+    }
+
+    /// Returns the renderer, with the given scheme.
+    pub fn with_scheme(mut self, scheme: TajweedScheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Returns the renderer, marking the text with the given language tag.
+    pub fn with_lang(mut self, lang: &'static str) -> Self {
+        self.lang = lang;
+        self
+    }
+
+    /// Returns the renderer, colouring the spans inline, or leaving their colours to a stylesheet
+    /// such as [`TajweedScheme::stylesheet()`].
+    pub fn with_inline_styles(mut self, inline_styles: bool) -> Self {
+        self.inline_styles = inline_styles;
+        self
+    }
+
+    /// Renders a text with the spans of the rules applying to it, whose ranges are indices of
+    /// characters within the text.
+    ///
+    /// The spans are clamped to the text, and those left empty are ignored.
+    pub fn render(&self, text: &str, spans: &[TajweedSpan]) -> String {
+        let characters: Vec<char> = text.chars().collect();
+        let mut rules: Vec<Option<(usize, TajweedRule)>> = vec![None; characters.len()];
+
+        for span in spans {
+            let mut start = span.range.start.min(characters.len());
+            let mut end = span.range.end.min(characters.len());
+            if start >= end {
+                continue;
+            }
+
+            while start > 0 && is_attached(characters[start]) {
+                start -= 1;
+            }
+            while end < characters.len() && is_attached(characters[end]) {
+                end += 1;
+            }
+
+            for rule in &mut rules[start..end] {
+                if rule.is_none_or(|(length, _)| end - start <= length) {
+                    *rule = Some((end - start, span.rule));
+                }
+            }
+        }
+
+        let mut html = String::from("<div dir=\"rtl\" lang=\"");
+        escape_all(self.lang, &mut html);
+        html.push_str("\">");
+        let mut current = None;
+
+        for (character, rule) in characters.iter().zip(&rules) {
+            let rule = rule.map(|(_, rule)| rule);
+            if rule != current {
+                if current.is_some() {
+                    html.push_str("</span>");
+                }
+                if let Some(rule) = rule {
+                    html.push_str(&self.open(rule));
+                }
+                current = rule;
+            }
+            escape(*character, &mut html);
+        }
+
+        if current.is_some() {
+            html.push_str("</span>");
+        }
+        html.push_str("</div>");
+        html
+    }
+
+    /// Returns the opening tag of the span of a rule.
+    fn open(&self, rule: TajweedRule) -> String {
+        let mut tag = String::from("<span class=\"");
+        escape_all(self.scheme.class(rule), &mut tag);
+
+        if let Some(colour) = self.scheme.colour(rule).filter(|_| self.inline_styles) {
+            tag.push_str("\" style=\"color: ");
+            escape_all(colour, &mut tag);
+        }
+
+        tag.push_str("\">");
+        tag
+    }
+}
+
+impl Default for TajweedHtmlRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns `true` if the character attaches to the letter preceding it: a diacritical mark or a
+/// tatweel.
+fn is_attached(character: char) -> bool {
+    ArabicCharacter::try_from(character).is_ok_and(|c| c.is_diacritic() || c == ArabicTatweel)
+}
+
+/// Pushes a text to the HTML, escaping its characters if needed.
+fn escape_all(text: &str, html: &mut String) {
+    for character in text.chars() {
+        escape(character, html);
+    }
+}
+
+/// Pushes a character to the HTML, escaping it if needed.
+fn escape(character: char, html: &mut String) {
+    match character {
+        '&' => html.push_str("&amp;"),
+        '<' => html.push_str("&lt;"),
+        '>' => html.push_str("&gt;"),
+        '"' => html.push_str("&quot;"),
+        c => html.push(c),
+    }
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::TajweedHtmlRenderer;
+    use crate::TajweedRule;
+    use crate::TajweedScheme;
+    use crate::TajweedSpan;
+
+    fn span(rule: TajweedRule, start: usize, end: usize) -> TajweedSpan {
+        TajweedSpan {
+            rule,
+            range: start..end,
+        }
+    }
+
+    #[test]
+    fn widens_spans_to_whole_letters() {
+        let renderer = TajweedHtmlRenderer::new().with_inline_styles(false);
+
+        assert_eq!(
+            renderer.render("قَالَ", &[span(TajweedRule::NaturalMadd, 1, 3)]),
+            "<div dir=\"rtl\" lang=\"ar\"><span class=\"tajweed-madd-natural\">قَا</span>\
+             لَ</div>"
+        );
+    }
+
+    #[test]
+    fn renders_the_shortest_of_overlapping_spans() {
+        let scheme = TajweedScheme::mushaf().with_class(TajweedRule::Qalqala, "q");
+        let renderer = TajweedHtmlRenderer::new()
+            .with_scheme(scheme)
+            .with_inline_styles(false)
+            .with_lang("ar-SA");
+        let spans = [
+            span(TajweedRule::Ikhfa, 0, 4),
+            span(TajweedRule::Qalqala, 2, 4),
+        ];
+
+        assert_eq!(
+            renderer.render("نْقُ", &spans),
+            "<div dir=\"rtl\" lang=\"ar-SA\"><span class=\"tajweed-ikhfa\">نْ</span>\
+             <span class=\"q\">قُ</span></div>"
+        );
+    }
+
+    #[test]
+    fn ignores_empty_spans() {
+        let renderer = TajweedHtmlRenderer::new();
+        let spans = [
+            span(TajweedRule::Ghunnah, 10, 12),
+            span(TajweedRule::Ghunnah, 2, 2),
+            span(TajweedRule::Ghunnah, 4, 1),
+        ];
+
+        assert_eq!(
+            renderer.render("بِسْمِ", &spans),
+            "<div dir=\"rtl\" lang=\"ar\">بِسْمِ</div>"
+        );
+    }
+
+    #[test]
+    fn escapes_markup() {
+        let renderer = TajweedHtmlRenderer::new();
+
+        assert_eq!(
+            renderer.render("<b>&", &[]),
+            "<div dir=\"rtl\" lang=\"ar\">&lt;b&gt;&amp;</div>"
+        );
+    }
+
+    #[test]
+    fn escapes_attributes() {
+        let scheme = TajweedScheme::mushaf()
+            .with_class(TajweedRule::Ghunnah, "a\"b")
+            .with_colour(TajweedRule::Ghunnah, Some("red\" onclick=\"x()"));
+        let renderer = TajweedHtmlRenderer::new()
+            .with_scheme(scheme)
+            .with_lang("ar<&>");
+
+        assert_eq!(
+            renderer.render("نّ", &[span(TajweedRule::Ghunnah, 0, 2)]),
+            "<div dir=\"rtl\" lang=\"ar&lt;&amp;&gt;\"><span class=\"a&quot;b\" \
+             style=\"color: red&quot; onclick=&quot;x()\">نّ</span></div>"
+        );
+    }
+
+    #[test]
+    fn configures_colours() {
+        let scheme = TajweedScheme::mushaf()
+            .with_colour(TajweedRule::Izhar, Some("teal"))
+            .with_colour(TajweedRule::Ghunnah, None);
+        let renderer = TajweedHtmlRenderer::new().with_scheme(scheme.clone());
+
+        assert_eq!(scheme.colour(TajweedRule::Izhar), Some("teal"));
+        assert!(!scheme.stylesheet().contains(".tajweed-ghunnah "));
+        assert_eq!(
+            renderer.render("مَنْ", &[span(TajweedRule::Ghunnah, 2, 4)]),
+            "<div dir=\"rtl\" lang=\"ar\">مَ<span class=\"tajweed-ghunnah\">نْ</span></div>"
+        );
+    }
+}
@@ -0,0 +1,62 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+/// Represents a rule of tajweed applying to a span of vocalized text.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TajweedRule {
+    /// A noon sakinah or a tanween pronounced clearly before a letter of the throat (الإظهار).
+    Izhar,
+
+    /// A noon sakinah or a tanween merged with a nasal sound into the ي ن م و starting the next
+    /// word (الإدغام بغنة).
+    IdghamWithGhunnah,
+
+    /// A noon sakinah or a tanween merged without a nasal sound into the ل or ر starting the next
+    /// word (الإدغام بغير غنة).
+    IdghamWithoutGhunnah,
+
+    /// A noon sakinah or a tanween turned into a meem before a ب (الإقلاب).
+    Iqlab,
+
+    /// A noon sakinah or a tanween hidden with a nasal sound before any other letter (الإخفاء).
+    Ikhfa,
+
+    /// A meem sakinah merged into the meem following it (الإدغام الشفوي).
+    IdghamShafawi,
+
+    /// A meem sakinah hidden with a nasal sound before a ب (الإخفاء الشفوي).
+    IkhfaShafawi,
+
+    /// A meem sakinah pronounced clearly before any other letter (الإظهار الشفوي).
+    IzharShafawi,
+
+    /// A letter of قطب جد echoing as it carries a sukun, or as the recitation pauses on it
+    /// (القلقلة).
+    Qalqala,
+
+    /// A long vowel lasting two counts (المد الطبيعي).
+    NaturalMadd,
+
+    /// A long vowel followed by a hamza within the same word (المد المتصل).
+    ConnectedMadd,
+
+    /// A long vowel ending a word, followed by a hamza starting the next word (المد المنفصل).
+    SeparatedMadd,
+
+    /// A long vowel followed by a sukun or a shadda within the same word (المد اللازم).
+    NecessaryMadd,
+
+    /// A noon or a meem carrying a shadda, held with a nasal sound (الغنة).
+    Ghunnah,
+}
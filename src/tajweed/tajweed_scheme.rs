@@ -0,0 +1,149 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use std::collections::HashMap;
+
+use crate::TajweedRule;
+
+/// The rules of tajweed, with their default class and colour.
+const RULES: [(TajweedRule, &str, Option<&str>); 14] = [
+    (TajweedRule::Izhar, "tajweed-izhar", None),
+    (
+        TajweedRule::IdghamWithGhunnah,
+        "tajweed-idgham-ghunnah",
+        Some("#169200"),
+    ),
+    (
+        TajweedRule::IdghamWithoutGhunnah,
+        "tajweed-idgham",
+        Some("#AAAAAA"),
+    ),
+    (TajweedRule::Iqlab, "tajweed-iqlab", Some("#26BFFD")),
+    (TajweedRule::Ikhfa, "tajweed-ikhfa", Some("#9400A8")),
+    (
+        TajweedRule::IdghamShafawi,
+        "tajweed-idgham-shafawi",
+        Some("#58B800"),
+    ),
+    (
+        TajweedRule::IkhfaShafawi,
+        "tajweed-ikhfa-shafawi",
+        Some("#D500B7"),
+    ),
+    (TajweedRule::IzharShafawi, "tajweed-izhar-shafawi", None),
+    (TajweedRule::Qalqala, "tajweed-qalqala", Some("#DD0008")),
+    (
+        TajweedRule::NaturalMadd,
+        "tajweed-madd-natural",
+        Some("#537FFF"),
+    ),
+    (
+        TajweedRule::ConnectedMadd,
+        "tajweed-madd-connected",
+        Some("#2144C1"),
+    ),
+    (
+        TajweedRule::SeparatedMadd,
+        "tajweed-madd-separated",
+        Some("#4050FF"),
+    ),
+    (
+        TajweedRule::NecessaryMadd,
+        "tajweed-madd-necessary",
+        Some("#000EBC"),
+    ),
+    (TajweedRule::Ghunnah, "tajweed-ghunnah", Some("#FF7E1E")),
+];
+
+/// Represents the classes and colours with which the rules of tajweed are rendered.
+///
+/// The default scheme follows the palette of the tajweed edition of the alquran.cloud API, used by
+/// many web Mushafs: shades of blue for the madd, from the natural to the necessary, red for the
+/// qalqala, orange for the ghunnah, and grey for the letters merged without a trace. The izhar is
+/// left uncoloured.
+///
+/// The printed colour-coded Mushafs, such as that of Dar al-Maarifa, use other colours, red for
+/// the madd and blue for the qalqala among them, which can be set with
+/// [`with_colour()`](Self::with_colour).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TajweedScheme {
+    classes: HashMap<TajweedRule, &'static str>,
+    colours: HashMap<TajweedRule, &'static str>,
+}
+
+impl TajweedScheme {
+    /// Returns the scheme with the palette of the tajweed edition of the alquran.cloud API.
+    pub fn mushaf() -> Self {
+        Self {
+            classes: RULES
+                .iter()
+                .map(|(rule, class, _)| (*rule, *class))
+                .collect(),
+            colours: RULES
+                .iter()
+                .filter_map(|(rule, _, colour)| Some((*rule, (*colour)?)))
+                .collect(),
+        }
+    }
+
+    /// Returns the scheme, with the given class for a rule.
+    pub fn with_class(mut self, rule: TajweedRule, class: &'static str) -> Self {
+        self.classes.insert(rule, class);
+        self
+    }
+
+    /// Returns the scheme, with the given CSS colour for a rule, or with no colour at all.
+    pub fn with_colour(mut self, rule: TajweedRule, colour: Option<&'static str>) -> Self {
+        match colour {
+            Some(colour) => self.colours.insert(rule, colour),
+            None => self.colours.remove(&rule),
+        };
+        self
+    }
+
+    /// Returns the class of a rule.
+    pub fn class(&self, rule: TajweedRule) -> &'static str {
+        self.classes[&rule]
+    }
+
+    /// Returns the CSS colour of a rule, or [`None`] if the rule is left uncoloured.
+    pub fn colour(&self, rule: TajweedRule) -> Option<&'static str> {
+        self.colours.get(&rule).copied()
+    }
+
+    /// Returns a stylesheet colouring the class of each rule.
+    ///
+    /// ```
+    /// use arabic_script::TajweedScheme;
+    ///
+    /// let stylesheet = TajweedScheme::mushaf().stylesheet();
+    ///
+    /// assert!(stylesheet.contains(".tajweed-ghunnah { color: #FF7E1E; }"));
+    /// ```
+    pub fn stylesheet(&self) -> String {
+        RULES
+            .iter()
+            .filter_map(|(rule, _, _)| {
+                let colour = self.colour(*rule)?;
+                Some(format!(".{} {{ color: {}; }}\n", self.class(*rule), colour))
+            })
+            .collect()
+    }
+}
+
+impl Default for TajweedScheme {
+    fn default() -> Self {
+        Self::mushaf()
+    }
+}
@@ -0,0 +1,27 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use std::ops::Range;
+
+use crate::TajweedRule;
+
+/// Represents a span of vocalized text to which a rule of tajweed applies.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TajweedSpan {
+    /// The rule applying to the span.
+    pub rule: TajweedRule,
+
+    /// The indices of the characters of the span, marks included.
+    pub range: Range<usize>,
+}
@@ -0,0 +1,149 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicCharacter;
+use crate::BuckwalterVariant;
+use crate::TransliterationError;
+use crate::UnicodeCharacter;
+use crate::UnmappedSymbol;
+
+/// Transliterates Arabic text into the given variant of the Buckwalter scheme.
+///
+/// Whitespace, ASCII digits, and the ASCII punctuation that the variant does not use are copied
+/// as-is. Any other symbol is reported in the returned [`TransliterationError`]:
+///
+/// ```
+/// use arabic_script::{to_buckwalter, BuckwalterVariant};
+///
+/// let text = to_buckwalter("كَتَبَ الدَّرْسَ", BuckwalterVariant::Standard).unwrap();
+/// assert_eq!(text, "kataba Ald~arosa");
+///
+/// let error = to_buckwalter("كتب x", BuckwalterVariant::Standard).unwrap_err();
+/// assert_eq!(error.unmapped[0].index, 4);
+/// ```
+pub fn to_buckwalter(
+    text: &str,
+    variant: BuckwalterVariant,
+) -> Result<String, TransliterationError> {
+    convert(text, |c| match ArabicCharacter::try_from(c) {
+        Ok(character) => variant.encode(character),
+        Err(()) => variant.passes_through(c).then_some(c),
+    })
+}
+
+/// Transliterates text in the given variant of the Buckwalter scheme back into Arabic.
+///
+/// Whitespace, ASCII digits, and the ASCII punctuation that the variant does not use are copied
+/// as-is. Any other symbol is reported in the returned [`TransliterationError`]:
+///
+/// ```
+/// use arabic_script::{from_buckwalter, BuckwalterVariant};
+///
+/// let text = from_buckwalter("kataba Ald~arosa", BuckwalterVariant::Standard).unwrap();
+/// assert_eq!(text, "كَتَبَ الدَّرْسَ");
+/// ```
+pub fn from_buckwalter(
+    text: &str,
+    variant: BuckwalterVariant,
+) -> Result<String, TransliterationError> {
+    convert(text, |c| match variant.decode(c) {
+        Some(character) => Some(character.scalar_value()),
+        None => variant.passes_through(c).then_some(c),
+    })
+}
+
+/// Converts every character of a text, collecting those that cannot be converted.
+fn convert(text: &str, map: impl Fn(char) -> Option<char>) -> Result<String, TransliterationError> {
+    let mut converted = String::with_capacity(text.len());
+    let mut unmapped = Vec::new();
+
+    for (index, symbol) in text.chars().enumerate() {
+        match map(symbol) {
+            Some(c) => converted.push(c),
+            None => unmapped.push(UnmappedSymbol { index, symbol }),
+        }
+    }
+
+    if unmapped.is_empty() {
+        Ok(converted)
+    } else {
+        Err(TransliterationError { unmapped })
+    }
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::from_buckwalter;
+    use crate::to_buckwalter;
+    use crate::ArabicCharacter;
+    use crate::BuckwalterVariant::*;
+    use crate::UnmappedSymbol;
+
+    #[test]
+    fn maps_every_character_both_ways() {
+        for c in '\u{0621}'..='\u{0652}' {
+            if let Ok(character) = ArabicCharacter::try_from(c) {
+                for variant in [Standard, XmlSafe] {
+                    let symbol = variant.encode(character).unwrap();
+                    assert!(symbol.is_ascii());
+                    assert_eq!(variant.decode(symbol).unwrap(), character);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn converts_the_xml_safe_variant() {
+        let arabic = "شَيْءٌ ذُو بَالٍ";
+
+        assert_eq!(to_buckwalter(arabic, Standard).unwrap(), "$ayo'N *uw baAlK");
+        assert_eq!(to_buckwalter(arabic, XmlSafe).unwrap(), "cayoCN Vuw baAlK");
+        assert_eq!(
+            from_buckwalter("cayoCN Vuw baAlK", XmlSafe).unwrap(),
+            arabic
+        );
+    }
+
+    #[test]
+    fn passes_through_unambiguous_symbols() {
+        assert_eq!(
+            to_buckwalter("سورة 2، آية 255", Standard).map_err(|e| e.unmapped.len()),
+            Err(1)
+        );
+        assert_eq!(
+            to_buckwalter("سورة 2: آية 255", Standard).unwrap(),
+            "swrp 2: |yp 255"
+        );
+        assert_eq!(
+            from_buckwalter("swrp 2: |yp 255", Standard).unwrap(),
+            "سورة 2: آية 255"
+        );
+    }
+
+    #[test]
+    fn reports_unmapped_symbols() {
+        assert_eq!(
+            from_buckwalter("kitAb#Q", Standard).unwrap_err().unmapped,
+            vec![UnmappedSymbol {
+                index: 6,
+                symbol: 'Q'
+            }]
+        );
+        assert_eq!(from_buckwalter("<b>", XmlSafe).unwrap(), "<ب>");
+    }
+}
@@ -0,0 +1,163 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicCharacter;
+use crate::ArabicDamma;
+use crate::ArabicDammatan;
+use crate::ArabicFatha;
+use crate::ArabicFathatan;
+use crate::ArabicKasra;
+use crate::ArabicKasratan;
+use crate::ArabicLetterAin;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterAlefMaksura;
+use crate::ArabicLetterAlefWithHamzaAbove;
+use crate::ArabicLetterAlefWithHamzaBelow;
+use crate::ArabicLetterAlefWithMaddaAbove;
+use crate::ArabicLetterBeh;
+use crate::ArabicLetterDad;
+use crate::ArabicLetterDal;
+use crate::ArabicLetterFeh;
+use crate::ArabicLetterGhain;
+use crate::ArabicLetterHah;
+use crate::ArabicLetterHamza;
+use crate::ArabicLetterHeh;
+use crate::ArabicLetterJeem;
+use crate::ArabicLetterKaf;
+use crate::ArabicLetterKhah;
+use crate::ArabicLetterLam;
+use crate::ArabicLetterMeem;
+use crate::ArabicLetterNoon;
+use crate::ArabicLetterQaf;
+use crate::ArabicLetterReh;
+use crate::ArabicLetterSad;
+use crate::ArabicLetterSeen;
+use crate::ArabicLetterSheen;
+use crate::ArabicLetterTah;
+use crate::ArabicLetterTeh;
+use crate::ArabicLetterTehMarbuta;
+use crate::ArabicLetterThal;
+use crate::ArabicLetterTheh;
+use crate::ArabicLetterWaw;
+use crate::ArabicLetterWawWithHamzaAbove;
+use crate::ArabicLetterYeh;
+use crate::ArabicLetterYehWithHamzaAbove;
+use crate::ArabicLetterZah;
+use crate::ArabicLetterZain;
+use crate::ArabicShadda;
+use crate::ArabicSukun;
+use crate::ArabicTatweel;
+
+/// The Buckwalter symbols of each character, in the standard and the XML-safe variants.
+const SYMBOLS: [(ArabicCharacter, char, char); 45] = [
+    (ArabicLetterHamza, '\'', 'C'),
+    (ArabicLetterAlefWithMaddaAbove, '|', 'M'),
+    (ArabicLetterAlefWithHamzaAbove, '>', 'O'),
+    (ArabicLetterWawWithHamzaAbove, '&', 'W'),
+    (ArabicLetterAlefWithHamzaBelow, '<', 'I'),
+    (ArabicLetterYehWithHamzaAbove, '}', 'Q'),
+    (ArabicLetterAlef, 'A', 'A'),
+    (ArabicLetterBeh, 'b', 'b'),
+    (ArabicLetterTehMarbuta, 'p', 'p'),
+    (ArabicLetterTeh, 't', 't'),
+    (ArabicLetterTheh, 'v', 'v'),
+    (ArabicLetterJeem, 'j', 'j'),
+    (ArabicLetterHah, 'H', 'H'),
+    (ArabicLetterKhah, 'x', 'x'),
+    (ArabicLetterDal, 'd', 'd'),
+    (ArabicLetterThal, '*', 'V'),
+    (ArabicLetterReh, 'r', 'r'),
+    (ArabicLetterZain, 'z', 'z'),
+    (ArabicLetterSeen, 's', 's'),
+    (ArabicLetterSheen, '$', 'c'),
+    (ArabicLetterSad, 'S', 'S'),
+    (ArabicLetterDad, 'D', 'D'),
+    (ArabicLetterTah, 'T', 'T'),
+    (ArabicLetterZah, 'Z', 'Z'),
+    (ArabicLetterAin, 'E', 'E'),
+    (ArabicLetterGhain, 'g', 'g'),
+    (ArabicTatweel, '_', '_'),
+    (ArabicLetterFeh, 'f', 'f'),
+    (ArabicLetterQaf, 'q', 'q'),
+    (ArabicLetterKaf, 'k', 'k'),
+    (ArabicLetterLam, 'l', 'l'),
+    (ArabicLetterMeem, 'm', 'm'),
+    (ArabicLetterNoon, 'n', 'n'),
+    (ArabicLetterHeh, 'h', 'h'),
+    (ArabicLetterWaw, 'w', 'w'),
+    (ArabicLetterAlefMaksura, 'Y', 'Y'),
+    (ArabicLetterYeh, 'y', 'y'),
+    (ArabicFathatan, 'F', 'F'),
+    (ArabicDammatan, 'N', 'N'),
+    (ArabicKasratan, 'K', 'K'),
+    (ArabicFatha, 'a', 'a'),
+    (ArabicDamma, 'u', 'u'),
+    (ArabicKasra, 'i', 'i'),
+    (ArabicShadda, '~', '~'),
+    (ArabicSukun, 'o', 'o'),
+];
+
+/// Represents a variant of the Buckwalter transliteration scheme.
+///
+/// Each variant maps the characters of the Arabic script to single ASCII symbols, and back:
+///
+/// ```
+/// use arabic_script::{ArabicLetterSheen, BuckwalterVariant};
+///
+/// assert_eq!(BuckwalterVariant::Standard.encode(ArabicLetterSheen), Some('$'));
+/// assert_eq!(BuckwalterVariant::XmlSafe.encode(ArabicLetterSheen), Some('c'));
+/// assert_eq!(BuckwalterVariant::XmlSafe.decode('c'), Some(ArabicLetterSheen));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum BuckwalterVariant {
+    /// The original scheme, as used by the Penn Arabic Treebank.
+    #[default]
+    Standard,
+
+    /// The Safe Buckwalter scheme, which avoids the symbols that are special in XML, regular
+    /// expressions, and shells.
+    XmlSafe,
+}
+
+impl BuckwalterVariant {
+    /// Returns the symbol of a character, or [`None`] if the character has no symbol.
+    pub fn encode(&self, character: ArabicCharacter) -> Option<char> {
+        SYMBOLS
+            .iter()
+            .find(|(c, _, _)| c == &character)
+            .map(|(_, standard, safe)| self.select(*standard, *safe))
+    }
+
+    /// Returns the character of a symbol, or [`None`] if the symbol belongs to no character.
+    pub fn decode(&self, symbol: char) -> Option<ArabicCharacter> {
+        SYMBOLS
+            .iter()
+            .find(|(_, standard, safe)| self.select(*standard, *safe) == symbol)
+            .map(|(c, _, _)| *c)
+    }
+
+    /// Returns `true` if a primitive character, that is not part of the scheme, may be copied
+    /// as-is between Arabic text and its transliteration without becoming ambiguous.
+    pub(crate) fn passes_through(&self, c: char) -> bool {
+        (c.is_whitespace() || c.is_ascii_digit() || c.is_ascii_punctuation())
+            && self.decode(c).is_none()
+    }
+
+    fn select(&self, standard: char, safe: char) -> char {
+        match self {
+            BuckwalterVariant::Standard => standard,
+            BuckwalterVariant::XmlSafe => safe,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+//! The **Transliteration** module converts Arabic text to and from ASCII transliteration schemes.
+
+pub use buckwalter::from_buckwalter;
+pub use buckwalter::to_buckwalter;
+pub use buckwalter_variant::BuckwalterVariant;
+pub use transliteration_error::TransliterationError;
+pub use unmapped_symbol::UnmappedSymbol;
+
+mod buckwalter;
+mod buckwalter_variant;
+mod transliteration_error;
+mod unmapped_symbol;
@@ -0,0 +1,37 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::UnmappedSymbol;
+
+/// Represents the failure of a transliteration, listing every symbol that could not be mapped.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransliterationError {
+    /// The symbols that could not be mapped, in the order they appear in the converted text.
+    pub unmapped: Vec<UnmappedSymbol>,
+}
+
+impl Display for TransliterationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unmapped symbols:")?;
+        for unmapped in &self.unmapped {
+            write!(f, " {:?} at {}", unmapped.symbol, unmapped.index)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for TransliterationError {}
@@ -0,0 +1,23 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+//! The **Vocalization** module validates the diacritical marks (_tashkeel_) of vocalized words.
+
+pub use vocalization_issue::VocalizationIssue;
+pub use vocalization_issue_reason::VocalizationIssueReason;
+pub use vocalization_validator::validate_vocalization;
+
+mod vocalization_issue;
+mod vocalization_issue_reason;
+mod vocalization_validator;
@@ -0,0 +1,25 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::VocalizationIssueReason;
+
+/// Represents a single problem found in the vocalization of a word.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VocalizationIssue {
+    /// The index, within the validated word, of the diacritical mark at fault.
+    pub index: usize,
+
+    /// The reason the diacritical mark is at fault.
+    pub reason: VocalizationIssueReason,
+}
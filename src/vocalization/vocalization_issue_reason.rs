@@ -0,0 +1,32 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+/// Represents the reason a diacritical mark was reported by the vocalization validator.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VocalizationIssueReason {
+    /// The letter already carries a vowel mark, be it a short vowel, a tanween, or a sukun.
+    ConflictingVowels,
+
+    /// A tanween is placed on a letter that does not end the word.
+    MedialTanween,
+
+    /// A sukun is placed on an Arabic Letter Alef.
+    SukunOnAlef,
+
+    /// A shadda is placed on the first letter of the word.
+    InitialShadda,
+
+    /// The diacritical mark is not preceded by any letter to carry it.
+    MissingBaseLetter,
+}
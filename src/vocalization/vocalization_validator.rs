@@ -0,0 +1,194 @@
+/**************************************************************************************************
+ * Copyright 2023 Tamer Elzein <tamer@tamerudition.com>                                           *
+ *                                                                                                *
+ * Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file      *
+ * except in compliance with the License. You may obtain a copy of the License at                 *
+ *                                                                                                *
+ * http://www.apache.org/licenses/LICENSE-2.0                                                     *
+ *                                                                                                *
+ * Unless required by applicable law or agreed to in writing, software distributed under the      *
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND,      *
+ * either express or implied. See the License for the specific language governing permissions     *
+ * and limitations under the License.                                                             *
+ **************************************************************************************************/
+
+use crate::ArabicCharacter;
+use crate::ArabicFathatan;
+use crate::ArabicLetterAlef;
+use crate::ArabicLetterAlefMaksura;
+use crate::ArabicShadda;
+use crate::ArabicSukun;
+use crate::VocalizationIssue;
+use crate::VocalizationIssueReason;
+
+/// Walks a vocalized word and reports every malformed diacritical mark it carries.
+///
+/// The issues are reported in the order of the marks within the word:
+///
+/// ```
+/// use arabic_script::{validate_vocalization, VocalizationIssueReason};
+/// use arabic_script::{ArabicFatha, ArabicKasra, ArabicLetterBeh, ArabicLetterMeem};
+///
+/// let word = [ArabicLetterBeh, ArabicFatha, ArabicKasra, ArabicLetterMeem];
+/// let issues = validate_vocalization(&word);
+///
+/// assert_eq!(issues.len(), 1);
+/// assert_eq!(issues[0].index, 2);
+/// assert_eq!(issues[0].reason, VocalizationIssueReason::ConflictingVowels);
+/// ```
+///
+/// A tanween is accepted on the letter preceding a final Arabic Letter Alef or Arabic Letter Alef
+/// Maksura, as in the accusative ending of كتابًا.
+pub fn validate_vocalization(word: &[ArabicCharacter]) -> Vec<VocalizationIssue> {
+    let first_letter = word.iter().position(ArabicCharacter::is_letter);
+    let mut issues = Vec::new();
+    let mut base: Option<usize> = None;
+    let mut vowelled = false;
+
+    for (index, character) in word.iter().enumerate() {
+        if !character.is_diacritic() {
+            base = Some(index);
+            vowelled = false;
+            continue;
+        }
+
+        let mut report = |reason| issues.push(VocalizationIssue { index, reason });
+
+        let Some(base) = base else {
+            report(VocalizationIssueReason::MissingBaseLetter);
+            continue;
+        };
+
+        if !matches!(character, ArabicShadda) {
+            if vowelled {
+                report(VocalizationIssueReason::ConflictingVowels);
+            }
+            vowelled = true;
+        }
+
+        if character.is_tanween() && !ends_word(word, base, character) {
+            report(VocalizationIssueReason::MedialTanween);
+        }
+
+        if matches!(character, ArabicSukun) && matches!(word[base], ArabicLetterAlef) {
+            report(VocalizationIssueReason::SukunOnAlef);
+        }
+
+        if matches!(character, ArabicShadda) && first_letter == Some(base) {
+            report(VocalizationIssueReason::InitialShadda);
+        }
+    }
+
+    issues
+}
+
+/// Returns `true` if the letter at the given index may carry the given tanween as the end of the
+/// word.
+fn ends_word(word: &[ArabicCharacter], base: usize, tanween: &ArabicCharacter) -> bool {
+    let mut rest = word[base + 1..].iter().filter(|c| !c.is_diacritic());
+
+    match (rest.next(), rest.next()) {
+        (None, _) => true,
+        (Some(ArabicLetterAlef | ArabicLetterAlefMaksura), None) => {
+            matches!(tanween, ArabicFathatan)
+        }
+        _ => false,
+    }
+}
+
+/**************************************************************************************************
+ * Tests.                                                                                         *
+ **************************************************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::validate_vocalization;
+    use crate::VocalizationIssue;
+    use crate::VocalizationIssueReason::*;
+
+    use crate::ArabicDamma;
+    use crate::ArabicDammatan;
+    use crate::ArabicFatha;
+    use crate::ArabicFathatan;
+    use crate::ArabicKasra;
+    use crate::ArabicLetterAlef;
+    use crate::ArabicLetterBeh;
+    use crate::ArabicLetterKaf;
+    use crate::ArabicLetterTeh;
+    use crate::ArabicShadda;
+    use crate::ArabicSukun;
+
+    #[test]
+    fn accepts_well_formed_words() {
+        let word = [
+            ArabicLetterKaf,
+            ArabicKasra,
+            ArabicLetterTeh,
+            ArabicFatha,
+            ArabicLetterAlef,
+            ArabicLetterBeh,
+            ArabicFathatan,
+            ArabicLetterAlef,
+        ];
+
+        assert!(validate_vocalization(&word).is_empty());
+    }
+
+    #[test]
+    fn reports_conflicting_vowels() {
+        let word = [ArabicLetterKaf, ArabicShadda, ArabicFatha, ArabicDamma];
+
+        assert_eq!(
+            validate_vocalization(&word),
+            vec![
+                VocalizationIssue {
+                    index: 1,
+                    reason: InitialShadda
+                },
+                VocalizationIssue {
+                    index: 3,
+                    reason: ConflictingVowels
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_medial_tanween() {
+        let word = [ArabicLetterKaf, ArabicDammatan, ArabicLetterTeh];
+
+        assert_eq!(
+            validate_vocalization(&word),
+            vec![VocalizationIssue {
+                index: 1,
+                reason: MedialTanween
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_sukun_on_alef() {
+        let word = [ArabicLetterKaf, ArabicFatha, ArabicLetterAlef, ArabicSukun];
+
+        assert_eq!(
+            validate_vocalization(&word),
+            vec![VocalizationIssue {
+                index: 3,
+                reason: SukunOnAlef
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_marks_without_base_letter() {
+        let word = [ArabicKasra, ArabicLetterBeh];
+
+        assert_eq!(
+            validate_vocalization(&word),
+            vec![VocalizationIssue {
+                index: 0,
+                reason: MissingBaseLetter
+            }]
+        );
+    }
+}